@@ -1,12 +1,12 @@
 use axum::extract::FromRef;
 use sea_orm::DatabaseConnection;
 
-use std::{ collections::HashMap, sync::Arc };
+use std::sync::Arc;
 
-use crate::twilightrs::discord_client::DiscordClientRef;
+use crate::bot_supervisor::BotSupervisor;
 
 #[derive(Clone, FromRef)]
 pub struct AppState {
     pub db: DatabaseConnection,
-    pub running_bots: HashMap<String, Arc<DiscordClientRef>>,
+    pub running_bots: Arc<BotSupervisor>,
 }
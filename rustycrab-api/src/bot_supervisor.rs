@@ -0,0 +1,286 @@
+//! Supervisor for the Discord bots run by this process.
+//!
+//! The supervisor owns the registry of running bots. Each bot runs in its own task, which
+//! creates the bot's client and shards and handles its gateway events. When the gateway loop
+//! of a bot ends without the bot being stopped, the bot is restarted with an exponential backoff.
+//!
+//! The supervisor also syncs the bots' slash commands, when a bot starts if it was asked to on
+//! the command line, or on request through the API.
+
+use std::{ collections::HashMap, sync::Arc, time::Duration };
+
+use rustycrab_model::response::bots::{ ResponseBotStatus, ResponseCommandSync };
+use sea_orm::DatabaseConnection;
+use tokio::{ sync::RwLock, task::JoinHandle, time::Instant };
+
+use crate::{
+    create_discord_client,
+    database::bots::Model as BotModel,
+    default_queries::DefaultSeaQueries,
+    queries::bot_queries::BotQueries,
+    twilightrs::{
+        commands::slash::command_sync::CommandScope,
+        discord_client::DiscordClient,
+        dispatchers::ClientDispatchers,
+        events::handle_bot_events,
+    },
+    utilities::app_error::{ AppError, BoxedError },
+};
+
+/// Delay before the first restart of a crashed bot.
+const INITIAL_BACKOFF: Duration = Duration::from_secs(5);
+/// Longest delay between two restarts of a crashed bot.
+const MAX_BACKOFF: Duration = Duration::from_secs(5 * 60);
+/// A bot running for this long is considered healthy again and its backoff is reset.
+const STABLE_AFTER: Duration = Duration::from_secs(10 * 60);
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BotStatus {
+    /// The bot's client and shards are being created
+    Starting,
+    /// The bot is connected and handling events
+    Running,
+    /// The bot crashed and is waiting to be restarted
+    Restarting,
+    /// The bot was stopped on request
+    Stopped,
+}
+
+impl BotStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BotStatus::Starting => "starting",
+            BotStatus::Running => "running",
+            BotStatus::Restarting => "restarting",
+            BotStatus::Stopped => "stopped",
+        }
+    }
+}
+
+struct SupervisedBot {
+    client: Option<DiscordClient>,
+    status: BotStatus,
+    restarts: u32,
+    last_error: Option<String>,
+    task: Option<JoinHandle<()>>,
+}
+
+impl SupervisedBot {
+    fn to_response(&self, bot_id: &str) -> ResponseBotStatus {
+        ResponseBotStatus {
+            bot_id: bot_id.to_string(),
+            status: self.status.as_str().to_string(),
+            restarts: self.restarts,
+            last_error: self.last_error.clone(),
+        }
+    }
+}
+
+pub struct BotSupervisor {
+    db: DatabaseConnection,
+    bots: RwLock<HashMap<String, SupervisedBot>>,
+    /// Where the slash commands of the bots are synced when they start
+    sync_on_start: Vec<CommandScope>,
+}
+
+impl BotSupervisor {
+    pub fn new(db: DatabaseConnection, sync_on_start: Vec<CommandScope>) -> Arc<Self> {
+        Arc::new(Self { db, bots: RwLock::new(HashMap::new()), sync_on_start })
+    }
+
+    /// Starts every bot found in the database.
+    pub async fn start_all(self: &Arc<Self>) -> Result<(), BoxedError> {
+        let bots: Vec<BotModel> = BotQueries::find_all(&self.db).await?;
+
+        for bot in bots {
+            if let Err(e) = self.start(&bot.bot_id).await {
+                eprintln!("[{}] failed to start bot: {}", bot.bot_id, e);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Gets the Discord client of a running bot.
+    pub async fn get_client(&self, bot_id: &str) -> Option<DiscordClient> {
+        self.bots
+            .read().await
+            .get(bot_id)
+            .and_then(|bot| bot.client.clone())
+    }
+
+    /// Gets the Discord clients of every running bot.
+    pub async fn clients(&self) -> Vec<DiscordClient> {
+        self.bots
+            .read().await
+            .values()
+            .filter_map(|bot| bot.client.clone())
+            .collect()
+    }
+
+    /// Starts a bot that is not running yet.
+    pub async fn start(self: &Arc<Self>, bot_id: &str) -> Result<ResponseBotStatus, AppError> {
+        // make sure the bot exists before spawning anything
+        BotQueries::find_by_discord_id(&self.db, bot_id).await?;
+
+        let mut bots = self.bots.write().await;
+        if let Some(bot) = bots.get(bot_id) {
+            if bot.task.as_ref().map_or(false, |task| !task.is_finished()) {
+                return Err(AppError::bad_request("Bot is already running"));
+            }
+        }
+
+        let task = tokio::spawn(Arc::clone(self).supervise(bot_id.to_string()));
+        let bot = SupervisedBot {
+            client: None,
+            status: BotStatus::Starting,
+            restarts: 0,
+            last_error: None,
+            task: Some(task),
+        };
+        let response = bot.to_response(bot_id);
+        bots.insert(bot_id.to_string(), bot);
+
+        Ok(response)
+    }
+
+    /// Stops a running bot, closing its gateway connections.
+    pub async fn stop(&self, bot_id: &str) -> Result<ResponseBotStatus, AppError> {
+        let mut bots = self.bots.write().await;
+        let bot = bots.get_mut(bot_id).ok_or_else(|| AppError::not_found("Bot is not running"))?;
+
+        if let Some(task) = bot.task.take() {
+            task.abort();
+        }
+        bot.client = None;
+        bot.status = BotStatus::Stopped;
+
+        Ok(bot.to_response(bot_id))
+    }
+
+    /// Stops the bot if it's running, then starts it again with its current database row.
+    pub async fn restart(self: &Arc<Self>, bot_id: &str) -> Result<ResponseBotStatus, AppError> {
+        let _ = self.stop(bot_id).await;
+
+        self.start(bot_id).await
+    }
+
+    /// Syncs the slash commands of a running bot, globally or in a single guild.
+    pub async fn sync_commands(
+        &self,
+        bot_id: &str,
+        scope: CommandScope
+    ) -> Result<ResponseCommandSync, AppError> {
+        let client = self
+            .get_client(bot_id).await
+            .ok_or_else(|| AppError::not_found("Bot is not running"))?;
+
+        let dispatchers = ClientDispatchers::new();
+        dispatchers.slash_commands
+            .sync_commands(
+                &client,
+                &dispatchers.context_commands,
+                &dispatchers.context_menus,
+                scope
+            ).await
+            .map_err(|e| AppError::internal_server_error(format!("Failed to sync commands: {}", e)))
+    }
+
+    pub async fn status(&self, bot_id: &str) -> Option<ResponseBotStatus> {
+        self.bots
+            .read().await
+            .get(bot_id)
+            .map(|bot| bot.to_response(bot_id))
+    }
+
+    pub async fn statuses(&self) -> Vec<ResponseBotStatus> {
+        self.bots
+            .read().await
+            .iter()
+            .map(|(bot_id, bot)| bot.to_response(bot_id))
+            .collect()
+    }
+
+    /// Runs a bot until it's stopped, restarting it with backoff whenever it stops on its own.
+    async fn supervise(self: Arc<Self>, bot_id: String) {
+        let mut backoff = INITIAL_BACKOFF;
+
+        loop {
+            let started_at = Instant::now();
+
+            let reason = match self.run_bot(&bot_id).await {
+                Ok(()) => "gateway connection closed".to_string(),
+                Err(e) => format!("{}", e),
+            };
+
+            if started_at.elapsed() >= STABLE_AFTER {
+                backoff = INITIAL_BACKOFF;
+            }
+
+            eprintln!("[{}] bot stopped ({}), restarting in {:?}", bot_id, reason, backoff);
+            if let Some(bot) = self.bots.write().await.get_mut(&bot_id) {
+                bot.client = None;
+                bot.status = BotStatus::Restarting;
+                bot.restarts += 1;
+                bot.last_error = Some(reason);
+            }
+
+            tokio::time::sleep(backoff).await;
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+        }
+    }
+
+    async fn run_bot(&self, bot_id: &str) -> Result<(), BoxedError> {
+        if let Some(bot) = self.bots.write().await.get_mut(bot_id) {
+            bot.status = BotStatus::Starting;
+        }
+
+        // read the bot again so token changes are picked up on restarts
+        let bot = BotQueries::find_by_discord_id(&self.db, bot_id).await?;
+        let (client, shards) = create_discord_client(&self.db, &bot).await?;
+
+        if let Some(bot) = self.bots.write().await.get_mut(bot_id) {
+            bot.client = Some(Arc::clone(&client));
+            bot.status = BotStatus::Running;
+        }
+
+        if !self.sync_on_start.is_empty() {
+            tokio::spawn(sync_commands_on_start(Arc::clone(&client), self.sync_on_start.clone()));
+        }
+
+        handle_bot_events(shards, client).await
+    }
+}
+
+/// Syncs the commands of a bot that just started, only logging the results.
+async fn sync_commands_on_start(client: DiscordClient, scopes: Vec<CommandScope>) {
+    let dispatchers = ClientDispatchers::new();
+    for scope in scopes {
+        match
+            dispatchers.slash_commands.sync_commands(
+                &client,
+                &dispatchers.context_commands,
+                &dispatchers.context_menus,
+                scope
+            ).await
+        {
+            Ok(report) => {
+                println!(
+                    "[{}] synced {} commands: {} created, {} updated, {} deleted, {} unchanged",
+                    client.bot_id,
+                    report.scope,
+                    report.created.len(),
+                    report.updated.len(),
+                    report.deleted.len(),
+                    report.unchanged
+                );
+                for (name, e) in &report.failed {
+                    eprintln!("[{}] failed to sync command {}: {}", client.bot_id, name, e);
+                }
+            }
+            Err(e) => {
+                eprintln!("[{}] failed to sync {} commands: {}", client.bot_id, scope, e);
+            }
+        }
+    }
+}
@@ -13,6 +13,14 @@ pub struct Model {
     pub module_flags: i32,
     #[sea_orm(column_name = "premiumFlags")]
     pub premium_flags: i32,
+    #[sea_orm(column_name = "commandCooldowns", column_type = "Text", nullable)]
+    pub command_cooldowns: Option<String>,
+    #[sea_orm(column_name = "commandSuggestions")]
+    pub command_suggestions: i8,
+    #[sea_orm(column_name = "extraPrefixes", column_type = "Text", nullable)]
+    pub extra_prefixes: Option<String>,
+    #[sea_orm(column_name = "mentionPrefix")]
+    pub mention_prefix: i8,
     #[sea_orm(column_name = "botId")]
     pub bot_id: i32,
     #[sea_orm(column_name = "guildId")]
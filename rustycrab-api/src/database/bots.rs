@@ -26,10 +26,16 @@ pub enum Relation {
     AutoRoles,
     #[sea_orm(has_many = "super::bot_guild_configurations::Entity")]
     BotGuildConfigurations,
+    #[sea_orm(has_many = "super::bot_guild_goodbyes::Entity")]
+    BotGuildGoodbyes,
     #[sea_orm(has_many = "super::bot_guild_welcomes::Entity")]
     BotGuildWelcomes,
     #[sea_orm(has_many = "super::bot_users::Entity")]
     BotUsers,
+    #[sea_orm(has_many = "super::command_rules::Entity")]
+    CommandRules,
+    #[sea_orm(has_many = "super::command_usages::Entity")]
+    CommandUsages,
     #[sea_orm(has_many = "super::guild_action_logs::Entity")]
     GuildActionLogs,
     #[sea_orm(has_many = "super::items::Entity")]
@@ -66,6 +72,12 @@ impl Related<super::bot_guild_configurations::Entity> for Entity {
     }
 }
 
+impl Related<super::bot_guild_goodbyes::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::BotGuildGoodbyes.def()
+    }
+}
+
 impl Related<super::bot_guild_welcomes::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::BotGuildWelcomes.def()
@@ -78,6 +90,18 @@ impl Related<super::bot_users::Entity> for Entity {
     }
 }
 
+impl Related<super::command_rules::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CommandRules.def()
+    }
+}
+
+impl Related<super::command_usages::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CommandUsages.def()
+    }
+}
+
 impl Related<super::guild_action_logs::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::GuildActionLogs.def()
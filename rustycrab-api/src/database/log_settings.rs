@@ -11,6 +11,12 @@ pub struct Model {
     pub specify_channels: i8,
     #[sea_orm(column_name = "newAccountAge")]
     pub new_account_age: i32,
+    #[sea_orm(column_name = "newAccountAction")]
+    pub new_account_action: Option<String>,
+    #[sea_orm(column_name = "newAccountTimeout")]
+    pub new_account_timeout: Option<i32>,
+    #[sea_orm(column_name = "quarantineRoleID")]
+    pub quarantine_role_id: Option<String>,
     #[sea_orm(column_name = "botId")]
     pub bot_id: i32,
     #[sea_orm(column_name = "guildId")]
@@ -18,6 +18,8 @@ pub struct Model {
 pub enum Relation {
     #[sea_orm(has_one = "super::auto_responses::Entity")]
     AutoResponses,
+    #[sea_orm(has_one = "super::bot_guild_goodbyes::Entity")]
+    BotGuildGoodbyes,
     #[sea_orm(has_one = "super::bot_guild_welcomes::Entity")]
     BotGuildWelcomes,
     #[sea_orm(
@@ -38,6 +40,12 @@ impl Related<super::auto_responses::Entity> for Entity {
     }
 }
 
+impl Related<super::bot_guild_goodbyes::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::BotGuildGoodbyes.def()
+    }
+}
+
 impl Related<super::bot_guild_welcomes::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::BotGuildWelcomes.def()
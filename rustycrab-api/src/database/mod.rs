@@ -5,11 +5,14 @@ pub mod prelude;
 pub mod auto_responses;
 pub mod auto_roles;
 pub mod bot_guild_configurations;
+pub mod bot_guild_goodbyes;
 pub mod bot_guild_welcomes;
 pub mod bot_staffs;
 pub mod bot_users;
 pub mod bots;
 pub mod buttons;
+pub mod command_rules;
+pub mod command_usages;
 pub mod embed_fields;
 pub mod embed_info;
 pub mod guild_action_logs;
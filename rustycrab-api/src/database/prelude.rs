@@ -3,11 +3,14 @@
 pub use super::auto_responses::Entity as AutoResponses;
 pub use super::auto_roles::Entity as AutoRoles;
 pub use super::bot_guild_configurations::Entity as BotGuildConfigurations;
+pub use super::bot_guild_goodbyes::Entity as BotGuildGoodbyes;
 pub use super::bot_guild_welcomes::Entity as BotGuildWelcomes;
 pub use super::bot_staffs::Entity as BotStaffs;
 pub use super::bot_users::Entity as BotUsers;
 pub use super::bots::Entity as Bots;
 pub use super::buttons::Entity as Buttons;
+pub use super::command_rules::Entity as CommandRules;
+pub use super::command_usages::Entity as CommandUsages;
 pub use super::embed_fields::Entity as EmbedFields;
 pub use super::embed_info::Entity as EmbedInfo;
 pub use super::guild_action_logs::Entity as GuildActionLogs;
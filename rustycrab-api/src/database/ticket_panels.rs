@@ -29,6 +29,8 @@ pub struct Model {
     pub welcome_message_id: i32,
     #[sea_orm(column_name = "supportTeamId")]
     pub support_team_id: Option<i32>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub questions: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -5,6 +5,8 @@
 //! utility macros for constructing Discord CDN URLs.
 
 pub mod app_state;
+pub mod bot_supervisor;
+pub mod metrics;
 pub mod router;
 pub mod database;
 pub mod utilities;
@@ -19,20 +21,20 @@ pub mod multi_bot_guild_entities_queries;
 pub mod multi_bot_guild_entities_router;
 
 use app_state::AppState;
+use bot_supervisor::BotSupervisor;
 use axum::Router;
-use default_queries::DefaultSeaQueries;
-use queries::bot_queries::BotQueries;
 use sea_orm::DatabaseConnection;
 
 use twilight_standby::Standby;
 use utilities::app_error::AppError;
 use std::future::Future;
-use std::{ collections::HashMap, error::Error };
+use std::error::Error;
 use std::net::SocketAddr;
 use std::sync::Arc;
 
 use crate::router::create_router::create_router;
 use crate::database::bots::Model as BotModel;
+use crate::queries::command_usages_queries::CommandUsagesQueries;
 
 // discord
 use twilight_gateway::{ Intents, Shard, Config, stream };
@@ -45,8 +47,7 @@ use twilight_model::gateway::{
     payload::outgoing::update_presence::UpdatePresencePayload,
     presence::{ MinimalActivity, ActivityType, Status },
 };
-use twilightrs::discord_client::DiscordClientRef;
-use twilightrs::events::handle_bot_events;
+use twilightrs::{ commands::slash::command_sync::CommandScope, discord_client::DiscordClientRef };
 
 /// Creates a URL to a user's avatar on Discord's CDN.
 #[macro_export]
@@ -91,74 +92,93 @@ pub async fn run(app_state: AppState) {
 
 /// Initializes and runs all bots present in the database.
 ///
-/// For each bot found in the database, this function sets up the necessary Discord client,
-/// including its configuration, HTTP client, and event handling.
+/// The bots are handed to a `BotSupervisor`, which keeps them running and allows starting,
+/// stopping and restarting them while the process is running. The slash commands of the bots
+/// are synced in `sync_commands` when they start.
 pub async fn running_bots(
-    db: &DatabaseConnection
-) -> Result<HashMap<String, Arc<DiscordClientRef>>, Box<dyn Error + Send + Sync>> {
-    let bots: Vec<BotModel> = BotQueries::find_all(&db).await?;
-
-    let mut discord_clients = HashMap::new();
-    for bot in bots {
-        let config = Config::builder(bot.token.clone(), Intents::all())
-            .presence(
-                UpdatePresencePayload::new(
-                    vec![
-                        (MinimalActivity {
-                            kind: ActivityType::Playing,
-                            name: "Rusty Crab".into(),
-                            url: None,
-                        }).into()
-                    ],
-                    false,
-                    None,
-                    Status::Idle
-                ).map_err(|e|
-                    AppError::internal_server_error(
-                        format!("Error creating presence for bot {:?}", e)
-                    )
-                )?
-            )
-            .build();
-        let http = Arc::new(HttpClient::new(bot.token.clone()));
-        let user_id = http.current_user().await?.model().await?.id;
-
-        let cache: Arc<InMemoryCache> = Arc::new(
-            InMemoryCache::builder().resource_types(ResourceType::all()).build()
-        );
-
-        let standby = Arc::new(Standby::new());
-
-        let shards: Vec<Shard> = stream
-            ::create_recommended(&http, config, |_, builder| builder.build()).await?
-            .collect();
-
-        let senders = TwilightMap::new(
-            shards
-                .iter()
-                .map(|s| (s.id().number(), s.sender()))
-                .collect()
-        );
-
-        let songbird = Arc::new(Songbird::twilight(Arc::new(senders), user_id));
-        // Only HTTP client is stored in DiscordClient
-        let client = Arc::new(
-            DiscordClientRef::new(
-                db.clone(),
-                http.clone(),
-                cache.clone(),
-                standby.clone(),
-                songbird.clone()
-            )
-        );
-
-        discord_clients.insert(bot.bot_id, client.clone());
-
-        // Handle events with the shard in a separate task
-        spawn(handle_bot_events(shards, client));
-    }
-
-    Ok(discord_clients)
+    db: &DatabaseConnection,
+    sync_commands: Vec<CommandScope>
+) -> Result<Arc<BotSupervisor>, Box<dyn Error + Send + Sync>> {
+    let supervisor = BotSupervisor::new(db.clone(), sync_commands);
+    supervisor.start_all().await?;
+    spawn_command_usages_pruning(db.clone());
+
+    Ok(supervisor)
+}
+
+/// Deletes the recorded command usages older than their retention every hour.
+fn spawn_command_usages_pruning(db: DatabaseConnection) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(60 * 60));
+        loop {
+            interval.tick().await;
+            if let Err(e) = CommandUsagesQueries::prune(&db, CommandUsagesQueries::retention()).await {
+                eprintln!("Failed to prune command usages: {:?}", e);
+            }
+        }
+    });
+}
+
+/// Sets up the Discord client of a bot.
+///
+/// This creates the bot's configuration, HTTP client, cache and voice manager, and returns the
+/// client together with the shards that still have to be run with `handle_bot_events`.
+pub async fn create_discord_client(
+    db: &DatabaseConnection,
+    bot: &BotModel
+) -> Result<(Arc<DiscordClientRef>, Vec<Shard>), Box<dyn Error + Send + Sync>> {
+    let config = Config::builder(bot.token.clone(), Intents::all())
+        .presence(
+            UpdatePresencePayload::new(
+                vec![
+                    (MinimalActivity {
+                        kind: ActivityType::Playing,
+                        name: "Rusty Crab".into(),
+                        url: None,
+                    }).into()
+                ],
+                false,
+                None,
+                Status::Idle
+            ).map_err(|e|
+                AppError::internal_server_error(format!("Error creating presence for bot {:?}", e))
+            )?
+        )
+        .build();
+    let http = Arc::new(HttpClient::new(bot.token.clone()));
+    let user_id = http.current_user().await?.model().await?.id;
+
+    let cache: Arc<InMemoryCache> = Arc::new(
+        InMemoryCache::builder().resource_types(ResourceType::all()).build()
+    );
+
+    let standby = Arc::new(Standby::new());
+
+    let shards: Vec<Shard> = stream
+        ::create_recommended(&http, config, |_, builder| builder.build()).await?
+        .collect();
+
+    let senders = TwilightMap::new(
+        shards
+            .iter()
+            .map(|s| (s.id().number(), s.sender()))
+            .collect()
+    );
+
+    let songbird = Arc::new(Songbird::twilight(Arc::new(senders), user_id));
+    // Only HTTP client is stored in DiscordClient
+    let client = Arc::new(
+        DiscordClientRef::new(
+            user_id,
+            db.clone(),
+            http.clone(),
+            cache.clone(),
+            standby.clone(),
+            songbird.clone()
+        )
+    );
+
+    Ok((client, shards))
 }
 
 pub fn spawn(
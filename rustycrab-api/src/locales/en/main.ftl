@@ -15,6 +15,23 @@ invalid-image-url = Invalid image url
 command-error = An error happened when trying to process the command
 command-guildonly = Command not used in a guild
 command-invalid = Invalid command
+command-cooldown = Slow down! Try again in { $seconds } seconds.
+command-missing-permissions = You don't have the permissions required to use this command
+command-rule-channel = This command can't be used in this channel
+command-rule-role = You don't have a role allowed to use this command
+command-rule-user = You are not allowed to use this command
+command-suggestion = Unknown command `{ $command }`. Did you mean { $suggestions }?
+command-arg-missing = Missing argument `{ $arg }`, expected { $expected }
+command-arg-invalid = Invalid argument `{ $arg }`, expected { $expected }
+arg-expected-text = some text
+arg-expected-number = a number
+arg-expected-user = a user mention, ID or name
+arg-expected-channel = a channel mention or ID
+arg-expected-role = a role mention, ID or name
+arg-expected-duration = a duration like `1h30m`, `2d` or `90s`
+arg-expected-bool = yes or no
+arg-expected-emoji = an emoji
+arg-expected-choice = one of: { $choices }
 
 # Bot Owner Commands
 command-botowner = Manage BotOwner permission
@@ -48,14 +65,39 @@ command-math-invalid = Invalid Math Expression
 
 # Prefix Command
 command-prefix = Change bot's server prefix
-command-prefix-invalid = Invalid prefix, prefix can't be empty
+command-prefix-invalid = Invalid prefix, prefix can't be empty or longer than { $max } characters
 command-prefix-success = Prefix updated successfully. Prefix: `{ $prefix }`
+command-prefix-failed = Failed to update prefix
+command-prefix-list = Prefixes of this server: { $prefixes }
+command-prefix-added = Prefix `{ $prefix }` added
+command-prefix-removed = Prefix `{ $prefix }` removed
+command-prefix-exists = `{ $prefix }` is already a prefix of this server
+command-prefix-notfound = `{ $prefix }` is not a prefix of this server
+command-prefix-main = `{ $prefix }` is the main prefix, change it with the `set` subcommand instead
+command-prefix-limit = A server can't have more than { $max } prefixes
+command-prefix-mention-on = { $bot } can now be used as a prefix
+command-prefix-mention-off = { $bot } can no longer be used as a prefix
+
+# Modules Command
+command-modules = Enable, disable or list the bot modules of the server
+command-modules-title = Server modules
+command-modules-enabled = Module `{ $module }` enabled
+command-modules-disabled = Module `{ $module }` disabled
+command-modules-failed = Failed to update the module
+command-modules-invalid = Use `modules enable/disable <module>`. Modules: { $modules }
+module-disabled = The `{ $module }` module is disabled in this server
+select-menu-not-yours = Only { $user } can use this menu
 
 # Banner Command
 command-banner = Check user banner
 
 # Help Command 
 command-help = display bot or command help
+help-search-title = Commands matching "{ $search }"
+help-empty = No commands found
+help-page = Page { $page }/{ $pages }
+help-prev = Prev Page
+help-next = Next Page
 
 # Ping Command
 command-ping = Check API and Bot average response time
@@ -84,8 +126,8 @@ command-kick-admin = Cannot kick { $user } with Admin permission
 
 # Timeout Command
 command-timeout = Timeout one or more members
-command-timeout-success = Timed out { $user } for { $duration } minutes
-command-timeout-fail = An error happened when trying to timeout { $user } for { $duration } minutes
+command-timeout-success = Timed out { $user } for { $duration }
+command-timeout-fail = An error happened when trying to timeout { $user } for { $duration }
 command-timeout-admin = Cannot timeout { $user } with Admin permission
 
 # Untimeout Command
@@ -101,6 +143,12 @@ command-role-add-failed = Failed to add role { $role } to { $user }. Error: { $e
 command-role-remove-success = Removed role { $role } from { $user } successfully
 command-role-remove-failed = Failed to remove role { $role } from { $user }. Error: { $err }
 command-role-no-perm = Bot does not have permission to manage role { $role }
+command-rolemenu = Send a menu members can use to pick their roles
+command-rolemenu-title = Roles
+command-rolemenu-placeholder = Pick your roles
+command-rolemenu-empty = None of these roles can be offered, they must be below your highest role, manageable by the bot and not grant moderation permissions
+rolemenu-updated = Your roles were updated. Added: { $added }, removed: { $removed }
+rolemenu-update-failed = Some of your roles couldn't be updated. Added: { $added }, removed: { $removed }
 
 # AFK
 command-afk = set AFK status
@@ -158,6 +206,10 @@ command-pause-unpaused = Unpaused the track
 # Play Command
 command-play = Play music
 command-play-invalid-url = Please provide a valid URL or search query to play
+command-search = Search YouTube and pick the track to play
+command-search-title = Results for `{ $query }`
+command-search-placeholder = Pick a track to play
+command-search-noresults = No track found for `{ $query }`
 command-play-added-tracks = Added { $count } tracks to the queue
 command-play-added-track = Added track
 
@@ -197,4 +249,41 @@ autores-delete-failed = Failed to delete auto-response with trigger `{ $trigger
 autores-notfound = I can't find auto-response with trigger `{ $trigger }`
 autores-updated = Updated auto-response with trigger `{ $trigger }`
 autores-update-failed = Failed to update auto-response with trigger `{ $trigger }`
-autores-limited = Reached limit of 20 Auto-responders per server.
\ No newline at end of file
+autores-limited = Reached limit of 20 Auto-responders per server.
+
+# Action logs
+log-field-content = Content
+log-field-before = Before
+log-field-after = After
+log-field-roles = Roles
+log-field-name = Name
+log-field-topic = Topic
+log-field-nsfw = NSFW
+log-message-delete = Message Deleted
+log-message-in-channel = Message sent in { $channel } was deleted
+log-message-edit = Message Edited
+log-message-edit-link = [Message]({ $url }) edited in { $channel }
+log-message-report = Message Reported
+log-message-report-description = { $reporter } reported a [message]({ $url }) in { $channel }
+report-sent = The message was reported to the moderators
+report-failed = Failed to report the message
+report-not-configured = Reports aren't set up in this server, no log channel receives them
+log-member-join = Member Joined
+log-member-join-description = { $user } joined the server
+log-member-join-new-account = New Account Joined
+log-field-account-age = Account age
+log-account-age = { $days } days (created { $created })
+log-member-leave = Member Left
+log-member-leave-description = { $user } left the server
+log-member-ban = Member Banned
+log-member-ban-description = { $user } was banned
+log-member-unban = Member Unbanned
+log-member-unban-description = { $user } was unbanned
+log-member-role-add = Roles Added
+log-member-role-remove = Roles Removed
+log-member-nickname = Nickname Changed
+log-member-update-description = { $user } was updated
+log-channel-create = Channel Created
+log-channel-delete = Channel Deleted
+log-channel-update = Channel Updated
+log-channel-description = { $channel } (`{ $name }`)
@@ -4,6 +4,9 @@ use intl_memoizer::concurrent::IntlLangMemoizer;
 use unic_langid::LanguageIdentifier;
 use std::fs;
 
+/// Locales with a `main.ftl`, guilds can only pick one of them.
+pub const AVAILABLE_LOCALES: [&str; 2] = ["en", "vn"];
+
 pub fn load_localization(locale: &str) -> FluentBundle<FluentResource, IntlLangMemoizer> {
     let ftl_path = format!("src/locales/{}/main.ftl", locale);
     let ftl_string = fs::read_to_string(ftl_path).expect("Failed to read FTL file");
@@ -14,6 +14,23 @@ invalid-image-url = Link/ảnh không hợp lệ
 # Commands 
 command-error = Đã có lỗi xảy ra khi xử lí lệnh.
 command-guildonly = Lệnh chỉ được sử dụng ở server.
+command-cooldown = Chậm lại! Hãy thử lại sau { $seconds } giây.
+command-missing-permissions = Bạn không có quyền cần thiết để sử dụng lệnh này
+command-rule-channel = Lệnh này không thể sử dụng trong kênh này
+command-rule-role = Bạn không có vai trò được phép sử dụng lệnh này
+command-rule-user = Bạn không được phép sử dụng lệnh này
+command-suggestion = Không tìm thấy lệnh `{ $command }`. Có phải ý bạn là { $suggestions }?
+command-arg-missing = Thiếu tham số `{ $arg }`, cần { $expected }
+command-arg-invalid = Tham số `{ $arg }` không hợp lệ, cần { $expected }
+arg-expected-text = một đoạn văn bản
+arg-expected-number = một số
+arg-expected-user = mention, ID hoặc tên của người dùng
+arg-expected-channel = mention hoặc ID của kênh
+arg-expected-role = mention, ID hoặc tên của vai trò
+arg-expected-duration = một khoảng thời gian như `1h30m`, `2d` hoặc `90s`
+arg-expected-bool = có (yes) hoặc không (no)
+arg-expected-emoji = một emoji
+arg-expected-choice = một trong: { $choices }
 
 # Lệnh của chủ sở hữu Bot
 command-botowner = Quản lý quyền BotOwner
@@ -47,14 +64,38 @@ command-math-invalid = Phép học không hợp lệ
 
 # Lệnh prefix
 command-prefix = Thay đổi prefix máy chủ của bot
-command-prefix-invalid = prefix không hợp lệ, prefix không được để trống
+command-prefix-invalid = prefix không hợp lệ, prefix không được để trống hoặc dài hơn { $max } ký tự
 command-prefix-success = prefix được cập nhật thành công. Prefix: `{ $prefix }`
 command-prefix-failed = thất bại khi cập nhật prefix
+command-prefix-list = Các prefix của máy chủ: { $prefixes }
+command-prefix-added = Đã thêm prefix `{ $prefix }`
+command-prefix-removed = Đã xóa prefix `{ $prefix }`
+command-prefix-exists = `{ $prefix }` đã là prefix của máy chủ
+command-prefix-notfound = `{ $prefix }` không phải là prefix của máy chủ
+command-prefix-main = `{ $prefix }` là prefix chính, hãy dùng lệnh con `set` để thay đổi
+command-prefix-limit = Máy chủ không thể có nhiều hơn { $max } prefix
+command-prefix-mention-on = { $bot } giờ có thể được dùng làm prefix
+command-prefix-mention-off = { $bot } không còn được dùng làm prefix
 # Lệnh check banner
 command-banner = Kiểm tra banner người dùng
 
+# Lệnh Modules
+command-modules = Bật, tắt hoặc liệt kê các module của bot trong máy chủ
+command-modules-title = Các module của máy chủ
+command-modules-enabled = Đã bật module `{ $module }`
+command-modules-disabled = Đã tắt module `{ $module }`
+command-modules-failed = thất bại khi cập nhật module
+command-modules-invalid = Sử dụng `modules enable/disable <module>`. Các module: { $modules }
+module-disabled = Module `{ $module }` đã bị tắt trong máy chủ này
+select-menu-not-yours = Chỉ { $user } có thể dùng menu này
+
 # Lệnh giúp đỡ
 command-help = hiển thị bot hoặc lệnh trợ giúp
+help-search-title = Các lệnh khớp với "{ $search }"
+help-empty = Không tìm thấy lệnh nào
+help-page = Trang { $page }/{ $pages }
+help-prev = Trang Trước
+help-next = Trang Sau
 
 # Lệnh ping
 command-ping = Kiểm tra thời gian phản hồi trung bình của API và Bot
@@ -83,8 +124,8 @@ command-kick-admin = Không thể kick người dùng { $user } với quyền Ad
 
 # Lệnh Timeout
 command-timeout = Mute một hoặc nhiều thành viên
-command-timeout-success = Đã mute { $user } trong { $duration } giây.
-command-timeout-fail = Đã xảy ra lỗi khi cố gắng mute { $user } trong { $duration } giây.
+command-timeout-success = Đã mute { $user } trong { $duration }.
+command-timeout-fail = Đã xảy ra lỗi khi cố gắng mute { $user } trong { $duration }.
 command-timeout-admin = Không thể mute người dùng { $user } với quyền Admin
 
 # Lệnh Untimeout
@@ -100,6 +141,12 @@ command-role-add-failed = Không thể thêm role { $role } cho { $user }. Lỗi
 command-role-remove-success = Đã bỏ role { $role } từ { $user } thành công.
 command-role-remove-failed = Không thể bỏ role { $role } từ { $user }. Lỗi: { $err }
 command-role-no-perm = Bot không có quyền quản lý role { $role }.
+command-rolemenu = Gửi menu để thành viên tự chọn role
+command-rolemenu-title = Roles
+command-rolemenu-placeholder = Chọn role của bạn
+command-rolemenu-empty = Không thể đưa role nào trong số này vào menu, role phải thấp hơn role cao nhất của bạn, bot cấp được và không có quyền quản lý
+rolemenu-updated = Đã cập nhật role của bạn. Đã thêm: { $added }, đã bỏ: { $removed }
+rolemenu-update-failed = Không thể cập nhật một số role của bạn. Đã thêm: { $added }, đã bỏ: { $removed }
 
 # AFK
 command-afk = Set trạng thái AFK
@@ -155,6 +202,10 @@ command-pause-unpaused = Đã tiếp tục phát bài hát
 # Play Command
 command-play = Phát nhạc
 command-play-invalid-url = Vui lòng cung cấp URL hoặc truy vấn tìm kiếm hợp lệ để phát
+command-search = Tìm kiếm trên YouTube và chọn bài hát để phát
+command-search-title = Kết quả cho `{ $query }`
+command-search-placeholder = Chọn bài hát để phát
+command-search-noresults = Không tìm thấy bài hát nào cho `{ $query }`
 command-play-added-tracks = Đã thêm { $count } bài hát vào hàng đợi
 command-play-added-track = Đã thêm bài hát
 
@@ -194,4 +245,41 @@ autores-delete-failed = Lỗi khi xoá auto-res với trigger `{ $trigger }`
 autores-notfound = Không tìm thấy auto-res với trigger `{ $trigger }`
 autores-updated = Đã cập nhật auto-res với trigger `{ $trigger }`
 autores-update-failed = Lỗi khi cập nhật auto-res với trigger `{ $trigger }`
-autores-limited = Server bạn đã đạt giới hạn 20 auto-res/server.
\ No newline at end of file
+autores-limited = Server bạn đã đạt giới hạn 20 auto-res/server.
+
+# Action logs
+log-field-content = Nội dung
+log-field-before = Trước
+log-field-after = Sau
+log-field-roles = Roles
+log-field-name = Tên
+log-field-topic = Chủ đề
+log-field-nsfw = NSFW
+log-message-delete = Tin nhắn đã bị xoá
+log-message-in-channel = Tin nhắn trong { $channel } đã bị xoá
+log-message-edit = Tin nhắn đã được chỉnh sửa
+log-message-edit-link = [Tin nhắn]({ $url }) đã được chỉnh sửa trong { $channel }
+log-message-report = Tin nhắn bị báo cáo
+log-message-report-description = { $reporter } đã báo cáo một [tin nhắn]({ $url }) trong { $channel }
+report-sent = Tin nhắn đã được báo cáo cho quản trị viên
+report-failed = Không thể báo cáo tin nhắn
+report-not-configured = Máy chủ này chưa thiết lập kênh nhận báo cáo
+log-member-join = Thành viên mới
+log-member-join-description = { $user } đã tham gia server
+log-member-join-new-account = Tài khoản mới tham gia
+log-field-account-age = Tuổi tài khoản
+log-account-age = { $days } ngày (tạo { $created })
+log-member-leave = Thành viên rời đi
+log-member-leave-description = { $user } đã rời server
+log-member-ban = Thành viên bị ban
+log-member-ban-description = { $user } đã bị ban
+log-member-unban = Thành viên được unban
+log-member-unban-description = { $user } đã được unban
+log-member-role-add = Đã thêm roles
+log-member-role-remove = Đã xoá roles
+log-member-nickname = Đổi biệt danh
+log-member-update-description = { $user } đã được cập nhật
+log-channel-create = Kênh mới được tạo
+log-channel-delete = Kênh đã bị xoá
+log-channel-update = Kênh đã được cập nhật
+log-channel-description = { $channel } (`{ $name }`)
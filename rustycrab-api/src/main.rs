@@ -1,8 +1,14 @@
-use rustycrab_api::{ app_state::AppState, run, running_bots };
+use rustycrab_api::{
+    app_state::AppState,
+    run,
+    running_bots,
+    twilightrs::commands::slash::command_sync::CommandScope,
+};
 
 use dotenv::dotenv;
 use sea_orm::Database;
 use std::env;
+use twilight_model::id::Id;
 // use std::collections::HashMap;
 // use tokio::sync::RwLock;
 // use std::sync::Arc;
@@ -25,8 +31,24 @@ async fn main() {
         }
     };
 
+    // `--sync-commands` syncs the global slash commands of the bots when they start, and
+    // `--sync-commands=<guild id>,<guild id>` syncs them in these (development) guilds only
+    let mut sync_commands: Vec<CommandScope> = Vec::new();
+    for arg in env::args() {
+        if arg == "--sync-commands" {
+            sync_commands.push(CommandScope::Global);
+        } else if let Some(guild_ids) = arg.strip_prefix("--sync-commands=") {
+            for guild_id in guild_ids.split(',') {
+                match guild_id.trim().parse::<u64>().ok().and_then(Id::new_checked) {
+                    Some(guild_id) => sync_commands.push(CommandScope::Guild(guild_id)),
+                    None => log::error!("Invalid guild ID to sync commands in: {}", guild_id),
+                }
+            }
+        }
+    }
+
     // Retrieve all bots from the database and their running state
-    let running_bots = running_bots(&db).await.expect("Failed to run Discord bots");
+    let running_bots = running_bots(&db, sync_commands).await.expect("Failed to run Discord bots");
 
     // Check if `--w-api` argument is passed
     if env::args().any(|arg| arg == "--api") {
@@ -0,0 +1,177 @@
+//! Prometheus metrics of the bots and the API.
+//!
+//! Every bot of the process shares the same registry, bot metrics are labelled with the bot's
+//! Discord ID. The metrics are exported in the text format on the `/metrics` route.
+
+use std::{ sync::OnceLock, time::Duration };
+
+use prometheus::{
+    Encoder,
+    HistogramOpts,
+    HistogramVec,
+    IntCounterVec,
+    IntGaugeVec,
+    Opts,
+    Registry,
+    TextEncoder,
+};
+
+use crate::utilities::app_error::BoxedError;
+
+pub struct Metrics {
+    registry: Registry,
+    gateway_events: IntCounterVec,
+    commands: IntCounterVec,
+    command_errors: IntCounterVec,
+    command_duration: HistogramVec,
+    autores_hits: IntCounterVec,
+    tracks_played: IntCounterVec,
+    track_failures: IntCounterVec,
+    voice_connections: IntGaugeVec,
+    tickets_opened: IntCounterVec,
+    tickets_closed: IntCounterVec,
+    http_request_duration: HistogramVec,
+}
+
+/// The metrics shared by the whole process.
+pub fn metrics() -> &'static Metrics {
+    static METRICS: OnceLock<Metrics> = OnceLock::new();
+    METRICS.get_or_init(Metrics::new)
+}
+
+fn counter(registry: &Registry, name: &str, help: &str, labels: &[&str]) -> IntCounterVec {
+    let counter = IntCounterVec::new(Opts::new(name, help), labels).expect("valid counter");
+    registry.register(Box::new(counter.clone())).expect("unique counter name");
+    counter
+}
+
+fn histogram(registry: &Registry, name: &str, help: &str, labels: &[&str]) -> HistogramVec {
+    let histogram = HistogramVec::new(HistogramOpts::new(name, help), labels).expect(
+        "valid histogram"
+    );
+    registry.register(Box::new(histogram.clone())).expect("unique histogram name");
+    histogram
+}
+
+impl Metrics {
+    fn new() -> Self {
+        let registry = Registry::new_custom(Some("rustycrab".to_string()), None).expect(
+            "valid registry prefix"
+        );
+
+        let voice_connections = IntGaugeVec::new(
+            Opts::new("voice_connections", "Active voice connections"),
+            &["bot"]
+        ).expect("valid gauge");
+        registry.register(Box::new(voice_connections.clone())).expect("unique gauge name");
+
+        Self {
+            gateway_events: counter(
+                &registry,
+                "gateway_events_total",
+                "Gateway events received",
+                &["bot", "event"]
+            ),
+            commands: counter(
+                &registry,
+                "commands_total",
+                "Commands invoked",
+                &["bot", "kind", "command"]
+            ),
+            command_errors: counter(
+                &registry,
+                "command_errors_total",
+                "Commands that returned an error",
+                &["bot", "kind", "command"]
+            ),
+            command_duration: histogram(
+                &registry,
+                "command_duration_seconds",
+                "Time spent running commands",
+                &["bot", "kind", "command"]
+            ),
+            autores_hits: counter(
+                &registry,
+                "autores_hits_total",
+                "Messages answered by an auto-response",
+                &["bot"]
+            ),
+            tracks_played: counter(&registry, "music_tracks_played_total", "Tracks played", &["bot"]),
+            track_failures: counter(
+                &registry,
+                "music_track_failures_total",
+                "Tracks that failed to play",
+                &["bot"]
+            ),
+            voice_connections,
+            tickets_opened: counter(&registry, "tickets_opened_total", "Tickets opened", &["bot"]),
+            tickets_closed: counter(&registry, "tickets_closed_total", "Tickets closed", &["bot"]),
+            http_request_duration: histogram(
+                &registry,
+                "http_request_duration_seconds",
+                "Time spent answering API requests",
+                &["method", "route", "status"]
+            ),
+            registry,
+        }
+    }
+
+    pub fn record_gateway_event(&self, bot: &str, event: &str) {
+        self.gateway_events.with_label_values(&[bot, event]).inc();
+    }
+
+    /// Records a command run, `kind` being `context`, `slash` or `menu` for context menus.
+    pub fn record_command(
+        &self,
+        bot: &str,
+        kind: &str,
+        command: &str,
+        duration: Duration,
+        failed: bool
+    ) {
+        let labels = [bot, kind, command];
+        self.commands.with_label_values(&labels).inc();
+        self.command_duration.with_label_values(&labels).observe(duration.as_secs_f64());
+        if failed {
+            self.command_errors.with_label_values(&labels).inc();
+        }
+    }
+
+    pub fn record_autores_hit(&self, bot: &str) {
+        self.autores_hits.with_label_values(&[bot]).inc();
+    }
+
+    pub fn record_track_played(&self, bot: &str) {
+        self.tracks_played.with_label_values(&[bot]).inc();
+    }
+
+    pub fn record_track_failure(&self, bot: &str) {
+        self.track_failures.with_label_values(&[bot]).inc();
+    }
+
+    pub fn set_voice_connections(&self, bot: &str, connections: usize) {
+        self.voice_connections.with_label_values(&[bot]).set(connections as i64);
+    }
+
+    pub fn record_ticket_opened(&self, bot: &str) {
+        self.tickets_opened.with_label_values(&[bot]).inc();
+    }
+
+    pub fn record_ticket_closed(&self, bot: &str) {
+        self.tickets_closed.with_label_values(&[bot]).inc();
+    }
+
+    pub fn record_http_request(&self, method: &str, route: &str, status: u16, duration: Duration) {
+        self.http_request_duration
+            .with_label_values(&[method, route, &status.to_string()])
+            .observe(duration.as_secs_f64());
+    }
+
+    /// Encodes every metric in the Prometheus text format.
+    pub fn encode(&self) -> Result<String, BoxedError> {
+        let mut buffer = Vec::new();
+        TextEncoder::new().encode(&self.registry.gather(), &mut buffer)?;
+
+        Ok(String::from_utf8(buffer)?)
+    }
+}
@@ -0,0 +1,126 @@
+use async_trait::async_trait;
+use rustycrab_model::response::auto_role::{ RequestCreateAutoRole, RequestUpdateAutoRole };
+use sea_orm::{
+    DatabaseConnection,
+    Set,
+    EntityTrait,
+    QueryFilter,
+    Condition,
+    QuerySelect,
+    ColumnTrait,
+    RelationTrait,
+};
+
+use crate::{
+    default_queries::DefaultSeaQueries,
+    database::{
+        auto_roles::{ Entity as AutoRoles, ActiveModel as AutoRoleActiveModel, self },
+        bots,
+        guild_info,
+    },
+    utilities::app_error::AppError,
+    multi_bot_guild_entities_queries::MultipleBotGuildEntityQueries,
+};
+
+use super::{ bot_queries::BotQueries, guild_queries::GuildQueries };
+
+pub struct AutoRolesQueries {}
+
+impl AutoRolesQueries {
+    pub async fn find_by_role_id(
+        db: &DatabaseConnection,
+        bot_discord_id: &str,
+        guild_discord_id: &str,
+        role_id: &str,
+        r#type: &str
+    ) -> Result<<<Self as DefaultSeaQueries>::Entity as EntityTrait>::Model, AppError> {
+        <<Self as DefaultSeaQueries>::Entity as EntityTrait>
+            ::find()
+            .join(
+                sea_orm::JoinType::LeftJoin,
+                <Self as MultipleBotGuildEntityQueries>::bot_relation()
+            )
+            .join(
+                sea_orm::JoinType::LeftJoin,
+                <Self as MultipleBotGuildEntityQueries>::guild_relation()
+            )
+            .filter(
+                Condition::all()
+                    .add(bots::Column::BotId.eq(bot_discord_id))
+                    .add(guild_info::Column::GuildId.eq(guild_discord_id))
+                    .add(auto_roles::Column::RoleId.eq(role_id))
+                    .add(auto_roles::Column::Type.eq(r#type))
+            )
+            .one(db).await
+            .map_err(AppError::from)?
+            .ok_or_else(|| AppError::not_found("Auto-role not found"))
+    }
+}
+
+impl MultipleBotGuildEntityQueries for AutoRolesQueries {
+    fn bot_relation() -> sea_orm::entity::RelationDef {
+        auto_roles::Relation::Bots.def()
+    }
+
+    fn guild_relation() -> sea_orm::entity::RelationDef {
+        auto_roles::Relation::GuildInfo.def()
+    }
+}
+
+#[async_trait]
+impl DefaultSeaQueries for AutoRolesQueries {
+    type Entity = AutoRoles;
+    type ActiveModel = AutoRoleActiveModel;
+
+    type CreateData = RequestCreateAutoRole;
+    type UpdateData = RequestUpdateAutoRole;
+
+    async fn create_entity(
+        db: &DatabaseConnection,
+        create_data: Self::CreateData
+    ) -> Result<<Self::Entity as EntityTrait>::Model, AppError> {
+        if
+            let Ok(entity) = Self::find_by_role_id(
+                db,
+                &create_data.bot_discord_id,
+                &create_data.guild_discord_id,
+                &create_data.role_id,
+                &create_data.r#type
+            ).await
+        {
+            return Ok(entity);
+        }
+
+        let bot = BotQueries::find_by_discord_id(db, &create_data.bot_discord_id).await?;
+        let guild = GuildQueries::find_one_or_create(db, &create_data.guild_discord_id).await?;
+
+        let active_model = Self::ActiveModel {
+            bot_id: Set(bot.id),
+            guild_id: Set(guild.id),
+            r#type: Set(create_data.r#type),
+            role_id: Set(create_data.role_id),
+            delay: Set(create_data.delay.unwrap_or(0)),
+            ..Default::default()
+        };
+
+        Self::save_active_model(db, active_model).await
+    }
+
+    async fn apply_updates(
+        _: &DatabaseConnection,
+        active_model: &mut Self::ActiveModel,
+        update_data: Self::UpdateData
+    ) -> Result<(), AppError> {
+        if let Some(value) = update_data.r#type {
+            active_model.r#type = Set(value);
+        }
+        if let Some(value) = update_data.role_id {
+            active_model.role_id = Set(value);
+        }
+        if let Some(value) = update_data.delay {
+            active_model.delay = Set(value);
+        }
+
+        Ok(())
+    }
+}
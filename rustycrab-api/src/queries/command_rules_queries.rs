@@ -0,0 +1,133 @@
+use async_trait::async_trait;
+use rustycrab_model::{
+    modules::GuildModule,
+    response::command_rule::{ RequestCreateCommandRule, RequestUpdateCommandRule },
+};
+use sea_orm::{ DatabaseConnection, Set, EntityTrait, RelationTrait };
+
+use crate::{
+    default_queries::DefaultSeaQueries,
+    database::command_rules::{
+        Entity as CommandRules,
+        ActiveModel as CommandRuleActiveModel,
+        self,
+    },
+    utilities::app_error::AppError,
+    multi_bot_guild_entities_queries::MultipleBotGuildEntityQueries,
+    twilightrs::commands::context::context_command_dispatcher::ContextCommandDispatcher,
+};
+
+use super::{ bot_queries::BotQueries, guild_queries::GuildQueries };
+
+pub struct CommandRulesQueries {}
+
+impl CommandRulesQueries {
+    /// Validates a rule, returns the name it's saved with: commands are saved with their full
+    /// name, so rules on an alias apply to the command.
+    fn validate(target: &str, name: &str, subject: &str) -> Result<String, AppError> {
+        let name = name.trim().to_lowercase();
+        let name = match target {
+            "command" => {
+                match ContextCommandDispatcher::shared().canonical_command_name(&name) {
+                    Some(name) => name,
+                    None => {
+                        return Err(AppError::bad_request("Unknown command"));
+                    }
+                }
+            }
+            "category" => name,
+            "module" if GuildModule::from_name(&name).is_some() => name,
+            "module" => {
+                return Err(AppError::bad_request("Unknown module"));
+            }
+            _ => {
+                return Err(
+                    AppError::bad_request("Rule target must be `command`, `category` or `module`")
+                );
+            }
+        };
+
+        if !["channel", "role", "user"].contains(&subject) {
+            return Err(AppError::bad_request("Rule subject must be `channel`, `role` or `user`"));
+        }
+
+        Ok(name)
+    }
+}
+
+impl MultipleBotGuildEntityQueries for CommandRulesQueries {
+    fn bot_relation() -> sea_orm::entity::RelationDef {
+        command_rules::Relation::Bots.def()
+    }
+
+    fn guild_relation() -> sea_orm::entity::RelationDef {
+        command_rules::Relation::GuildInfo.def()
+    }
+}
+
+#[async_trait]
+impl DefaultSeaQueries for CommandRulesQueries {
+    type Entity = CommandRules;
+    type ActiveModel = CommandRuleActiveModel;
+
+    type CreateData = RequestCreateCommandRule;
+    type UpdateData = RequestUpdateCommandRule;
+
+    async fn create_entity(
+        db: &DatabaseConnection,
+        create_data: Self::CreateData
+    ) -> Result<<Self::Entity as EntityTrait>::Model, AppError> {
+        let name = Self::validate(
+            &create_data.target,
+            &create_data.name,
+            &create_data.subject
+        )?;
+
+        let bot = BotQueries::find_by_discord_id(db, &create_data.bot_discord_id).await?;
+        let guild = GuildQueries::find_one_or_create(db, &create_data.guild_discord_id).await?;
+
+        let active_model = Self::ActiveModel {
+            bot_id: Set(bot.id),
+            guild_id: Set(guild.id),
+            target: Set(create_data.target),
+            name: Set(name),
+            subject: Set(create_data.subject),
+            subject_id: Set(create_data.subject_id),
+            allow: Set(create_data.allow.unwrap_or(0)),
+            ..Default::default()
+        };
+
+        Self::save_active_model(db, active_model).await
+    }
+
+    async fn apply_updates(
+        _: &DatabaseConnection,
+        active_model: &mut Self::ActiveModel,
+        update_data: Self::UpdateData
+    ) -> Result<(), AppError> {
+        if let Some(value) = update_data.target {
+            active_model.target = Set(value);
+        }
+        if let Some(value) = update_data.name {
+            active_model.name = Set(value);
+        }
+        if let Some(value) = update_data.subject {
+            active_model.subject = Set(value);
+        }
+        if let Some(value) = update_data.subject_id {
+            active_model.subject_id = Set(value);
+        }
+        if let Some(value) = update_data.allow {
+            active_model.allow = Set(value);
+        }
+
+        let name = Self::validate(
+            active_model.target.as_ref(),
+            active_model.name.as_ref(),
+            active_model.subject.as_ref()
+        )?;
+        active_model.name = Set(name);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,256 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use rustycrab_model::response::command_usage::{
+    RequestCreateCommandUsage,
+    RequestUpdateCommandUsage,
+    ResponseCommandStats,
+    ResponseCommandUsageStats,
+    ResponseDailyUsage,
+};
+use sea_orm::{
+    sea_query::Expr,
+    ColumnTrait,
+    DatabaseConnection,
+    EntityTrait,
+    FromQueryResult,
+    QueryFilter,
+    QueryOrder,
+    QuerySelect,
+    RelationTrait,
+    Set,
+};
+
+use crate::{
+    default_queries::DefaultSeaQueries,
+    database::{
+        bot_guild_configurations::Model as GuildConfigModel,
+        command_usages::{
+            Entity as CommandUsages,
+            ActiveModel as CommandUsageActiveModel,
+            self,
+        },
+    },
+    utilities::app_error::AppError,
+    multi_bot_guild_entities_queries::MultipleBotGuildEntityQueries,
+};
+
+use super::{ bot_queries::BotQueries, guild_queries::GuildQueries };
+
+/// Usages older than this many days are deleted, unless `COMMAND_USAGE_RETENTION_DAYS` is set.
+pub const DEFAULT_RETENTION_DAYS: u64 = 30;
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+#[derive(FromQueryResult)]
+struct CommandCount {
+    command: String,
+    uses: i64,
+    errors: i64,
+    average_duration_ms: i64,
+}
+
+#[derive(FromQueryResult)]
+struct DayCount {
+    day: i64,
+    uses: i64,
+    errors: i64,
+}
+
+/// A command invocation to record.
+pub struct CommandUsageRecord {
+    /// Full name of the command, e.g. `autores add`
+    pub command: String,
+    /// `context`, `slash` or `menu`
+    pub kind: &'static str,
+    pub channel_id: String,
+    pub user_id: String,
+    pub duration: Duration,
+    pub success: bool,
+}
+
+fn error_rate(uses: i64, errors: i64) -> f64 {
+    if uses == 0 { 0.0 } else { (errors as f64) / (uses as f64) }
+}
+
+pub struct CommandUsagesQueries {}
+
+impl CommandUsagesQueries {
+    /// How long usages are kept, read from `COMMAND_USAGE_RETENTION_DAYS`.
+    pub fn retention() -> Duration {
+        let days = std::env
+            ::var("COMMAND_USAGE_RETENTION_DAYS")
+            .ok()
+            .and_then(|days| days.parse::<u64>().ok())
+            .unwrap_or(DEFAULT_RETENTION_DAYS);
+
+        Duration::from_secs(days * (SECONDS_PER_DAY as u64))
+    }
+
+    /// Records a command invocation in the guild of `config`.
+    pub async fn record(
+        db: &DatabaseConnection,
+        config: &GuildConfigModel,
+        usage: CommandUsageRecord
+    ) -> Result<(), AppError> {
+        // the configuration already knows the bot and guild rows, saving two lookups
+        let active_model = CommandUsageActiveModel {
+            command: Set(usage.command),
+            kind: Set(usage.kind.to_string()),
+            channel_id: Set(usage.channel_id),
+            user_id: Set(usage.user_id),
+            duration_ms: Set(usage.duration.as_millis() as i64),
+            success: Set(usage.success as i8),
+            created_at: Set(chrono::Utc::now().timestamp()),
+            bot_id: Set(config.bot_id),
+            guild_id: Set(config.guild_id),
+            ..Default::default()
+        };
+        CommandUsages::insert(active_model).exec(db).await.map_err(AppError::from)?;
+
+        Ok(())
+    }
+
+    /// Deletes the usages older than the retention, returns how many were deleted.
+    pub async fn prune(db: &DatabaseConnection, retention: Duration) -> Result<u64, AppError> {
+        let oldest = chrono::Utc::now().timestamp() - (retention.as_secs() as i64);
+        let result = CommandUsages::delete_many()
+            .filter(command_usages::Column::CreatedAt.lt(oldest))
+            .exec(db).await
+            .map_err(AppError::from)?;
+
+        Ok(result.rows_affected)
+    }
+
+    /// Aggregates the usages of the last `days` days in a guild.
+    pub async fn stats(
+        db: &DatabaseConnection,
+        bot_discord_id: &str,
+        guild_discord_id: &str,
+        days: u64,
+        limit: u64
+    ) -> Result<ResponseCommandUsageStats, AppError> {
+        let bot = BotQueries::find_by_discord_id(db, bot_discord_id).await?;
+        let guild = GuildQueries::find_by_discord_id(db, guild_discord_id).await?;
+
+        let now = chrono::Utc::now().timestamp();
+        // counted from the start of the oldest day, so every day bucket is complete
+        let since = (now - (days as i64) * SECONDS_PER_DAY).div_euclid(SECONDS_PER_DAY) *
+            SECONDS_PER_DAY;
+
+        let usages = CommandUsages::find()
+            .filter(command_usages::Column::BotId.eq(bot.id))
+            .filter(command_usages::Column::GuildId.eq(guild.id))
+            .filter(command_usages::Column::CreatedAt.gte(since));
+
+        let top_commands: Vec<ResponseCommandStats> = usages
+            .clone()
+            .select_only()
+            .column(command_usages::Column::Command)
+            .column_as(Expr::cust("COUNT(*)"), "uses")
+            .column_as(Expr::cust("CAST(SUM(1 - `success`) AS SIGNED)"), "errors")
+            .column_as(Expr::cust("CAST(AVG(`durationMs`) AS SIGNED)"), "average_duration_ms")
+            .group_by(command_usages::Column::Command)
+            .order_by_desc(Expr::cust("uses"))
+            .limit(limit)
+            .into_model::<CommandCount>()
+            .all(db).await
+            .map_err(AppError::from)?
+            .into_iter()
+            .map(|count| ResponseCommandStats {
+                error_rate: error_rate(count.uses, count.errors),
+                command: count.command,
+                uses: count.uses,
+                errors: count.errors,
+                average_duration_ms: count.average_duration_ms,
+            })
+            .collect();
+
+        let day_start = format!("CAST(FLOOR(`createdAt` / {0}) * {0} AS SIGNED)", SECONDS_PER_DAY);
+        let daily: Vec<ResponseDailyUsage> = usages
+            .select_only()
+            .column_as(Expr::cust(day_start), "day")
+            .column_as(Expr::cust("COUNT(*)"), "uses")
+            .column_as(Expr::cust("CAST(SUM(1 - `success`) AS SIGNED)"), "errors")
+            .group_by(Expr::cust("day"))
+            .order_by_asc(Expr::cust("day"))
+            .into_model::<DayCount>()
+            .all(db).await
+            .map_err(AppError::from)?
+            .into_iter()
+            .map(|count| ResponseDailyUsage {
+                day: count.day,
+                uses: count.uses,
+                errors: count.errors,
+            })
+            .collect();
+
+        let uses: i64 = daily
+            .iter()
+            .map(|day| day.uses)
+            .sum();
+        let errors: i64 = daily
+            .iter()
+            .map(|day| day.errors)
+            .sum();
+
+        Ok(ResponseCommandUsageStats {
+            since,
+            uses,
+            errors,
+            error_rate: error_rate(uses, errors),
+            top_commands,
+            daily,
+        })
+    }
+}
+
+impl MultipleBotGuildEntityQueries for CommandUsagesQueries {
+    fn bot_relation() -> sea_orm::entity::RelationDef {
+        command_usages::Relation::Bots.def()
+    }
+
+    fn guild_relation() -> sea_orm::entity::RelationDef {
+        command_usages::Relation::GuildInfo.def()
+    }
+}
+
+#[async_trait]
+impl DefaultSeaQueries for CommandUsagesQueries {
+    type Entity = CommandUsages;
+    type ActiveModel = CommandUsageActiveModel;
+
+    type CreateData = RequestCreateCommandUsage;
+    type UpdateData = RequestUpdateCommandUsage;
+
+    async fn create_entity(
+        db: &DatabaseConnection,
+        create_data: Self::CreateData
+    ) -> Result<<Self::Entity as EntityTrait>::Model, AppError> {
+        let bot = BotQueries::find_by_discord_id(db, &create_data.bot_discord_id).await?;
+        let guild = GuildQueries::find_one_or_create(db, &create_data.guild_discord_id).await?;
+
+        let active_model = Self::ActiveModel {
+            command: Set(create_data.command),
+            kind: Set(create_data.kind),
+            channel_id: Set(create_data.channel_id),
+            user_id: Set(create_data.user_id),
+            duration_ms: Set(create_data.duration_ms),
+            success: Set(create_data.success),
+            created_at: Set(chrono::Utc::now().timestamp()),
+            bot_id: Set(bot.id),
+            guild_id: Set(guild.id),
+            ..Default::default()
+        };
+
+        Self::save_active_model(db, active_model).await
+    }
+
+    async fn apply_updates(
+        _: &DatabaseConnection,
+        _: &mut Self::ActiveModel,
+        _: Self::UpdateData
+    ) -> Result<(), AppError> {
+        Err(AppError::bad_request("Command usages can't be updated"))
+    }
+}
@@ -1,7 +1,12 @@
 // queries/guild_config_queries.rs
+use std::collections::HashMap;
+
 use async_trait::async_trait;
 
-use rustycrab_model::response::bot_guild_config::{ RequestCreateConfig, RequestUpdateConfig };
+use rustycrab_model::{
+    cooldown::CommandCooldown,
+    response::bot_guild_config::{ RequestCreateConfig, RequestUpdateConfig },
+};
 use sea_orm::{ ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, Set, RelationTrait };
 
 use crate::{
@@ -21,6 +26,11 @@ use crate::{
 
 use super::{ bot_queries::BotQueries, guild_queries::GuildQueries };
 
+/// Most prefixes a guild can have, counting the main one.
+pub const MAX_PREFIXES: usize = 5;
+/// Longest a prefix can be, in characters.
+pub const MAX_PREFIX_LENGTH: usize = 16;
+
 pub struct GuildConfigQueries {}
 
 impl GuildConfigQueries {
@@ -58,6 +68,35 @@ impl GuildConfigQueries {
             )
         }
     }
+
+    /// The guild's cooldown overrides, keyed by the full command name.
+    pub fn command_cooldowns(config: &GuildConfigModel) -> HashMap<String, CommandCooldown> {
+        config.command_cooldowns
+            .as_deref()
+            .and_then(|json| serde_json::from_str(json).ok())
+            .unwrap_or_default()
+    }
+
+    /// The prefixes accepted besides the main one.
+    pub fn extra_prefixes(config: &GuildConfigModel) -> Vec<String> {
+        config.extra_prefixes
+            .as_deref()
+            .and_then(|json| serde_json::from_str(json).ok())
+            .unwrap_or_default()
+    }
+
+    /// Every prefix of the guild, the main one first, without the ones only differing by case.
+    pub fn prefixes(config: &GuildConfigModel) -> Vec<String> {
+        let mut prefixes: Vec<String> = Vec::new();
+        for prefix in std::iter::once(config.prefix.clone()).chain(Self::extra_prefixes(config)) {
+            let known = prefixes.iter().any(|known| known.to_lowercase() == prefix.to_lowercase());
+            if !prefix.is_empty() && !known {
+                prefixes.push(prefix);
+            }
+        }
+
+        prefixes
+    }
 }
 
 impl UniqueBotGuildEntityQueries for GuildConfigQueries {
@@ -124,6 +163,48 @@ impl DefaultSeaQueries for GuildConfigQueries {
             active_model.premium_flags = Set(value);
         }
 
+        if let Some(value) = update_data.command_cooldowns {
+            if value.values().any(|cooldown| cooldown.burst == 0 || cooldown.window == 0) {
+                return Err(AppError::bad_request("Cooldown burst and window must be positive"));
+            }
+
+            let json = serde_json
+                ::to_string(&value)
+                .map_err(|e| AppError::internal_server_error(e.to_string()))?;
+            active_model.command_cooldowns = Set((!value.is_empty()).then_some(json));
+        }
+
+        if let Some(value) = update_data.command_suggestions {
+            active_model.command_suggestions = Set(value);
+        }
+
+        if let Some(value) = update_data.extra_prefixes {
+            let invalid = |prefix: &String| {
+                prefix.trim().is_empty() || prefix.chars().count() > MAX_PREFIX_LENGTH
+            };
+            if value.iter().any(invalid) {
+                let message = format!(
+                    "Prefixes can't be blank or longer than {} characters",
+                    MAX_PREFIX_LENGTH
+                );
+                return Err(AppError::bad_request(message));
+            }
+            // the main prefix counts too
+            if value.len() >= MAX_PREFIXES {
+                let message = format!("A guild can have at most {} prefixes", MAX_PREFIXES);
+                return Err(AppError::bad_request(message));
+            }
+
+            let json = serde_json
+                ::to_string(&value)
+                .map_err(|e| AppError::internal_server_error(e.to_string()))?;
+            active_model.extra_prefixes = Set((!value.is_empty()).then_some(json));
+        }
+
+        if let Some(value) = update_data.mention_prefix {
+            active_model.mention_prefix = Set(value);
+        }
+
         Ok(())
     }
 }
@@ -74,6 +74,23 @@ impl DefaultSeaQueries for LogSettingQueries {
             active_model.new_account_age = Set(value);
         }
 
+        if let Some(value) = update_data.new_account_action {
+            // an empty action turns it off
+            active_model.new_account_action = Set(
+                if value.is_empty() { None } else { Some(value) }
+            );
+        }
+
+        if let Some(value) = update_data.new_account_timeout {
+            active_model.new_account_timeout = Set(Some(value));
+        }
+
+        if let Some(value) = update_data.quarantine_role_id {
+            active_model.quarantine_role_id = Set(
+                if value.is_empty() { None } else { Some(value) }
+            );
+        }
+
         Ok(())
     }
 }
@@ -21,6 +21,7 @@ pub enum MessageEvents {
     MessageBulkDelete = 1,
     MessageEdit = 2,
     GuildInvites = 3,
+    MessageReport = 4,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Primitive)]
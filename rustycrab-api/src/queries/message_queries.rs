@@ -91,7 +91,8 @@ impl DefaultSeaQueries for MessageQueries {
                 println!("update embed {}", e_id);
                 MessageEmbedQueries::update_by_id(db, e_id, embed_data).await?;
             } else {
-                MessageEmbedQueries::create_entity(db, embed_data).await?;
+                let embed = MessageEmbedQueries::create_entity(db, embed_data).await?;
+                active_model.embed_id = Set(Some(embed.id));
             }
         }
         Ok(())
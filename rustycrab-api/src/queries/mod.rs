@@ -4,6 +4,7 @@ pub mod guild_queries;
 pub mod user_queries;
 pub mod bot_user_queries;
 pub mod guild_welcome_queries;
+pub mod guild_goodbye_queries;
 pub mod message_button_queries;
 pub mod message_embed_queries;
 pub mod message_queries;
@@ -12,6 +13,9 @@ pub mod tickets_system;
 pub mod auto_responses_queries;
 pub mod marriages_queries;
 pub mod item_queries;
+pub mod auto_roles_queries;
+pub mod command_rules_queries;
+pub mod command_usages_queries;
 
 use crate::utilities::app_error::AppError;
 use axum::http::StatusCode;
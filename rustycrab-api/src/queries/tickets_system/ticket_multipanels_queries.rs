@@ -243,18 +243,7 @@ pub async fn create_button_components(
             Component::Button(Button {
                 custom_id: Some(format!("1:1:{}", subpanel.id)),
                 disabled: false,
-                emoji: if button.emoji.len() > 10 {
-                    let emoji_id = u64
-                        ::from_str_radix(&button.emoji, 10)
-                        .map_err(|_| { AppError::bad_request("Invalid emoji ID") })?;
-                    Some(ReactionType::Custom {
-                        animated: false,
-                        id: Id::new(emoji_id),
-                        name: None,
-                    })
-                } else {
-                    Some(ReactionType::Unicode { name: button.emoji })
-                },
+                emoji: Some(button_emoji(button.emoji)?),
                 label: Some(format!("{}", button.text)),
                 style: color_to_button_style(&button.color),
                 url: None,
@@ -264,3 +253,19 @@ pub async fn create_button_components(
 
     Ok(components)
 }
+
+/// Emojis of panel buttons are saved as the ID of a custom emoji or as a unicode emoji.
+pub fn button_emoji(emoji: String) -> Result<ReactionType, AppError> {
+    if emoji.len() > 10 {
+        let emoji_id = u64
+            ::from_str_radix(&emoji, 10)
+            .map_err(|_| { AppError::bad_request("Invalid emoji ID") })?;
+        Ok(ReactionType::Custom {
+            animated: false,
+            id: Id::new(emoji_id),
+            name: None,
+        })
+    } else {
+        Ok(ReactionType::Unicode { name: emoji })
+    }
+}
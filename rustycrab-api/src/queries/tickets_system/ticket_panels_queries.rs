@@ -49,9 +49,37 @@ use super::{
     ticket_support_team_queries::TicketSupportTeamQueries,
 };
 
+/// Most questions a panel asks, a modal holds at most 5 text inputs.
+pub const MAX_PANEL_QUESTIONS: usize = 5;
+
 pub struct TicketPanelsQueries {}
 
 impl TicketPanelsQueries {
+    /// The questions asked before opening a ticket, stored one per line.
+    pub fn questions(
+        panel: &<<Self as DefaultSeaQueries>::Entity as EntityTrait>::Model
+    ) -> Vec<String> {
+        panel.questions
+            .as_deref()
+            .unwrap_or_default()
+            .lines()
+            .map(|question| question.trim().to_string())
+            .filter(|question| !question.is_empty())
+            .take(MAX_PANEL_QUESTIONS)
+            .collect()
+    }
+
+    fn join_questions(questions: Vec<String>) -> Option<String> {
+        let questions: Vec<String> = questions
+            .iter()
+            .map(|question| question.replace('\n', " ").trim().to_string())
+            .filter(|question| !question.is_empty())
+            .take(MAX_PANEL_QUESTIONS)
+            .collect();
+
+        if questions.is_empty() { None } else { Some(questions.join("\n")) }
+    }
+
     pub async fn find_panels_by_discord_ids(
         db: &DatabaseConnection,
         bot_discord_id: &str,
@@ -110,6 +138,7 @@ impl TicketPanelsQueries {
             message,
             button,
             welcome_message,
+            questions: panel.questions.clone(),
             mention_on_open: panel.mention_on_open.clone(),
             naming_scheme: panel.naming_scheme.clone(),
             channel_id: panel.channel_id.clone(),
@@ -152,6 +181,7 @@ impl DefaultSeaQueries for TicketPanelsQueries {
             button_id: Set(button.id),
             welcome_message_id: Set(welcome_message.id),
             support_team_id: Set(Some(create_data.support_team_id)),
+            questions: Set(create_data.questions.and_then(Self::join_questions)),
             ..Default::default()
         }).await
     }
@@ -181,6 +211,10 @@ impl DefaultSeaQueries for TicketPanelsQueries {
             active_model.support_team_id = Set(Some(value));
         }
 
+        if let Some(value) = update_data.questions {
+            active_model.questions = Set(Self::join_questions(value));
+        }
+
         println!("Current message_id in active_model: {:?}", active_model.message_id);
         if let Some(data) = update_data.message_data {
             println!("Message data exists: {:?}", data);
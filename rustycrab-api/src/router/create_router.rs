@@ -3,18 +3,28 @@ use crate::{
         bots::BotsRouter,
         bot_guild_configs::BotGuildConfigsRoutes,
         bot_guild_welcomes::BotGuildWelcomesRoutes,
+        bot_guild_goodbyes::BotGuildGoodbyesRoutes,
         bot_logs::bot_logs_routes,
+        auto_roles::AutoRolesRoutes,
+        command_rules::CommandRulesRoutes,
     },
     app_state::AppState,
     default_router::DefaultRoutes,
     unique_bot_guild_entity_router::UniqueBotGuildEntityRoutes,
+    multi_bot_guild_entities_router::MultipleBotGuildEntitiesRoutes,
 };
 
 use axum::{ routing::get, Router, Extension, middleware };
 
 use super::{
-    middlewares::log_route::log_route,
-    routes::{ tickets::ticket_routes, discord_oauth::auth_routes },
+    middlewares::{ log_route::log_route, track_metrics::track_metrics },
+    routes::{
+        tickets::ticket_routes,
+        discord_oauth::auth_routes,
+        metrics::metrics_routes,
+        command_catalog::command_catalog_routes,
+        command_usages::command_usages_routes,
+    },
 };
 
 pub async fn create_router(app_state: AppState) -> Router {
@@ -22,13 +32,20 @@ pub async fn create_router(app_state: AppState) -> Router {
         .merge(BotsRouter::router().await)
         .merge(<BotGuildConfigsRoutes as UniqueBotGuildEntityRoutes>::router().await)
         .merge(<BotGuildWelcomesRoutes as UniqueBotGuildEntityRoutes>::router().await)
+        .merge(<BotGuildGoodbyesRoutes as UniqueBotGuildEntityRoutes>::router().await)
         .merge(bot_logs_routes().await)
         .merge(ticket_routes().await)
+        .merge(<AutoRolesRoutes as MultipleBotGuildEntitiesRoutes>::router().await)
+        .merge(<CommandRulesRoutes as MultipleBotGuildEntitiesRoutes>::router().await)
+        .merge(command_usages_routes().await)
+        .merge(metrics_routes().await)
+        .merge(command_catalog_routes().await)
         .layer(Extension(app_state.clone()))
         .merge(auth_routes().await)
         .route(
             "/",
             get(|| async { "Hello, World!" })
         )
+        .layer(middleware::from_fn(track_metrics))
         .layer(middleware::from_fn(log_route))
 }
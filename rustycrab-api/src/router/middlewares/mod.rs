@@ -1 +1,2 @@
 pub mod log_route;
+pub mod track_metrics;
@@ -0,0 +1,23 @@
+use std::time::Instant;
+
+use axum::{ response::Response, middleware::Next, extract::{ Request, MatchedPath } };
+
+use crate::metrics::metrics;
+
+/// Records the latency of every request, by route and response status.
+pub async fn track_metrics(req: Request, next: Next) -> Response {
+    let method = req.method().as_str().to_uppercase();
+    // use the route pattern rather than the path, to keep IDs out of the labels
+    let route = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| "unmatched".to_string());
+
+    let started_at = Instant::now();
+    let response = next.run(req).await;
+
+    metrics().record_http_request(&method, &route, response.status().as_u16(), started_at.elapsed());
+
+    response
+}
@@ -0,0 +1,37 @@
+use async_trait::async_trait;
+use rustycrab_model::response::auto_role::ResponseAutoRole;
+
+use crate::{
+    database::auto_roles::Model as AutoRoleModel,
+    default_router::DefaultRoutes,
+    queries::auto_roles_queries::AutoRolesQueries,
+    multi_bot_guild_entities_router::MultipleBotGuildEntitiesRoutes,
+};
+
+impl From<AutoRoleModel> for ResponseAutoRole {
+    fn from(model: AutoRoleModel) -> Self {
+        ResponseAutoRole {
+            id: model.id,
+            r#type: model.r#type,
+            role_id: model.role_id,
+            delay: model.delay,
+            bot_id: model.bot_id,
+            guild_id: model.guild_id,
+        }
+    }
+}
+
+pub struct AutoRolesRoutes {}
+
+#[async_trait]
+impl DefaultRoutes for AutoRolesRoutes {
+    type Queries = AutoRolesQueries;
+
+    type ResponseJson = ResponseAutoRole;
+
+    fn path() -> String {
+        format!("auto-roles")
+    }
+}
+
+impl MultipleBotGuildEntitiesRoutes for AutoRolesRoutes {}
@@ -1,16 +1,49 @@
 use async_trait::async_trait;
-use rustycrab_model::response::bot_guild_config::ResponseGuildConfig;
+use axum::{ Extension, Json, Router, extract::Path, routing::get };
+use rustycrab_model::{
+    modules::GuildModule,
+    response::{
+        ResponseDataList,
+        bot_guild_config::{ ResponseGuildConfig, ResponseGuildModule },
+    },
+};
+use sea_orm::{ EntityTrait, IntoActiveModel, PrimaryKeyTrait };
 
 use crate::{
+    app_state::AppState,
     database::bot_guild_configurations::Model as GuildConfig,
+    default_queries::DefaultSeaQueries,
     default_router::DefaultRoutes,
     queries::guild_config_queries::GuildConfigQueries,
+    unique_bot_guild_entity_queries::UniqueBotGuildEntityQueries,
     unique_bot_guild_entity_router::UniqueBotGuildEntityRoutes,
+    utilities::app_error::AppError,
 };
 
 pub struct BotGuildConfigsRoutes {}
 
-impl BotGuildConfigsRoutes {}
+impl BotGuildConfigsRoutes {
+    /// Lists every module with whether it's enabled in the guild.
+    async fn get_modules(
+        Extension(state): Extension<AppState>,
+        Path((bot_discord_id, guild_discord_id)): Path<(String, String)>
+    ) -> Result<Json<ResponseDataList<ResponseGuildModule>>, AppError> {
+        let config = GuildConfigQueries::find_by_discord_ids(
+            &state.db,
+            &bot_discord_id,
+            &guild_discord_id
+        ).await?;
+
+        let modules = GuildModule::ALL.into_iter()
+            .map(|module| ResponseGuildModule {
+                module,
+                enabled: module.is_enabled(config.module_flags),
+            })
+            .collect();
+
+        Ok(Json(ResponseDataList { data: modules }))
+    }
+}
 
 #[async_trait]
 impl DefaultRoutes for BotGuildConfigsRoutes {
@@ -21,12 +54,29 @@ impl DefaultRoutes for BotGuildConfigsRoutes {
     fn path() -> String {
         format!("configs")
     }
+
+    async fn more_routes() -> Router
+        where
+            <<<<BotGuildConfigsRoutes as DefaultRoutes>::Queries as DefaultSeaQueries>::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::ValueType: From<i32>,
+            <<<BotGuildConfigsRoutes as DefaultRoutes>::Queries as DefaultSeaQueries>::Entity as sea_orm::EntityTrait>::Model: IntoActiveModel<<<BotGuildConfigsRoutes as DefaultRoutes>::Queries as DefaultSeaQueries>::ActiveModel>
+    {
+        Router::new().nest(
+            &format!("/{}", &Self::path()),
+            Router::new().route(
+                "/:bot_discord_id/:guild_discord_id/modules",
+                get(Self::get_modules)
+            )
+        )
+    }
 }
 
 impl UniqueBotGuildEntityRoutes for BotGuildConfigsRoutes {}
 
 impl From<GuildConfig> for ResponseGuildConfig {
     fn from(model: GuildConfig) -> Self {
+        let command_cooldowns = GuildConfigQueries::command_cooldowns(&model);
+        let extra_prefixes = GuildConfigQueries::extra_prefixes(&model);
+
         Self {
             id: model.id,
             prefix: model.prefix,
@@ -35,6 +85,10 @@ impl From<GuildConfig> for ResponseGuildConfig {
             guild_id: model.guild_id,
             module_flags: model.module_flags,
             premium_flags: model.premium_flags,
+            command_cooldowns,
+            command_suggestions: model.command_suggestions,
+            extra_prefixes,
+            mention_prefix: model.mention_prefix,
         }
     }
 }
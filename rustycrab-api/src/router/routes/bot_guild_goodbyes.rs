@@ -0,0 +1,36 @@
+use crate::unique_bot_guild_entity_router::UniqueBotGuildEntityRoutes;
+use crate::database::bot_guild_goodbyes::Model as GoodbyeModel;
+use crate::default_router::DefaultRoutes;
+use crate::queries::guild_goodbye_queries::GuildGoodbyeQueries;
+
+use async_trait::async_trait;
+use rustycrab_model::response::bot_guild_goodbye::ResponseGuildGoodbye;
+
+pub struct BotGuildGoodbyesRoutes {}
+
+impl BotGuildGoodbyesRoutes {}
+
+#[async_trait]
+impl DefaultRoutes for BotGuildGoodbyesRoutes {
+    type Queries = GuildGoodbyeQueries;
+
+    type ResponseJson = ResponseGuildGoodbye;
+
+    fn path() -> String {
+        format!("goodbyes")
+    }
+}
+
+impl UniqueBotGuildEntityRoutes for BotGuildGoodbyesRoutes {}
+
+impl From<GoodbyeModel> for ResponseGuildGoodbye {
+    fn from(model: GoodbyeModel) -> Self {
+        Self {
+            id: model.id,
+            channel_id: model.channel_id,
+            bot_id: model.bot_id,
+            guild_id: model.guild_id,
+            message_id: model.message_id,
+        }
+    }
+}
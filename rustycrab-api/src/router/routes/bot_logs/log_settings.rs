@@ -31,6 +31,9 @@ impl From<LogSettingModel> for ResponseLogSetting {
             id: model.id,
             specify_channels: model.specify_channels,
             new_account_age: model.new_account_age,
+            new_account_action: model.new_account_action,
+            new_account_timeout: model.new_account_timeout,
+            quarantine_role_id: model.quarantine_role_id,
             bot_id: model.bot_id,
             guild_id: model.guild_id,
         }
@@ -1,7 +1,13 @@
-use axum::{ Extension, Json, routing::{ get, patch }, Router };
+use axum::{ Extension, Json, routing::{ get, patch, post }, Router };
 use axum::extract::Path;
-use rustycrab_model::response::{ ResponseDataJson, bots::ResponseBot };
-use sea_orm::{ EntityTrait, PrimaryKeyTrait, IntoActiveModel };
+use rustycrab_model::response::{
+    ResponseDataJson,
+    ResponseDataList,
+    ResponseDataMessage,
+    bots::{ RequestSyncCommands, ResponseBot, ResponseBotStatus, ResponseCommandSync },
+};
+use twilight_model::id::Id;
+use sea_orm::{ EntityTrait, PrimaryKeyTrait, IntoActiveModel, DeleteResult };
 use async_trait::async_trait;
 
 use crate::app_state::AppState;
@@ -9,6 +15,7 @@ use crate::default_queries::DefaultSeaQueries;
 use crate::utilities::app_error::AppError;
 use crate::{ default_router::DefaultRoutes, queries::bot_queries::BotQueries };
 use crate::database::bots::Model as BotModel;
+use crate::twilightrs::commands::slash::command_sync::CommandScope;
 
 pub struct BotsRouter {}
 
@@ -35,6 +42,8 @@ impl BotsRouter {
             <<<<BotsRouter as DefaultRoutes>::Queries as DefaultSeaQueries>::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::ValueType: From<i32>,
             <<<BotsRouter as DefaultRoutes>::Queries as DefaultSeaQueries>::Entity as sea_orm::EntityTrait>::Model: IntoActiveModel<<<BotsRouter as DefaultRoutes>::Queries as DefaultSeaQueries>::ActiveModel>
     {
+        let token_changed = update_dto.token.is_some();
+
         let model: <<<BotsRouter as DefaultRoutes>::Queries as DefaultSeaQueries>::Entity as EntityTrait>::Model = <BotsRouter as DefaultRoutes>::Queries::update_by_discord_id(
             &state.db,
             &bot_discord_id,
@@ -45,8 +54,83 @@ impl BotsRouter {
             model
         );
 
+        // reconnect the bot with its new token
+        if token_changed {
+            let _ = state.running_bots.restart(&response.bot_id).await;
+        }
+
         Ok(Json(ResponseDataJson { data: response }))
     }
+
+    async fn get_statuses(Extension(state): Extension<AppState>) -> Result<
+        Json<ResponseDataList<ResponseBotStatus>>,
+        AppError
+    > {
+        let statuses = state.running_bots.statuses().await;
+
+        Ok(Json(ResponseDataList { data: statuses }))
+    }
+
+    async fn get_status(
+        Extension(state): Extension<AppState>,
+        Path(bot_discord_id): Path<String>
+    ) -> Result<Json<ResponseDataJson<ResponseBotStatus>>, AppError> {
+        let status = state.running_bots
+            .status(&bot_discord_id).await
+            .ok_or_else(|| AppError::not_found("Bot is not running"))?;
+
+        Ok(Json(ResponseDataJson { data: status }))
+    }
+
+    async fn start_bot(
+        Extension(state): Extension<AppState>,
+        Path(bot_discord_id): Path<String>
+    ) -> Result<Json<ResponseDataJson<ResponseBotStatus>>, AppError> {
+        let status = state.running_bots.start(&bot_discord_id).await?;
+
+        Ok(Json(ResponseDataJson { data: status }))
+    }
+
+    async fn stop_bot(
+        Extension(state): Extension<AppState>,
+        Path(bot_discord_id): Path<String>
+    ) -> Result<Json<ResponseDataJson<ResponseBotStatus>>, AppError> {
+        let status = state.running_bots.stop(&bot_discord_id).await?;
+
+        Ok(Json(ResponseDataJson { data: status }))
+    }
+
+    async fn restart_bot(
+        Extension(state): Extension<AppState>,
+        Path(bot_discord_id): Path<String>
+    ) -> Result<Json<ResponseDataJson<ResponseBotStatus>>, AppError> {
+        let status = state.running_bots.restart(&bot_discord_id).await?;
+
+        Ok(Json(ResponseDataJson { data: status }))
+    }
+
+    /// Syncs the bot's slash commands, globally unless a guild is given.
+    async fn sync_commands(
+        Extension(state): Extension<AppState>,
+        Path(bot_discord_id): Path<String>,
+        body: Option<Json<RequestSyncCommands>>
+    ) -> Result<Json<ResponseDataJson<ResponseCommandSync>>, AppError> {
+        let scope = match body.and_then(|Json(body)| body.guild_discord_id) {
+            Some(guild_discord_id) => {
+                let guild_id = guild_discord_id
+                    .parse::<u64>()
+                    .ok()
+                    .and_then(Id::new_checked)
+                    .ok_or_else(|| AppError::bad_request("Invalid guild ID"))?;
+                CommandScope::Guild(guild_id)
+            }
+            None => CommandScope::Global,
+        };
+
+        let report = state.running_bots.sync_commands(&bot_discord_id, scope).await?;
+
+        Ok(Json(ResponseDataJson { data: report }))
+    }
 }
 
 #[async_trait]
@@ -59,6 +143,55 @@ impl DefaultRoutes for BotsRouter {
         format!("bots")
     }
 
+    async fn create_one(
+        Extension(state): Extension<AppState>,
+        Json(create_dto): Json<<Self::Queries as DefaultSeaQueries>::CreateData>
+    ) -> Result<Json<ResponseDataJson<Self::ResponseJson>>, AppError> {
+        let model = Self::Queries::create_entity(&state.db, create_dto).await?;
+
+        // start the new bot right away, this fails if it was already running
+        let _ = state.running_bots.start(&model.bot_id).await;
+
+        Ok(Json(ResponseDataJson { data: Self::ResponseJson::from(model) }))
+    }
+
+    async fn update_by_id(
+        Extension(state): Extension<AppState>,
+        Path(id): Path<i32>,
+        Json(update_dto): Json<<Self::Queries as DefaultSeaQueries>::UpdateData>
+    )
+        -> Result<Json<ResponseDataJson<Self::ResponseJson>>, AppError>
+        where
+            <<<Self::Queries as DefaultSeaQueries>::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::ValueType: From<i32>,
+            <<Self::Queries as DefaultSeaQueries>::Entity as sea_orm::EntityTrait>::Model: IntoActiveModel<<Self::Queries as DefaultSeaQueries>::ActiveModel>
+    {
+        let token_changed = update_dto.token.is_some();
+
+        let model = Self::Queries::update_by_id(&state.db, id, update_dto).await?;
+
+        // reconnect the bot with its new token
+        if token_changed {
+            let _ = state.running_bots.restart(&model.bot_id).await;
+        }
+
+        Ok(Json(ResponseDataJson { data: Self::ResponseJson::from(model) }))
+    }
+
+    async fn delete_by_id(
+        Extension(state): Extension<AppState>,
+        Path(id): Path<i32>
+    ) -> Result<Json<ResponseDataMessage>, AppError>
+        where
+            <<<Self::Queries as DefaultSeaQueries>::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::ValueType: From<i32>
+    {
+        let bot = Self::Queries::find_by_id(&state.db, id).await?;
+        let _ = state.running_bots.stop(&bot.bot_id).await;
+
+        let result: DeleteResult = Self::Queries::delete_by_id(&state.db, id).await?;
+        let message: String = format!("{} row(s) deleted", result.rows_affected);
+        Ok(Json(ResponseDataMessage { message }))
+    }
+
     async fn more_routes() -> Router
         where
             <<<<BotsRouter as DefaultRoutes>::Queries as DefaultSeaQueries>::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::ValueType: From<i32>,
@@ -69,6 +202,12 @@ impl DefaultRoutes for BotsRouter {
             Router::new()
                 .route("/discord/:bot_discord_id", get(Self::get_one_by_discord_id))
                 .route("/discord/:bot_discord_id", patch(Self::update_by_discord_id))
+                .route("/status", get(Self::get_statuses))
+                .route("/discord/:bot_discord_id/status", get(Self::get_status))
+                .route("/discord/:bot_discord_id/start", post(Self::start_bot))
+                .route("/discord/:bot_discord_id/stop", post(Self::stop_bot))
+                .route("/discord/:bot_discord_id/restart", post(Self::restart_bot))
+                .route("/discord/:bot_discord_id/commands/sync", post(Self::sync_commands))
         )
     }
 }
@@ -0,0 +1,34 @@
+use axum::{ Json, Router, extract::Query, routing::get };
+use rustycrab_model::response::{
+    ResponseDataList,
+    command_catalog::ResponseCommandCategory,
+};
+use serde::Deserialize;
+
+use crate::{
+    locales::AVAILABLE_LOCALES,
+    twilightrs::commands::context::catalog::command_catalog,
+    utilities::app_error::AppError,
+};
+
+#[derive(Deserialize)]
+pub struct CommandCatalogQuery {
+    /// Locale of the descriptions, `en` by default
+    locale: Option<String>,
+}
+
+/// Every context command by category, with its aliases, arguments, permissions and description.
+async fn get_command_catalog(
+    Query(query): Query<CommandCatalogQuery>
+) -> Result<Json<ResponseDataList<ResponseCommandCategory>>, AppError> {
+    let locale = query.locale.unwrap_or_else(|| "en".to_string());
+    if !AVAILABLE_LOCALES.contains(&locale.as_str()) {
+        return Err(AppError::bad_request(format!("Unknown locale {}", locale)));
+    }
+
+    Ok(Json(ResponseDataList { data: command_catalog(&locale) }))
+}
+
+pub async fn command_catalog_routes() -> Router {
+    Router::new().route("/commands", get(get_command_catalog))
+}
@@ -0,0 +1,39 @@
+use async_trait::async_trait;
+use rustycrab_model::response::command_rule::ResponseCommandRule;
+
+use crate::{
+    database::command_rules::Model as CommandRuleModel,
+    default_router::DefaultRoutes,
+    queries::command_rules_queries::CommandRulesQueries,
+    multi_bot_guild_entities_router::MultipleBotGuildEntitiesRoutes,
+};
+
+impl From<CommandRuleModel> for ResponseCommandRule {
+    fn from(model: CommandRuleModel) -> Self {
+        ResponseCommandRule {
+            id: model.id,
+            target: model.target,
+            name: model.name,
+            subject: model.subject,
+            subject_id: model.subject_id,
+            allow: model.allow,
+            bot_id: model.bot_id,
+            guild_id: model.guild_id,
+        }
+    }
+}
+
+pub struct CommandRulesRoutes {}
+
+#[async_trait]
+impl DefaultRoutes for CommandRulesRoutes {
+    type Queries = CommandRulesQueries;
+
+    type ResponseJson = ResponseCommandRule;
+
+    fn path() -> String {
+        format!("command-rules")
+    }
+}
+
+impl MultipleBotGuildEntitiesRoutes for CommandRulesRoutes {}
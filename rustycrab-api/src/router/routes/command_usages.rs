@@ -0,0 +1,99 @@
+use async_trait::async_trait;
+use axum::{ Extension, Json, Router, extract::{ Path, Query }, routing::get };
+use rustycrab_model::response::{
+    ResponseDataJson,
+    command_usage::{ ResponseCommandUsage, ResponseCommandUsageStats },
+};
+use sea_orm::{ EntityTrait, IntoActiveModel, PrimaryKeyTrait };
+use serde::Deserialize;
+
+use crate::{
+    app_state::AppState,
+    database::command_usages::Model as CommandUsageModel,
+    default_queries::DefaultSeaQueries,
+    default_router::DefaultRoutes,
+    queries::command_usages_queries::CommandUsagesQueries,
+    multi_bot_guild_entities_router::MultipleBotGuildEntitiesRoutes,
+    utilities::app_error::AppError,
+};
+
+impl From<CommandUsageModel> for ResponseCommandUsage {
+    fn from(model: CommandUsageModel) -> Self {
+        ResponseCommandUsage {
+            id: model.id,
+            command: model.command,
+            kind: model.kind,
+            channel_id: model.channel_id,
+            user_id: model.user_id,
+            duration_ms: model.duration_ms,
+            success: model.success,
+            created_at: model.created_at,
+            bot_id: model.bot_id,
+            guild_id: model.guild_id,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct UsageStatsQuery {
+    /// How many days back the stats go, 7 by default
+    days: Option<u64>,
+    /// How many of the most used commands are returned, 10 by default
+    limit: Option<u64>,
+}
+
+pub struct CommandUsagesRoutes {}
+
+impl CommandUsagesRoutes {
+    /// Top commands, error rates and usage per day of a guild.
+    async fn get_stats(
+        Extension(state): Extension<AppState>,
+        Path((bot_discord_id, guild_discord_id)): Path<(String, String)>,
+        Query(query): Query<UsageStatsQuery>
+    ) -> Result<Json<ResponseDataJson<ResponseCommandUsageStats>>, AppError> {
+        let stats = CommandUsagesQueries::stats(
+            &state.db,
+            &bot_discord_id,
+            &guild_discord_id,
+            query.days.unwrap_or(7).min(365),
+            query.limit.unwrap_or(10).min(100)
+        ).await?;
+
+        Ok(Json(ResponseDataJson { data: stats }))
+    }
+}
+
+#[async_trait]
+impl DefaultRoutes for CommandUsagesRoutes {
+    type Queries = CommandUsagesQueries;
+
+    type ResponseJson = ResponseCommandUsage;
+
+    fn path() -> String {
+        format!("command-usages")
+    }
+
+    async fn more_routes() -> Router
+        where
+            <<<<CommandUsagesRoutes as DefaultRoutes>::Queries as DefaultSeaQueries>::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::ValueType: From<i32>,
+            <<<CommandUsagesRoutes as DefaultRoutes>::Queries as DefaultSeaQueries>::Entity as sea_orm::EntityTrait>::Model: IntoActiveModel<<<CommandUsagesRoutes as DefaultRoutes>::Queries as DefaultSeaQueries>::ActiveModel>
+    {
+        Router::new().nest(
+            &format!("/{}", &Self::path()),
+            Router::new().route(
+                "/:bot_discord_id/:guild_discord_id/stats",
+                get(Self::get_stats)
+            )
+        )
+    }
+}
+
+impl MultipleBotGuildEntitiesRoutes for CommandUsagesRoutes {}
+
+/// Usages are only recorded by the bots, the guild's usages and stats can only be read.
+pub async fn command_usages_routes() -> Router {
+    let usages_routes =
+        <CommandUsagesRoutes as MultipleBotGuildEntitiesRoutes>::bot_guild_router().await;
+
+    usages_routes.merge(CommandUsagesRoutes::more_routes().await)
+}
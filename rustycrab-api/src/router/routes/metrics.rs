@@ -0,0 +1,23 @@
+use axum::{ Extension, Router, routing::get, http::header, response::IntoResponse };
+
+use crate::{ app_state::AppState, metrics::metrics, utilities::app_error::AppError };
+
+async fn get_metrics(Extension(state): Extension<AppState>) -> Result<impl IntoResponse, AppError> {
+    // voice connections are read from the voice managers when scraped
+    for client in state.running_bots.clients().await {
+        metrics().set_voice_connections(
+            &client.bot_id.to_string(),
+            client.voice_music_manager.active_connections()
+        );
+    }
+
+    let body = metrics()
+        .encode()
+        .map_err(|e| AppError::internal_server_error(format!("Error encoding metrics {:?}", e)))?;
+
+    Ok(([(header::CONTENT_TYPE, "text/plain; version=0.0.4")], body))
+}
+
+pub async fn metrics_routes() -> Router {
+    Router::new().route("/metrics", get(get_metrics))
+}
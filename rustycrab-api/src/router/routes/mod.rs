@@ -6,10 +6,16 @@ pub mod guilds;
 pub mod bot_guild_configs;
 pub mod bot_users;
 pub mod bot_guild_welcomes;
+pub mod bot_guild_goodbyes;
 pub mod bot_logs;
 pub mod tickets;
 pub mod auto_responses;
+pub mod auto_roles;
+pub mod command_rules;
+pub mod command_usages;
+pub mod command_catalog;
 pub mod discord_oauth;
+pub mod metrics;
 
 use crate::database::{ embed_info::Model as EmbedModel, buttons::Model as ButtonModel };
 use rustycrab_model::response::discord_message::{ ResponseEmbed, ResponseButton };
@@ -1,12 +1,20 @@
 use async_trait::async_trait;
-use axum::{ Extension, extract::Path, Json, Router, routing::get };
+use axum::{ Extension, extract::{ Path, Query }, Json, Router, routing::get };
 use rustycrab_model::response::{
     ticket::multipanel::ResponseTicketMultiPanel,
     ResponseDataList,
     ResponseDataMessage,
 };
-use sea_orm::{ EntityTrait, PrimaryKeyTrait, IntoActiveModel };
-use twilight_model::{ channel::message::{ Embed, component::ActionRow, Component }, id::Id };
+use sea_orm::{ DatabaseConnection, EntityTrait, PrimaryKeyTrait, IntoActiveModel };
+use serde::Deserialize;
+use twilight_model::{
+    channel::message::{
+        Embed,
+        component::{ ActionRow, SelectMenu, SelectMenuOption, SelectMenuType },
+        Component,
+    },
+    id::Id,
+};
 
 use crate::{
     database::ticket_multi_panels::Model as TicketMultiPanelModel,
@@ -14,15 +22,24 @@ use crate::{
     queries::{
         bot_queries::BotQueries,
         message_queries::MessageQueries,
-        tickets_system::ticket_multipanels_queries::{
-            TicketMultiPanelQueries,
-            create_button_components,
+        tickets_system::{
+            ticket_multipanels_queries::{
+                TicketMultiPanelQueries,
+                create_button_components,
+                button_emoji,
+            },
+            ticket_panels_links_queries::TicketPanelLinksQueries,
         },
         message_embed_queries::MessageEmbedQueries,
+        message_button_queries::MessageButtonQueries,
     },
     default_queries::DefaultSeaQueries,
     app_state::AppState,
-    twilightrs::messages::DiscordEmbed,
+    twilightrs::{
+        messages::DiscordEmbed,
+        events::interaction_handlers::select_menus::SelectMenuEvents,
+        utils::select_menus::{ select_menu, select_option, select_menu_row, MAX_SELECT_OPTIONS },
+    },
     default_router::DefaultRoutes,
 };
 
@@ -39,6 +56,12 @@ impl From<TicketMultiPanelModel> for ResponseTicketMultiPanel {
     }
 }
 
+#[derive(Deserialize)]
+pub struct SendMultiPanelQuery {
+    /// Sends the panels as a dropdown instead of buttons, which fits more than 5 panels
+    dropdown: Option<bool>,
+}
+
 pub struct TicketMultiPanelsRoutes {}
 
 #[async_trait]
@@ -88,13 +111,14 @@ impl TicketMultiPanelsRoutes {
 
     pub async fn send_panel(
         Extension(state): Extension<AppState>,
-        Path(id): Path<i32>
+        Path(id): Path<i32>,
+        Query(query): Query<SendMultiPanelQuery>
     ) -> Result<Json<ResponseDataMessage>, AppError> {
         let panel = TicketMultiPanelQueries::find_by_id(&state.db, id).await?;
 
         let bot = BotQueries::find_by_id(&state.db, panel.bot_id).await?;
         let client = state.running_bots
-            .get(&bot.bot_id)
+            .get_client(&bot.bot_id).await
             .ok_or_else(|| { AppError::not_found("Bot client not found") })?;
 
         let channel_id = u64
@@ -113,22 +137,53 @@ impl TicketMultiPanelsRoutes {
             embed_id
         ).await?.into();
 
-        let button_components = create_button_components(&state.db, panel.id).await?;
-        println!("{:?}", button_components);
+        let components = if query.dropdown.unwrap_or(false) {
+            vec![create_select_menu_component(&state.db, panel.id).await?]
+        } else {
+            let button_components = create_button_components(&state.db, panel.id).await?;
+            vec![
+                Component::ActionRow(ActionRow {
+                    components: button_components,
+                })
+            ]
+        };
         let _ = client.http
             .create_message(Id::new(channel_id))
             .embeds(&vec![Embed::from(embed)])
             .map_err(|_| AppError::internal_server_error("Failed to create message embed"))?
-            .components(
-                &vec![
-                    Component::ActionRow(ActionRow {
-                        components: button_components,
-                    })
-                ]
-            )
+            .components(&components)
             .map_err(|_| AppError::internal_server_error("Failed to create message button"))?.await
             .map_err(|_| AppError::bad_request("Failed to send message"))?;
 
         Ok(Json(ResponseDataMessage { message: "Panel sent".to_string() }))
     }
 }
+
+/// A dropdown picking the panel to open a ticket with, fits more panels than a row of buttons.
+async fn create_select_menu_component(
+    db: &DatabaseConnection,
+    multi_panel_id: i32
+) -> Result<Component, AppError> {
+    let subpanels = TicketPanelLinksQueries::get_subpanels(db, multi_panel_id).await?;
+
+    let mut options = Vec::new();
+    for subpanel in subpanels.into_iter().take(MAX_SELECT_OPTIONS) {
+        let button = MessageButtonQueries::find_by_id(db, subpanel.button_id).await?;
+
+        options.push(SelectMenuOption {
+            emoji: Some(button_emoji(button.emoji)?),
+            ..select_option(&button.text, &subpanel.id.to_string())
+        });
+    }
+
+    Ok(
+        select_menu_row(SelectMenu {
+            options: Some(options),
+            ..select_menu(
+                SelectMenuEvents::TicketPanels.custom_id(&[&multi_panel_id.to_string()]),
+                SelectMenuType::Text,
+                "Pick a ticket type"
+            )
+        })
+    )
+}
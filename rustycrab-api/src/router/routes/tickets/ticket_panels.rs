@@ -40,6 +40,7 @@ impl From<TicketPanelModel> for ResponseTicketPanel {
             button_id: model.button_id,
             welcome_message_id: model.welcome_message_id,
             support_team_id: model.support_team_id,
+            questions: TicketPanelsQueries::questions(&model),
             ticket_category: model.ticket_category,
         }
     }
@@ -152,7 +153,7 @@ impl TicketPanelsRoutes {
 
         let bot = BotQueries::find_by_id(&state.db, panel.bot_id).await?;
         let client = state.running_bots
-            .get(&bot.bot_id)
+            .get_client(&bot.bot_id).await
             .ok_or_else(|| { AppError::not_found("Bot client not found") })?;
 
         let channel_id = u64
@@ -0,0 +1,155 @@
+use fluent_bundle::FluentArgs;
+use rustycrab_model::color::ColorResolvables;
+use twilight_model::{
+    channel::Channel,
+    gateway::payload::incoming::{ ChannelCreate, ChannelDelete, ChannelUpdate },
+};
+
+use crate::{
+    twilightrs::{ discord_client::DiscordClient, messages::{ DiscordEmbed, DiscordEmbedField } },
+    queries::guild_logs::ChannelEvents,
+    utilities::app_error::BoxedError,
+};
+
+use super::{ LogEvent, CachedBefore, send_action_log, log_locale };
+
+fn channel_log_embed(channel: &Channel, title: String, color: ColorResolvables) -> DiscordEmbed {
+    DiscordEmbed {
+        title: Some(title),
+        color: Some(color.as_u32()),
+        footer_text: Some(format!("ID: {}", channel.id)),
+        timestamp: Some(true),
+        ..Default::default()
+    }
+}
+
+fn channel_args(channel: &Channel) -> FluentArgs<'static> {
+    let mut args = FluentArgs::new();
+    args.set("channel", format!("<#{}>", channel.id));
+    args.set("name", channel.name.clone().unwrap_or_default());
+    args
+}
+
+/// Logs a channel being created in the guild.
+pub async fn log_channel_create(client: DiscordClient, event: &ChannelCreate) -> Result<(), BoxedError> {
+    let guild_id = if let Some(guild_id) = event.guild_id {
+        guild_id
+    } else {
+        return Ok(());
+    };
+
+    let locale = log_locale(&client, guild_id).await;
+    let mut embed = channel_log_embed(
+        event,
+        client.get_locale_string(&locale, "log-channel-create", None),
+        ColorResolvables::Green
+    );
+    embed.description = Some(
+        client.get_locale_string(&locale, "log-channel-description", Some(&channel_args(event)))
+    );
+
+    send_action_log(
+        &client,
+        guild_id,
+        LogEvent::Channel(ChannelEvents::ChannelCreate),
+        Some(event.id),
+        &[],
+        embed
+    ).await
+}
+
+/// Logs a channel being deleted from the guild.
+pub async fn log_channel_delete(client: DiscordClient, event: &ChannelDelete) -> Result<(), BoxedError> {
+    let guild_id = if let Some(guild_id) = event.guild_id {
+        guild_id
+    } else {
+        return Ok(());
+    };
+
+    let locale = log_locale(&client, guild_id).await;
+    let mut embed = channel_log_embed(
+        event,
+        client.get_locale_string(&locale, "log-channel-delete", None),
+        ColorResolvables::Red
+    );
+    embed.description = Some(
+        client.get_locale_string(&locale, "log-channel-description", Some(&channel_args(event)))
+    );
+
+    send_action_log(
+        &client,
+        guild_id,
+        LogEvent::Channel(ChannelEvents::ChannelDelete),
+        Some(event.id),
+        &[],
+        embed
+    ).await
+}
+
+/// Logs name, topic and NSFW changes of a channel by comparing with the cached channel.
+pub async fn log_channel_update(
+    client: DiscordClient,
+    event: &ChannelUpdate,
+    before: Option<CachedBefore>
+) -> Result<(), BoxedError> {
+    let guild_id = if let Some(guild_id) = event.guild_id {
+        guild_id
+    } else {
+        return Ok(());
+    };
+    let before = if let Some(CachedBefore::Channel(channel)) = before {
+        channel
+    } else {
+        return Ok(());
+    };
+
+    let locale = log_locale(&client, guild_id).await;
+    let mut fields: Vec<DiscordEmbedField> = vec![];
+
+    let changes = [
+        ("log-field-name", before.name.clone(), event.name.clone()),
+        ("log-field-topic", before.topic.clone(), event.topic.clone()),
+        (
+            "log-field-nsfw",
+            before.nsfw.map(|nsfw| nsfw.to_string()),
+            event.nsfw.map(|nsfw| nsfw.to_string()),
+        ),
+    ];
+    for (key, old_value, new_value) in changes {
+        if old_value != new_value {
+            fields.push(DiscordEmbedField {
+                name: client.get_locale_string(&locale, key, None),
+                value: format!(
+                    "{} → {}",
+                    old_value.unwrap_or_else(|| "-".to_string()),
+                    new_value.unwrap_or_else(|| "-".to_string())
+                ),
+                inline: false,
+            });
+        }
+    }
+
+    // permission overwrites and position changes are not logged
+    if fields.is_empty() {
+        return Ok(());
+    }
+
+    let mut embed = channel_log_embed(
+        event,
+        client.get_locale_string(&locale, "log-channel-update", None),
+        ColorResolvables::Yellow
+    );
+    embed.description = Some(
+        client.get_locale_string(&locale, "log-channel-description", Some(&channel_args(event)))
+    );
+    embed.fields = Some(fields);
+
+    send_action_log(
+        &client,
+        guild_id,
+        LogEvent::Channel(ChannelEvents::ChannelUpdate),
+        Some(event.id),
+        &[],
+        embed
+    ).await
+}
@@ -0,0 +1,274 @@
+use fluent_bundle::FluentArgs;
+use rustycrab_model::color::ColorResolvables;
+use twilight_model::gateway::payload::incoming::{
+    MemberAdd,
+    MemberRemove,
+    MemberUpdate,
+    BanAdd,
+    BanRemove,
+};
+
+use crate::{
+    twilightrs::{
+        discord_client::DiscordClient,
+        messages::DiscordEmbedField,
+        bot::new_accounts::is_new_account,
+    },
+    queries::guild_logs::{ MemberEvents, log_setting_queries::LogSettingQueries },
+    unique_bot_guild_entity_queries::UniqueBotGuildEntityQueries,
+    utilities::{ app_error::BoxedError, utils::{ account_age, account_created_at } },
+};
+
+use super::{ LogEvent, CachedBefore, send_action_log, log_locale, user_log_embed };
+
+/// Logs a member joining the guild.
+pub async fn log_member_add(client: DiscordClient, event: &MemberAdd) -> Result<(), BoxedError> {
+    let bot = client.get_bot().await?;
+    let is_new = LogSettingQueries::find_by_discord_ids(
+        &client.db,
+        &bot.id.to_string(),
+        &event.guild_id.to_string()
+    ).await
+        .map(|setting| is_new_account(&setting, event.user.id))
+        .unwrap_or(false);
+
+    let locale = log_locale(&client, event.guild_id).await;
+    let mut args = FluentArgs::new();
+    args.set("user", format!("<@{}>", event.user.id));
+    args.set("days", account_age(event.user.id).num_days());
+    args.set("created", format!("<t:{}:R>", account_created_at(event.user.id).timestamp()));
+
+    // highlight accounts younger than the guild's new account age
+    let (title_key, color) = if is_new {
+        ("log-member-join-new-account", ColorResolvables::Yellow)
+    } else {
+        ("log-member-join", ColorResolvables::Green)
+    };
+
+    let mut embed = user_log_embed(
+        &event.user,
+        client.get_locale_string(&locale, title_key, None),
+        color
+    );
+    embed.description = Some(
+        client.get_locale_string(&locale, "log-member-join-description", Some(&args))
+    );
+    embed.fields = Some(
+        vec![DiscordEmbedField {
+            name: client.get_locale_string(&locale, "log-field-account-age", None),
+            value: client.get_locale_string(&locale, "log-account-age", Some(&args)),
+            inline: false,
+        }]
+    );
+
+    send_action_log(
+        &client,
+        event.guild_id,
+        LogEvent::Member(MemberEvents::MemberJoin),
+        None,
+        &event.roles,
+        embed
+    ).await
+}
+
+/// Logs a member leaving the guild, using the cached member for their roles.
+pub async fn log_member_remove(
+    client: DiscordClient,
+    event: &MemberRemove,
+    before: Option<CachedBefore>
+) -> Result<(), BoxedError> {
+    let roles = if let Some(CachedBefore::Member { roles, .. }) = before { roles } else { vec![] };
+
+    let locale = log_locale(&client, event.guild_id).await;
+    let mut args = FluentArgs::new();
+    args.set("user", format!("<@{}>", event.user.id));
+
+    let mut embed = user_log_embed(
+        &event.user,
+        client.get_locale_string(&locale, "log-member-leave", None),
+        ColorResolvables::Red
+    );
+    embed.description = Some(
+        client.get_locale_string(&locale, "log-member-leave-description", Some(&args))
+    );
+    if !roles.is_empty() {
+        embed.fields = Some(
+            vec![DiscordEmbedField {
+                name: client.get_locale_string(&locale, "log-field-roles", None),
+                value: roles
+                    .iter()
+                    .map(|role_id| format!("<@&{}>", role_id))
+                    .collect::<Vec<String>>()
+                    .join(" "),
+                inline: false,
+            }]
+        );
+    }
+
+    send_action_log(
+        &client,
+        event.guild_id,
+        LogEvent::Member(MemberEvents::MemberLeave),
+        None,
+        &roles,
+        embed
+    ).await
+}
+
+/// Logs a user being banned from the guild.
+pub async fn log_ban_add(client: DiscordClient, event: &BanAdd) -> Result<(), BoxedError> {
+    let locale = log_locale(&client, event.guild_id).await;
+    let mut args = FluentArgs::new();
+    args.set("user", format!("<@{}>", event.user.id));
+
+    let mut embed = user_log_embed(
+        &event.user,
+        client.get_locale_string(&locale, "log-member-ban", None),
+        ColorResolvables::Red
+    );
+    embed.description = Some(
+        client.get_locale_string(&locale, "log-member-ban-description", Some(&args))
+    );
+
+    send_action_log(
+        &client,
+        event.guild_id,
+        LogEvent::Member(MemberEvents::MemberBan),
+        None,
+        &[],
+        embed
+    ).await
+}
+
+/// Logs a user being unbanned from the guild.
+pub async fn log_ban_remove(client: DiscordClient, event: &BanRemove) -> Result<(), BoxedError> {
+    let locale = log_locale(&client, event.guild_id).await;
+    let mut args = FluentArgs::new();
+    args.set("user", format!("<@{}>", event.user.id));
+
+    let mut embed = user_log_embed(
+        &event.user,
+        client.get_locale_string(&locale, "log-member-unban", None),
+        ColorResolvables::Green
+    );
+    embed.description = Some(
+        client.get_locale_string(&locale, "log-member-unban-description", Some(&args))
+    );
+
+    send_action_log(
+        &client,
+        event.guild_id,
+        LogEvent::Member(MemberEvents::MemberUnban),
+        None,
+        &[],
+        embed
+    ).await
+}
+
+/// Logs role and nickname changes of a member by comparing with the cached member.
+pub async fn log_member_update(
+    client: DiscordClient,
+    event: &MemberUpdate,
+    before: Option<CachedBefore>
+) -> Result<(), BoxedError> {
+    let (old_nick, old_roles) = if let Some(CachedBefore::Member { nick, roles }) = before {
+        (nick, roles)
+    } else {
+        return Ok(());
+    };
+
+    let locale = log_locale(&client, event.guild_id).await;
+    let mut args = FluentArgs::new();
+    args.set("user", format!("<@{}>", event.user.id));
+
+    let added_roles: Vec<String> = event.roles
+        .iter()
+        .filter(|role_id| !old_roles.contains(role_id))
+        .map(|role_id| format!("<@&{}>", role_id))
+        .collect();
+    let removed_roles: Vec<String> = old_roles
+        .iter()
+        .filter(|role_id| !event.roles.contains(role_id))
+        .map(|role_id| format!("<@&{}>", role_id))
+        .collect();
+
+    for (roles, log_event, key, color) in [
+        (
+            added_roles,
+            MemberEvents::MemberRoleAdd,
+            "log-member-role-add",
+            ColorResolvables::Green,
+        ),
+        (
+            removed_roles,
+            MemberEvents::MemberRoleRemove,
+            "log-member-role-remove",
+            ColorResolvables::Red,
+        ),
+    ] {
+        if roles.is_empty() {
+            continue;
+        }
+
+        let mut embed = user_log_embed(
+            &event.user,
+            client.get_locale_string(&locale, key, None),
+            color
+        );
+        embed.description = Some(
+            client.get_locale_string(&locale, "log-member-update-description", Some(&args))
+        );
+        embed.fields = Some(
+            vec![DiscordEmbedField {
+                name: client.get_locale_string(&locale, "log-field-roles", None),
+                value: roles.join(" "),
+                inline: false,
+            }]
+        );
+
+        send_action_log(
+            &client,
+            event.guild_id,
+            LogEvent::Member(log_event),
+            None,
+            &event.roles,
+            embed
+        ).await?;
+    }
+
+    if old_nick != event.nick {
+        let mut embed = user_log_embed(
+            &event.user,
+            client.get_locale_string(&locale, "log-member-nickname", None),
+            ColorResolvables::Yellow
+        );
+        embed.description = Some(
+            client.get_locale_string(&locale, "log-member-update-description", Some(&args))
+        );
+        embed.fields = Some(
+            vec![
+                DiscordEmbedField {
+                    name: client.get_locale_string(&locale, "log-field-before", None),
+                    value: old_nick.unwrap_or_else(|| "-".to_string()),
+                    inline: true,
+                },
+                DiscordEmbedField {
+                    name: client.get_locale_string(&locale, "log-field-after", None),
+                    value: event.nick.clone().unwrap_or_else(|| "-".to_string()),
+                    inline: true,
+                }
+            ]
+        );
+
+        send_action_log(
+            &client,
+            event.guild_id,
+            LogEvent::Member(MemberEvents::MemberNicknameChange),
+            None,
+            &event.roles,
+            embed
+        ).await?;
+    }
+
+    Ok(())
+}
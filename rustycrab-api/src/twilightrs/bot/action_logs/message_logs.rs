@@ -0,0 +1,216 @@
+use fluent_bundle::FluentArgs;
+use rustycrab_model::color::ColorResolvables;
+use twilight_model::{
+    channel::Message,
+    gateway::payload::incoming::{ MessageDelete, MessageUpdate },
+    id::{ Id, marker::{ GuildMarker, RoleMarker, UserMarker } },
+    user::User,
+};
+
+use crate::{
+    twilightrs::{ discord_client::DiscordClient, messages::{ DiscordEmbed, DiscordEmbedField } },
+    queries::guild_logs::MessageEvents,
+    utilities::app_error::BoxedError,
+    cdn_avatar,
+};
+
+use super::{ LogEvent, CachedBefore, send_action_log, post_action_log, log_locale };
+
+/// Embed field values are limited to 1024 characters.
+fn truncate_field(text: &str) -> String {
+    if text.is_empty() {
+        return "-".to_string();
+    }
+    if text.chars().count() > 1024 {
+        format!("{}...", text.chars().take(1021).collect::<String>())
+    } else {
+        text.to_string()
+    }
+}
+
+fn member_roles(
+    client: &DiscordClient,
+    guild_id: Id<GuildMarker>,
+    user_id: Id<UserMarker>
+) -> Vec<Id<RoleMarker>> {
+    client.cache
+        .member(guild_id, user_id)
+        .map(|member| member.roles().to_vec())
+        .unwrap_or_default()
+}
+
+/// Logs a deleted message, using the cached copy of the message for its content.
+pub async fn log_message_delete(client: DiscordClient, event: &MessageDelete) -> Result<(), BoxedError> {
+    let guild_id = if let Some(guild_id) = event.guild_id {
+        guild_id
+    } else {
+        return Ok(());
+    };
+    let message = if let Some(message) = client.cache.message(event.id) {
+        message.value().clone()
+    } else {
+        return Ok(());
+    };
+
+    let author = client.cache.user(message.author()).map(|user| user.value().clone());
+    if author.as_ref().map_or(false, |user| user.bot) {
+        return Ok(());
+    }
+
+    let locale = log_locale(&client, guild_id).await;
+    let mut args = FluentArgs::new();
+    args.set("channel", format!("<#{}>", event.channel_id));
+
+    let embed = DiscordEmbed {
+        title: Some(client.get_locale_string(&locale, "log-message-delete", None)),
+        description: Some(client.get_locale_string(&locale, "log-message-in-channel", Some(&args))),
+        author_name: author.as_ref().map(|user| user.name.clone()),
+        author_icon_url: author
+            .as_ref()
+            .and_then(|user| user.avatar.map(|hash| cdn_avatar!(user.id, hash))),
+        color: Some(ColorResolvables::Red.as_u32()),
+        fields: Some(
+            vec![DiscordEmbedField {
+                name: client.get_locale_string(&locale, "log-field-content", None),
+                value: truncate_field(message.content()),
+                inline: false,
+            }]
+        ),
+        footer_text: Some(format!("ID: {}", message.author())),
+        timestamp: Some(true),
+        ..Default::default()
+    };
+
+    send_action_log(
+        &client,
+        guild_id,
+        LogEvent::Message(MessageEvents::MessageDelete),
+        Some(event.channel_id),
+        &member_roles(&client, guild_id, message.author()),
+        embed
+    ).await
+}
+
+/// Logs an edited message when its content changed from what was cached.
+pub async fn log_message_update(
+    client: DiscordClient,
+    event: &MessageUpdate,
+    before: Option<CachedBefore>
+) -> Result<(), BoxedError> {
+    let guild_id = if let Some(guild_id) = event.guild_id {
+        guild_id
+    } else {
+        return Ok(());
+    };
+    let (before, after) = match (before, &event.content) {
+        (Some(CachedBefore::Message(message)), Some(content)) => (message, content),
+        _ => {
+            return Ok(());
+        }
+    };
+
+    // embeds being resolved also trigger updates, only log actual edits
+    if before.content() == after {
+        return Ok(());
+    }
+
+    let author = event.author
+        .clone()
+        .or_else(|| client.cache.user(before.author()).map(|user| user.value().clone()));
+    if author.as_ref().map_or(false, |user| user.bot) {
+        return Ok(());
+    }
+
+    let locale = log_locale(&client, guild_id).await;
+    let mut args = FluentArgs::new();
+    args.set("channel", format!("<#{}>", event.channel_id));
+    args.set(
+        "url",
+        format!("https://discord.com/channels/{}/{}/{}", guild_id, event.channel_id, event.id)
+    );
+
+    let embed = DiscordEmbed {
+        title: Some(client.get_locale_string(&locale, "log-message-edit", None)),
+        description: Some(client.get_locale_string(&locale, "log-message-edit-link", Some(&args))),
+        author_name: author.as_ref().map(|user| user.name.clone()),
+        author_icon_url: author
+            .as_ref()
+            .and_then(|user| user.avatar.map(|hash| cdn_avatar!(user.id, hash))),
+        color: Some(ColorResolvables::Yellow.as_u32()),
+        fields: Some(
+            vec![
+                DiscordEmbedField {
+                    name: client.get_locale_string(&locale, "log-field-before", None),
+                    value: truncate_field(before.content()),
+                    inline: false,
+                },
+                DiscordEmbedField {
+                    name: client.get_locale_string(&locale, "log-field-after", None),
+                    value: truncate_field(after),
+                    inline: false,
+                }
+            ]
+        ),
+        footer_text: Some(format!("ID: {}", before.author())),
+        timestamp: Some(true),
+        ..Default::default()
+    };
+
+    send_action_log(
+        &client,
+        guild_id,
+        LogEvent::Message(MessageEvents::MessageEdit),
+        Some(event.channel_id),
+        &member_roles(&client, guild_id, before.author()),
+        embed
+    ).await
+}
+
+/// Logs a message reported by a member with the `Report message` context menu.
+///
+/// Tells whether a log channel received the report, `false` if none listens to reports.
+pub async fn log_message_report(
+    client: &DiscordClient,
+    guild_id: Id<GuildMarker>,
+    reporter: &User,
+    message: &Message
+) -> Result<bool, BoxedError> {
+    let locale = log_locale(client, guild_id).await;
+    let mut args = FluentArgs::new();
+    args.set("reporter", format!("<@{}>", reporter.id));
+    args.set("channel", format!("<#{}>", message.channel_id));
+    args.set(
+        "url",
+        format!("https://discord.com/channels/{}/{}/{}", guild_id, message.channel_id, message.id)
+    );
+
+    let embed = DiscordEmbed {
+        title: Some(client.get_locale_string(&locale, "log-message-report", None)),
+        description: Some(
+            client.get_locale_string(&locale, "log-message-report-description", Some(&args))
+        ),
+        author_name: Some(message.author.name.clone()),
+        author_icon_url: message.author.avatar.map(|hash| cdn_avatar!(message.author.id, hash)),
+        color: Some(ColorResolvables::Red.as_u32()),
+        fields: Some(
+            vec![DiscordEmbedField {
+                name: client.get_locale_string(&locale, "log-field-content", None),
+                value: truncate_field(&message.content),
+                inline: false,
+            }]
+        ),
+        footer_text: Some(format!("ID: {}", message.author.id)),
+        timestamp: Some(true),
+        ..Default::default()
+    };
+
+    // reports are sent even from ignored channels, a member explicitly asked for it
+    post_action_log(
+        client,
+        guild_id,
+        LogEvent::Message(MessageEvents::MessageReport),
+        None,
+        &[],
+        embed
+    ).await
+}
@@ -0,0 +1,294 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use rustycrab_model::{ color::ColorResolvables, modules::GuildModule };
+use twilight_cache_inmemory::{ InMemoryCache, model::CachedMessage };
+use twilight_gateway::Event;
+use twilight_model::{
+    channel::Channel,
+    gateway::event::EventType,
+    id::{ Id, marker::{ ChannelMarker, GuildMarker, RoleMarker } },
+    user::User,
+};
+
+use crate::{
+    twilightrs::{
+        discord_client::{ DiscordClient, MessageContent },
+        commands::context::context_command::GuildConfigModel,
+        event_bus::{ EventContext, EventFlow, EventListener },
+        messages::DiscordEmbed,
+    },
+    queries::guild_logs::{
+        DiscordLogsCategories,
+        MessageEvents,
+        MemberEvents,
+        ChannelEvents,
+        action_log_queries::ActionLogsQueries,
+        log_ignore_channel_queries::LogIgnoreChannelQueries,
+        log_ignore_role_queries::LogIgnoreRoleQueries,
+    },
+    utilities::app_error::BoxedError,
+    cdn_avatar,
+};
+
+pub mod message_logs;
+pub mod member_logs;
+pub mod channel_logs;
+
+use self::{
+    message_logs::{ log_message_delete, log_message_update },
+    member_logs::{
+        log_member_add,
+        log_member_remove,
+        log_member_update,
+        log_ban_add,
+        log_ban_remove,
+    },
+    channel_logs::{ log_channel_create, log_channel_delete, log_channel_update },
+};
+
+/// Writes message, member and channel events to the guild's action-log channels.
+pub struct ActionLogsListener {}
+
+#[async_trait]
+impl EventListener for ActionLogsListener {
+    fn name(&self) -> &'static str {
+        "action-logs"
+    }
+
+    fn events(&self) -> Vec<EventType> {
+        vec![
+            EventType::MessageDelete,
+            EventType::MessageUpdate,
+            EventType::MemberAdd,
+            EventType::MemberRemove,
+            EventType::MemberUpdate,
+            EventType::BanAdd,
+            EventType::BanRemove,
+            EventType::ChannelCreate,
+            EventType::ChannelDelete,
+            EventType::ChannelUpdate
+        ]
+    }
+
+    /// Runs first, so joins are logged before the member might get kicked.
+    fn priority(&self) -> i32 {
+        -100
+    }
+
+    fn is_enabled(&self, config: &GuildConfigModel) -> bool {
+        GuildModule::Logging.is_enabled(config.module_flags)
+    }
+
+    async fn handle(&self, ctx: &EventContext) -> Result<EventFlow, BoxedError> {
+        let client = Arc::clone(&ctx.client);
+        let before = ctx.before.clone();
+
+        match &ctx.event {
+            Event::MessageDelete(event) => log_message_delete(client, event).await?,
+            Event::MessageUpdate(event) => log_message_update(client, event, before).await?,
+            Event::MemberAdd(event) => log_member_add(client, event).await?,
+            Event::MemberRemove(event) => log_member_remove(client, event, before).await?,
+            Event::MemberUpdate(event) => log_member_update(client, event, before).await?,
+            Event::BanAdd(event) => log_ban_add(client, event).await?,
+            Event::BanRemove(event) => log_ban_remove(client, event).await?,
+            Event::ChannelCreate(event) => log_channel_create(client, event).await?,
+            Event::ChannelDelete(event) => log_channel_delete(client, event).await?,
+            Event::ChannelUpdate(event) => log_channel_update(client, event, before).await?,
+            _ => {}
+        }
+
+        Ok(EventFlow::Continue)
+    }
+}
+
+/// An event that can be written to the guild's action-log channels.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LogEvent {
+    Message(MessageEvents),
+    Member(MemberEvents),
+    Channel(ChannelEvents),
+}
+
+impl LogEvent {
+    fn codes(&self) -> (i32, i32) {
+        match self {
+            LogEvent::Message(event) => (DiscordLogsCategories::Message as i32, *event as i32),
+            LogEvent::Member(event) => (DiscordLogsCategories::Member as i32, *event as i32),
+            LogEvent::Channel(event) => (DiscordLogsCategories::Channel as i32, *event as i32),
+        }
+    }
+
+    /// Checks whether the event is part of an action log's `events`.
+    ///
+    /// `events` is a comma separated list of `category:event` codes, using the values of
+    /// `DiscordLogsCategories` and the matching events enum (e.g. `0:2` is a message edit).
+    /// A bare category code enables every event of that category.
+    pub fn is_enabled(&self, events: &str) -> bool {
+        let (category, event) = self.codes();
+
+        events
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .any(|entry| {
+                match entry.split_once(':') {
+                    Some((entry_category, entry_event)) =>
+                        entry_category.trim().parse::<i32>() == Ok(category) &&
+                            entry_event.trim().parse::<i32>() == Ok(event),
+                    None => entry.parse::<i32>() == Ok(category),
+                }
+            })
+    }
+}
+
+/// What the cache knew about an entity right before an event updated it.
+///
+/// The cache is updated before events are handled, so this has to be taken in the event loop.
+#[derive(Clone, Debug)]
+pub enum CachedBefore {
+    Message(CachedMessage),
+    Member {
+        nick: Option<String>,
+        roles: Vec<Id<RoleMarker>>,
+    },
+    Channel(Channel),
+}
+
+impl CachedBefore {
+    pub fn from_cache(cache: &InMemoryCache, event: &Event) -> Option<Self> {
+        match event {
+            Event::MessageUpdate(update) => {
+                cache.message(update.id).map(|message| Self::Message(message.value().clone()))
+            }
+            Event::MemberUpdate(update) => {
+                cache.member(update.guild_id, update.user.id).map(|member| Self::Member {
+                    nick: member.nick().map(String::from),
+                    roles: member.roles().to_vec(),
+                })
+            }
+            Event::MemberRemove(remove) => {
+                cache.member(remove.guild_id, remove.user.id).map(|member| Self::Member {
+                    nick: member.nick().map(String::from),
+                    roles: member.roles().to_vec(),
+                })
+            }
+            Event::ChannelUpdate(update) => {
+                cache.channel(update.id).map(|channel| Self::Channel(channel.value().clone()))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Posts an action log embed to every log channel of the guild listening to `event`.
+///
+/// Nothing is sent if `channel_id` is an ignored channel, or if any of `role_ids` is an ignored
+/// role. Events happening inside a log channel are never logged to that same channel.
+pub async fn send_action_log(
+    client: &DiscordClient,
+    guild_id: Id<GuildMarker>,
+    event: LogEvent,
+    channel_id: Option<Id<ChannelMarker>>,
+    role_ids: &[Id<RoleMarker>],
+    embed: DiscordEmbed
+) -> Result<(), BoxedError> {
+    post_action_log(client, guild_id, event, channel_id, role_ids, embed).await.map(|_| ())
+}
+
+/// Same as [`send_action_log`], tells whether at least one log channel received the embed.
+pub async fn post_action_log(
+    client: &DiscordClient,
+    guild_id: Id<GuildMarker>,
+    event: LogEvent,
+    channel_id: Option<Id<ChannelMarker>>,
+    role_ids: &[Id<RoleMarker>],
+    embed: DiscordEmbed
+) -> Result<bool, BoxedError> {
+    let bot_discord_id = client.get_bot().await?.id.to_string();
+    let guild_discord_id = guild_id.to_string();
+
+    let log_channels: Vec<Id<ChannelMarker>> = ActionLogsQueries::find_guild_action_logs(
+        &client.db,
+        &bot_discord_id,
+        &guild_discord_id
+    ).await?
+        .into_iter()
+        .filter(|log| event.is_enabled(&log.events))
+        .filter_map(|log| log.channel_id.parse::<u64>().ok().and_then(Id::new_checked))
+        .filter(|log_channel| Some(*log_channel) != channel_id)
+        .collect();
+
+    if log_channels.is_empty() {
+        return Ok(false);
+    }
+
+    if let Some(channel_id) = channel_id {
+        if
+            LogIgnoreChannelQueries::check_by_discord_ids(
+                &client.db,
+                &bot_discord_id,
+                &guild_discord_id,
+                &channel_id.to_string()
+            ).await?
+        {
+            return Ok(false);
+        }
+    }
+
+    if !role_ids.is_empty() {
+        let ignored_roles = LogIgnoreRoleQueries::get_guild_ignores_by_discord_ids(
+            &client.db,
+            &bot_discord_id,
+            &guild_discord_id
+        ).await?;
+
+        if
+            ignored_roles
+                .iter()
+                .any(|ignored| role_ids.iter().any(|role_id| role_id.to_string() == ignored.role_id))
+        {
+            return Ok(false);
+        }
+    }
+
+    let mut sent = false;
+    for log_channel in log_channels {
+        match
+            client.send_message(
+                log_channel,
+                MessageContent::DiscordEmbeds(vec![embed.clone()])
+            ).await
+        {
+            Ok(_) => {
+                sent = true;
+            }
+            Err(e) => {
+                eprintln!("Failed to send action log to channel {}: {:?}", log_channel, e);
+            }
+        }
+    }
+
+    Ok(sent)
+}
+
+/// Gets the locale used for the guild's log messages, falling back to `en`.
+async fn log_locale(client: &DiscordClient, guild_id: Id<GuildMarker>) -> String {
+    client
+        .get_guild_config(&guild_id).await
+        .map(|config| config.locale)
+        .unwrap_or_else(|_| "en".to_string())
+}
+
+/// Base embed for a log entry about a user.
+fn user_log_embed(user: &User, title: String, color: ColorResolvables) -> DiscordEmbed {
+    DiscordEmbed {
+        title: Some(title),
+        author_name: Some(user.name.clone()),
+        author_icon_url: user.avatar.map(|hash| cdn_avatar!(user.id, hash)),
+        color: Some(color.as_u32()),
+        footer_text: Some(format!("ID: {}", user.id)),
+        timestamp: Some(true),
+        ..Default::default()
+    }
+}
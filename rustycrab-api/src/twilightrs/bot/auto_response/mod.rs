@@ -1,9 +1,15 @@
-use twilight_model::gateway::payload::incoming::MessageCreate;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use rustycrab_model::modules::GuildModule;
+use twilight_gateway::Event;
+use twilight_model::gateway::{ event::EventType, payload::incoming::MessageCreate };
 
 use crate::{
     twilightrs::{
         discord_client::{ DiscordClient, MessageContent },
         commands::context::context_command::GuildConfigModel,
+        event_bus::{ EventContext, EventFlow, EventListener },
         messages::DiscordEmbed,
     },
     utilities::{ app_error::BoxedError, utils::process_placeholders_sync },
@@ -14,8 +20,40 @@ use crate::{
     },
     database::messages,
     default_queries::DefaultSeaQueries,
+    metrics::metrics,
 };
 
+/// Replies to messages matching one of the guild's auto-response triggers.
+pub struct AutoResponseListener {}
+
+#[async_trait]
+impl EventListener for AutoResponseListener {
+    fn name(&self) -> &'static str {
+        "auto-responses"
+    }
+
+    fn events(&self) -> Vec<EventType> {
+        vec![EventType::MessageCreate]
+    }
+
+    fn priority(&self) -> i32 {
+        20
+    }
+
+    fn is_enabled(&self, config: &GuildConfigModel) -> bool {
+        GuildModule::AutoResponses.is_enabled(config.module_flags)
+    }
+
+    async fn handle(&self, ctx: &EventContext) -> Result<EventFlow, BoxedError> {
+        if let (Event::MessageCreate(msg), Some(config)) = (&ctx.event, &ctx.config) {
+            // most messages don't match any trigger, which is not an error
+            let _ = check_autores(Arc::clone(&ctx.client), msg, config).await;
+        }
+
+        Ok(EventFlow::Continue)
+    }
+}
+
 pub async fn check_autores(
     client: DiscordClient,
     msg: &MessageCreate,
@@ -52,6 +90,7 @@ pub async fn check_autores(
     ).await?.ok_or("can't build response from autores object")?;
     // println!("reply_message");
     let _ = client.reply_message(msg.channel_id, msg.id, message_content).await?;
+    metrics().record_autores_hit(&bot_discord_id);
 
     Ok(())
 }
@@ -0,0 +1,117 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use twilight_gateway::Event;
+use twilight_model::{
+    gateway::{ event::EventType, payload::incoming::MemberAdd },
+    id::{ Id, marker::RoleMarker },
+};
+
+use crate::{
+    twilightrs::{
+        discord_client::DiscordClient,
+        event_bus::{ EventContext, EventFlow, EventListener },
+    },
+    utilities::app_error::BoxedError,
+    queries::auto_roles_queries::AutoRolesQueries,
+    multi_bot_guild_entities_queries::MultipleBotGuildEntityQueries,
+};
+
+/// Whether an auto-role `type` applies to a member, based on whether the member is a bot.
+///
+/// `human`/`user` only targets humans, `bot` only targets bots, anything else targets everyone.
+fn auto_role_applies(role_type: &str, is_bot: bool) -> bool {
+    match role_type.trim().to_ascii_lowercase().as_str() {
+        "human" | "humans" | "user" | "users" => !is_bot,
+        "bot" | "bots" => is_bot,
+        _ => true,
+    }
+}
+
+/// Assigns the guild's configured auto roles to a member that just joined.
+///
+/// Each role is assigned after its own `delay` (in seconds). The member is re-checked before
+/// assigning so members who left during the delay are skipped, and roles above the bot's
+/// highest role are ignored.
+pub async fn assign_auto_roles(client: DiscordClient, event: &MemberAdd) -> Result<(), BoxedError> {
+    let bot = client.get_bot().await?;
+
+    let auto_roles = AutoRolesQueries::find_by_discord_ids(
+        &client.db,
+        &bot.id.to_string(),
+        &event.guild_id.to_string()
+    ).await?;
+
+    let guild_id = event.guild_id;
+    let user_id = event.user.id;
+
+    for auto_role in auto_roles {
+        if !auto_role_applies(&auto_role.r#type, event.user.bot) {
+            continue;
+        }
+
+        let role_id: Id<RoleMarker> = if
+            let Some(id) = auto_role.role_id.parse::<u64>().ok().and_then(Id::new_checked)
+        {
+            id
+        } else {
+            continue;
+        };
+
+        let client = Arc::clone(&client);
+        let delay = auto_role.delay.max(0) as u64;
+
+        tokio::spawn(async move {
+            if delay > 0 {
+                tokio::time::sleep(tokio::time::Duration::from_secs(delay)).await;
+            }
+
+            // the member may have left while we were waiting
+            if client.http.guild_member(guild_id, user_id).await.is_err() {
+                return;
+            }
+
+            match client.can_bot_manage_role(guild_id, role_id).await {
+                Ok(true) => {
+                    if let Err(e) = client.http.add_guild_member_role(guild_id, user_id, role_id).await {
+                        eprintln!("Failed to assign auto role {} to {}: {:?}", role_id, user_id, e);
+                    }
+                }
+                Ok(false) => {
+                    eprintln!("Auto role {} is above the bot's highest role", role_id);
+                }
+                Err(e) => {
+                    eprintln!("Failed to check auto role {}: {:?}", role_id, e);
+                }
+            }
+        });
+    }
+
+    Ok(())
+}
+
+/// Assigns the guild's auto roles to members joining.
+pub struct AutoRolesListener {}
+
+#[async_trait]
+impl EventListener for AutoRolesListener {
+    fn name(&self) -> &'static str {
+        "auto-roles"
+    }
+
+    fn events(&self) -> Vec<EventType> {
+        vec![EventType::MemberAdd]
+    }
+
+    fn priority(&self) -> i32 {
+        10
+    }
+
+    async fn handle(&self, ctx: &EventContext) -> Result<EventFlow, BoxedError> {
+        if let Event::MemberAdd(event) = &ctx.event {
+            let _ = assign_auto_roles(Arc::clone(&ctx.client), event).await;
+        }
+
+        Ok(EventFlow::Continue)
+    }
+}
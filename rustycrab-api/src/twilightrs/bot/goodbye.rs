@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use twilight_gateway::Event;
+use twilight_model::gateway::{ event::EventType, payload::incoming::MemberRemove };
+
+use crate::{
+    twilightrs::{
+        discord_client::DiscordClient,
+        bot::greeting::{ send_greeting, GreetingConfig },
+        event_bus::{ EventContext, EventFlow, EventListener },
+    },
+    utilities::app_error::BoxedError,
+    queries::guild_goodbye_queries::GuildGoodbyeQueries,
+    unique_bot_guild_entity_queries::UniqueBotGuildEntityQueries,
+};
+
+/// Sends the guild's configured goodbye message when a member leaves.
+///
+/// Works the same way as welcome messages.
+pub async fn send_goodbye_message(client: DiscordClient, event: &MemberRemove) -> Result<(), BoxedError> {
+    let bot = client.get_bot().await?;
+
+    let goodbye = GuildGoodbyeQueries::find_optional_by_discord_ids(
+        &client.db,
+        &bot.id.to_string(),
+        &event.guild_id.to_string()
+    ).await?;
+    let row = goodbye.map(|goodbye| GreetingConfig {
+        channel_id: goodbye.channel_id,
+        message_id: goodbye.message_id,
+    });
+
+    send_greeting(&client, event.guild_id, &event.user, row).await
+}
+
+/// Sends the guild's goodbye message when members leave.
+pub struct GoodbyeListener {}
+
+#[async_trait]
+impl EventListener for GoodbyeListener {
+    fn name(&self) -> &'static str {
+        "goodbye"
+    }
+
+    fn events(&self) -> Vec<EventType> {
+        vec![EventType::MemberRemove]
+    }
+
+    async fn handle(&self, ctx: &EventContext) -> Result<EventFlow, BoxedError> {
+        if let Event::MemberRemove(event) = &ctx.event {
+            send_goodbye_message(Arc::clone(&ctx.client), event).await?;
+        }
+
+        Ok(EventFlow::Continue)
+    }
+}
@@ -0,0 +1,58 @@
+use twilight_model::{ id::{ Id, marker::GuildMarker }, user::User };
+
+use crate::{
+    twilightrs::{ discord_client::DiscordClient, bot::auto_response::build_response },
+    utilities::app_error::BoxedError,
+    queries::message_queries::MessageQueries,
+    default_queries::DefaultSeaQueries,
+};
+
+/// The channel and message of a guild's welcome or goodbye.
+pub struct GreetingConfig {
+    pub channel_id: Option<String>,
+    pub message_id: Option<i32>,
+}
+
+/// Sends a welcome or goodbye message about `user`.
+///
+/// Nothing is sent if the guild has no greeting configured, or if either the channel or the
+/// message is missing.
+pub async fn send_greeting(
+    client: &DiscordClient,
+    guild_id: Id<GuildMarker>,
+    user: &User,
+    row: Option<GreetingConfig>
+) -> Result<(), BoxedError> {
+    let row = match row {
+        Some(row) => row,
+        None => {
+            return Ok(());
+        }
+    };
+    let channel_id = row.channel_id
+        .and_then(|id| id.parse::<u64>().ok())
+        .and_then(Id::new_checked);
+    let (channel_id, message_id) = match (channel_id, row.message_id) {
+        (Some(channel_id), Some(message_id)) => (channel_id, message_id),
+        _ => {
+            return Ok(());
+        }
+    };
+
+    let message_model = MessageQueries::find_by_id(&client.db, message_id).await?;
+
+    // fetch the guild with counts so `{member-count}` can be resolved
+    let guild = client.http.guild(guild_id).with_counts(true).await?.model().await?;
+
+    let message_content = build_response(
+        client,
+        message_model,
+        &Some(guild),
+        &Some(user.clone())
+    ).await?;
+    if let Some(message_content) = message_content {
+        client.send_message(channel_id, message_content).await?;
+    }
+
+    Ok(())
+}
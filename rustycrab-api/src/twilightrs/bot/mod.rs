@@ -1,3 +1,9 @@
 pub mod tickets;
 pub mod voice_music;
 pub mod auto_response;
+pub mod greeting;
+pub mod welcome;
+pub mod goodbye;
+pub mod auto_roles;
+pub mod action_logs;
+pub mod new_accounts;
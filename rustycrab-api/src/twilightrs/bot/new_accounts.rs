@@ -0,0 +1,149 @@
+use std::{ sync::Arc, time::{ Duration, SystemTime } };
+
+use async_trait::async_trait;
+use rustycrab_model::modules::GuildModule;
+use twilight_gateway::Event;
+use twilight_model::{
+    gateway::{ event::EventType, payload::incoming::MemberAdd },
+    id::{ Id, marker::{ RoleMarker, UserMarker } },
+    util::Timestamp,
+};
+
+use crate::{
+    database::log_settings::Model as LogSettingModel,
+    twilightrs::{
+        discord_client::DiscordClient,
+        commands::context::context_command::GuildConfigModel,
+        event_bus::{ EventContext, EventFlow, EventListener },
+    },
+    queries::guild_logs::log_setting_queries::LogSettingQueries,
+    unique_bot_guild_entity_queries::UniqueBotGuildEntityQueries,
+    utilities::{ app_error::BoxedError, utils::account_age },
+};
+
+/// Discord doesn't allow timeouts longer than 28 days.
+const MAX_TIMEOUT_MINUTES: i32 = 28 * 24 * 60;
+
+/// What to do with members whose account is younger than the guild's `new_account_age`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NewAccountAction {
+    /// Assign the guild's `quarantine_role_id`
+    QuarantineRole,
+    /// Timeout the member for `new_account_timeout` minutes
+    Timeout,
+    Kick,
+}
+
+impl NewAccountAction {
+    pub fn from_str(action: &str) -> Option<Self> {
+        match action.trim().to_ascii_lowercase().as_str() {
+            "role" | "quarantine" => Some(Self::QuarantineRole),
+            "timeout" => Some(Self::Timeout),
+            "kick" => Some(Self::Kick),
+            _ => None,
+        }
+    }
+}
+
+/// Checks whether an account is younger than the guild's `new_account_age` (in days).
+///
+/// A `new_account_age` of 0 turns the check off.
+pub fn is_new_account(setting: &LogSettingModel, user_id: Id<UserMarker>) -> bool {
+    setting.new_account_age > 0 && account_age(user_id).num_days() < (setting.new_account_age as i64)
+}
+
+/// Applies the guild's new account action to a member that just joined.
+///
+/// Returns `true` if the member was kicked.
+pub async fn handle_new_account(client: DiscordClient, event: &MemberAdd) -> Result<bool, BoxedError> {
+    if event.user.bot {
+        return Ok(false);
+    }
+
+    let bot = client.get_bot().await?;
+    let setting = LogSettingQueries::find_by_discord_ids(
+        &client.db,
+        &bot.id.to_string(),
+        &event.guild_id.to_string()
+    ).await?;
+
+    if !is_new_account(&setting, event.user.id) {
+        return Ok(false);
+    }
+
+    let action = match
+        setting.new_account_action.as_deref().and_then(NewAccountAction::from_str)
+    {
+        Some(action) => action,
+        None => {
+            return Ok(false);
+        }
+    };
+
+    match action {
+        NewAccountAction::QuarantineRole => {
+            let role_id: Id<RoleMarker> = setting.quarantine_role_id
+                .and_then(|id| id.parse::<u64>().ok())
+                .and_then(Id::new_checked)
+                .ok_or("quarantine role is not configured")?;
+
+            if !client.can_bot_manage_role(event.guild_id, role_id).await? {
+                return Err("quarantine role is above the bot's highest role".into());
+            }
+
+            client.http.add_guild_member_role(event.guild_id, event.user.id, role_id).await?;
+        }
+        NewAccountAction::Timeout => {
+            let minutes = setting.new_account_timeout.unwrap_or(60).clamp(1, MAX_TIMEOUT_MINUTES);
+            let timeout_end = SystemTime::now() + Duration::from_secs((minutes as u64) * 60);
+
+            let timestamp = Timestamp::from_secs(
+                timeout_end.duration_since(SystemTime::UNIX_EPOCH)?.as_secs() as i64
+            )?;
+
+            client.http
+                .update_guild_member(event.guild_id, event.user.id)
+                .communication_disabled_until(Some(timestamp))?.await?;
+        }
+        NewAccountAction::Kick => {
+            client.http.remove_guild_member(event.guild_id, event.user.id).await?;
+
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+/// Quarantines, times out or kicks new accounts, before they are welcomed.
+pub struct NewAccountsListener {}
+
+#[async_trait]
+impl EventListener for NewAccountsListener {
+    fn name(&self) -> &'static str {
+        "new-accounts"
+    }
+
+    fn events(&self) -> Vec<EventType> {
+        vec![EventType::MemberAdd]
+    }
+
+    fn priority(&self) -> i32 {
+        -50
+    }
+
+    fn is_enabled(&self, config: &GuildConfigModel) -> bool {
+        GuildModule::Moderation.is_enabled(config.module_flags)
+    }
+
+    async fn handle(&self, ctx: &EventContext) -> Result<EventFlow, BoxedError> {
+        if let Event::MemberAdd(event) = &ctx.event {
+            // kicked members shouldn't be welcomed or get auto roles
+            if handle_new_account(Arc::clone(&ctx.client), event).await? {
+                return Ok(EventFlow::Stop);
+            }
+        }
+
+        Ok(EventFlow::Continue)
+    }
+}
@@ -11,6 +11,7 @@ use rustycrab_model::response::ticket::{
         RequestUpdateTicket,
     },
     panel::ResponseTicketPanelDetails,
+    support_team::ResponseTicketSupportTeam,
 };
 use twilight_model::{
     gateway::payload::incoming::InteractionCreate,
@@ -26,7 +27,7 @@ use twilight_model::{
         permission_overwrite::PermissionOverwriteType as ChannelPermissionOverwriteType,
     },
     user::User,
-    id::{ Id, marker::{ GuildMarker, UserMarker } },
+    id::{ Id, marker::{ GuildMarker, UserMarker, ChannelMarker } },
     guild::{ Permissions, Member },
 };
 
@@ -43,8 +44,51 @@ use crate::{
     utilities::utils::color_to_button_style,
     cdn_avatar,
     cdn_guild_icon,
+    metrics::metrics,
 };
 
+/// Lets the roles and users of a support team see and manage a ticket channel.
+pub async fn add_support_team_to_channel(
+    client: &DiscordClient,
+    channel_id: Id<ChannelMarker>,
+    team: &ResponseTicketSupportTeam
+) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+    let support_team_permission_overwrites =
+        Permissions::VIEW_CHANNEL |
+        Permissions::SEND_MESSAGES |
+        Permissions::READ_MESSAGE_HISTORY |
+        Permissions::MANAGE_MESSAGES |
+        Permissions::MANAGE_ROLES;
+    for role_id in &team.roles {
+        if !role_id.is_empty() {
+            let _ = client.http.update_channel_permission(
+                channel_id,
+                &(PermissionOverwrite {
+                    kind: PermissionOverwriteType::Role,
+                    id: Id::new(u64::from_str_radix(role_id, 10)?.into()),
+                    allow: Some(support_team_permission_overwrites),
+                    deny: None,
+                })
+            ).await;
+        }
+    }
+    for user_id in &team.users {
+        if !user_id.is_empty() {
+            let _ = client.http.update_channel_permission(
+                channel_id,
+                &(PermissionOverwrite {
+                    kind: PermissionOverwriteType::Member,
+                    id: Id::new(u64::from_str_radix(user_id, 10)?.into()),
+                    allow: Some(support_team_permission_overwrites),
+                    deny: None,
+                })
+            ).await;
+        }
+    }
+
+    Ok(())
+}
+
 pub async fn close_ticket_handler(
     client: DiscordClient,
     interaction: &Box<InteractionCreate>,
@@ -312,6 +356,7 @@ pub async fn close_confirmed_handler(
         status: Some("Closed".to_string()),
         ..Default::default()
     }).await;
+    metrics().record_ticket_closed(&client.bot_id.to_string());
 
     Ok(())
 }
@@ -1,8 +1,8 @@
 use rustycrab_model::{ error::BoxedError, color::ColorResolvables };
 
-use crate::twilightrs::{
-    bot::voice_music::player::add_next_track::add_next_track,
-    messages::DiscordEmbed,
+use crate::{
+    twilightrs::{ bot::voice_music::player::add_next_track::add_next_track, messages::DiscordEmbed },
+    metrics::metrics,
 };
 
 use super::MusicEventHandler;
@@ -12,6 +12,7 @@ pub async fn handle_event_track_error(
     locale: &str
 ) -> Result<(), BoxedError> {
     // Logic to execute when an error happened loading the track
+    metrics().record_track_failure(&music_event_handler.client.bot_id.to_string());
 
     if
         let Some(message_id) =
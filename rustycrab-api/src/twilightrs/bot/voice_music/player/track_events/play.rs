@@ -5,6 +5,7 @@ use twilight_model::channel::message::Embed;
 use crate::{
     twilightrs::{ bot::voice_music::player::track_info::track_info_fields, messages::DiscordEmbed },
     cdn_avatar,
+    metrics::metrics,
 };
 
 use super::MusicEventHandler;
@@ -14,6 +15,7 @@ pub async fn handle_event_track_play(
     locale: &str
 ) -> Result<(), BoxedError> {
     // Logic to execute when a track starts playing.
+    metrics().record_track_played(&music_event_handler.client.bot_id.to_string());
     music_event_handler.client.voice_music_manager.set_current_song(
         music_event_handler.guild_id,
         Some((music_event_handler.metadata.clone(), &music_event_handler.requested_by))
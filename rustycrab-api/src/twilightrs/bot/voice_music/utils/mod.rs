@@ -1,4 +1,4 @@
 mod soundcloud;
-mod youtube_dl;
+pub mod youtube_dl;
 mod spotify;
 pub mod parse_url;
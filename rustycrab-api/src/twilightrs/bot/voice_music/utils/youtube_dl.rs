@@ -36,3 +36,33 @@ pub async fn get_youtube_playlist_tracks(url: &str) -> Result<Vec<String>, Boxed
         }
     }
 }
+
+/// Searches YouTube for tracks, returns the title and url of each result.
+pub async fn search_youtube_tracks(
+    query: &str,
+    limit: usize
+) -> Result<Vec<(String, String)>, BoxedError> {
+    let search = format!("ytsearch{}:{}", limit, query);
+    // youtube-dl blocks until the search is done
+    let output = tokio::task
+        ::spawn_blocking(move || {
+            YouTubeDlSearch::new(search)
+                .socket_timeout("5")
+                .flat_playlist(true)
+                .ignore_errors(true)
+                .run()
+        }).await??;
+
+    Ok(
+        output
+            .into_playlist()
+            .and_then(|playlist| playlist.entries)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|video| {
+                let url = format!("https://www.youtube.com/watch?v={}", video.id);
+                (video.title.unwrap_or_else(|| url.clone()), url)
+            })
+            .collect()
+    )
+}
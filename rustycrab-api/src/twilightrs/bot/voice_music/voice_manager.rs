@@ -45,6 +45,11 @@ impl VoiceManager {
         }
     }
 
+    /// Number of voice calls the bot is currently in.
+    pub fn active_connections(&self) -> usize {
+        self.songbird.iter().count()
+    }
+
     /// Retrieves guild's call
     pub async fn fetch_call_lock(
         &self,
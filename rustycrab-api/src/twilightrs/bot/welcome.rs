@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use twilight_gateway::Event;
+use twilight_model::gateway::{ event::EventType, payload::incoming::MemberAdd };
+
+use crate::{
+    twilightrs::{
+        discord_client::DiscordClient,
+        bot::greeting::{ send_greeting, GreetingConfig },
+        event_bus::{ EventContext, EventFlow, EventListener },
+    },
+    utilities::app_error::BoxedError,
+    queries::guild_welcome_queries::GuildWelcomeQueries,
+    unique_bot_guild_entity_queries::UniqueBotGuildEntityQueries,
+};
+
+/// Sends the guild's configured welcome message when a member joins.
+///
+/// The welcome row is looked up by the bot and guild Discord IDs, guilds without a welcome
+/// configured are skipped.
+pub async fn send_welcome_message(client: DiscordClient, event: &MemberAdd) -> Result<(), BoxedError> {
+    let bot = client.get_bot().await?;
+
+    let welcome = GuildWelcomeQueries::find_optional_by_discord_ids(
+        &client.db,
+        &bot.id.to_string(),
+        &event.guild_id.to_string()
+    ).await?;
+    let row = welcome.map(|welcome| GreetingConfig {
+        channel_id: welcome.channel_id,
+        message_id: welcome.message_id,
+    });
+
+    send_greeting(&client, event.guild_id, &event.user, row).await
+}
+
+/// Sends the guild's welcome message to members joining.
+pub struct WelcomeListener {}
+
+#[async_trait]
+impl EventListener for WelcomeListener {
+    fn name(&self) -> &'static str {
+        "welcome"
+    }
+
+    fn events(&self) -> Vec<EventType> {
+        vec![EventType::MemberAdd]
+    }
+
+    async fn handle(&self, ctx: &EventContext) -> Result<EventFlow, BoxedError> {
+        if let Event::MemberAdd(event) = &ctx.event {
+            send_welcome_message(Arc::clone(&ctx.client), event).await?;
+        }
+
+        Ok(EventFlow::Continue)
+    }
+}
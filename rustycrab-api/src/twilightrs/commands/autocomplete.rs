@@ -0,0 +1,68 @@
+//! Helpers for answering the autocomplete interactions of slash command options.
+
+use twilight_model::application::{
+    command::{ CommandOptionChoice, CommandOptionChoiceValue },
+    interaction::application_command::{ CommandDataOption, CommandOptionValue },
+};
+
+/// Most choices Discord accepts in an autocomplete response.
+pub const MAX_AUTOCOMPLETE_CHOICES: usize = 25;
+
+/// Longest name or string value of a choice.
+const MAX_CHOICE_LENGTH: usize = 100;
+
+/// The option being typed, the one the suggestions are for.
+pub struct FocusedOption {
+    /// Name of the slash command option
+    pub name: String,
+    /// What was typed so far
+    pub value: String,
+}
+
+/// Finds the focused option among the options of a command or subcommand.
+pub fn focused_option(options: &[CommandDataOption]) -> Option<FocusedOption> {
+    options.iter().find_map(|option| {
+        match &option.value {
+            CommandOptionValue::Focused(value, _) =>
+                Some(FocusedOption {
+                    name: option.name.clone(),
+                    value: value.clone(),
+                }),
+            _ => None,
+        }
+    })
+}
+
+/// Whether a suggestion matches what was typed, ignoring case.
+pub fn matches_typed(candidate: &str, typed: &str) -> bool {
+    candidate.to_lowercase().contains(&typed.trim().to_lowercase())
+}
+
+pub fn string_choice(name: &str, value: &str) -> CommandOptionChoice {
+    CommandOptionChoice {
+        name: truncate(name),
+        name_localizations: None,
+        value: CommandOptionChoiceValue::String(truncate(value)),
+    }
+}
+
+pub fn integer_choice(name: &str, value: i64) -> CommandOptionChoice {
+    CommandOptionChoice {
+        name: truncate(name),
+        name_localizations: None,
+        value: CommandOptionChoiceValue::Integer(value),
+    }
+}
+
+fn truncate(text: &str) -> String {
+    if text.chars().count() <= MAX_CHOICE_LENGTH {
+        return text.to_string();
+    }
+
+    let mut truncated: String = text
+        .chars()
+        .take(MAX_CHOICE_LENGTH - 1)
+        .collect();
+    truncated.push('…');
+    truncated
+}
@@ -0,0 +1,73 @@
+use std::{ collections::HashMap, sync::Mutex, time::{ Duration, Instant } };
+
+use twilight_model::id::{ Id, marker::MessageMarker };
+
+/// How long after being sent an edited message still re-runs its command.
+pub const EDIT_RERUN_WINDOW: Duration = Duration::from_secs(120);
+
+/// Number of tracked replies above which the replies older than the window are dropped.
+const CLEANUP_THRESHOLD: usize = 10_000;
+
+/// First replies of recent prefix commands, by the ID of the message that invoked them.
+///
+/// A command re-run after its message was edited edits its previous reply instead of
+/// sending a new one. Messages whose last run didn't execute a command are tracked too, editing
+/// them fixes a typo whatever the command.
+#[derive(Default)]
+pub struct CommandReplies {
+    replies: Mutex<HashMap<Id<MessageMarker>, (Instant, Id<MessageMarker>)>>,
+    not_executed: Mutex<HashMap<Id<MessageMarker>, Instant>>,
+}
+
+impl CommandReplies {
+    /// Records the first reply to a command message, a re-run replaces the recorded reply.
+    pub fn record(&self, message_id: Id<MessageMarker>, reply_id: Id<MessageMarker>) {
+        let now = Instant::now();
+        let mut replies = self.replies.lock().unwrap();
+        if replies.len() > CLEANUP_THRESHOLD {
+            replies.retain(|_, (replied_at, _)| {
+                now.duration_since(*replied_at) < EDIT_RERUN_WINDOW
+            });
+        }
+
+        replies.insert(message_id, (now, reply_id));
+    }
+
+    /// The reply to a command message, if it was sent within the re-run window.
+    pub fn get(&self, message_id: Id<MessageMarker>) -> Option<Id<MessageMarker>> {
+        self.replies
+            .lock()
+            .unwrap()
+            .get(&message_id)
+            .filter(|(replied_at, _)| replied_at.elapsed() < EDIT_RERUN_WINDOW)
+            .map(|(_, reply_id)| *reply_id)
+    }
+
+    /// Records that a message didn't execute a command, its name is unknown or its arguments
+    /// are wrong.
+    pub fn record_not_executed(&self, message_id: Id<MessageMarker>) {
+        let now = Instant::now();
+        let mut not_executed = self.not_executed.lock().unwrap();
+        if not_executed.len() > CLEANUP_THRESHOLD {
+            not_executed.retain(|_, recorded_at| {
+                now.duration_since(*recorded_at) < EDIT_RERUN_WINDOW
+            });
+        }
+
+        not_executed.insert(message_id, now);
+    }
+
+    /// Records that a message executed its command.
+    pub fn record_executed(&self, message_id: Id<MessageMarker>) {
+        self.not_executed.lock().unwrap().remove(&message_id);
+    }
+
+    /// Whether the last run of a message, within the re-run window, didn't execute a command.
+    pub fn not_executed(&self, message_id: Id<MessageMarker>) -> bool {
+        self.not_executed
+            .lock()
+            .unwrap()
+            .get(&message_id)
+            .map_or(false, |recorded_at| recorded_at.elapsed() < EDIT_RERUN_WINDOW)
+    }
+}
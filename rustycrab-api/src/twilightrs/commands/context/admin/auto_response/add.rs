@@ -7,7 +7,7 @@ use rustycrab_model::{
         discord_message::RequestCreateUpdateMessage,
     },
 };
-use twilight_model::gateway::payload::incoming::MessageCreate;
+use crate::twilightrs::commands::invocation::CommandInvocation;
 use std::error::Error;
 
 use crate::{
@@ -48,7 +48,7 @@ impl ContextCommand for AddAutoResponseCommand {
         &self,
         client: DiscordClient,
         config: &GuildConfigModel,
-        msg: &MessageCreate,
+        msg: &CommandInvocation,
         command_args: Vec<ParsedArg>
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         let guild_id = msg.guild_id.ok_or("command-guildonly")?;
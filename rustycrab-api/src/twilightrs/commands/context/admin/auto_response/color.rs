@@ -7,7 +7,8 @@ use rustycrab_model::{
         discord_message::{ RequestCreateUpdateMessage, RequestCreateUpdateEmbed },
     },
 };
-use twilight_model::gateway::payload::incoming::MessageCreate;
+use crate::twilightrs::commands::{ invocation::CommandInvocation, autocomplete::FocusedOption };
+use twilight_model::application::command::CommandOptionChoice;
 use std::error::Error;
 
 use crate::{
@@ -27,7 +28,7 @@ use crate::{
     default_queries::DefaultSeaQueries,
 };
 
-use super::{ AutoResCommand, utils::split_trigger_and_value };
+use super::{ AutoResCommand, utils::{ split_trigger_and_value, trigger_choices } };
 pub struct ColorUpdateAutoResCommand;
 
 #[async_trait]
@@ -37,18 +38,30 @@ impl ContextCommand for ColorUpdateAutoResCommand {
     }
 
     fn args(&self) -> Vec<ArgSpec> {
-        vec![ArgSpec::new("trigger | color hex (#fafafa)", ArgType::Text, false)]
+        vec![
+            ArgSpec::new("trigger | color hex (#fafafa)", ArgType::Text, false).with_autocomplete()
+        ]
     }
 
     fn parent_command(&self) -> Option<Box<dyn ContextCommand>> {
         Some(Box::new(AutoResCommand {}) as Box<dyn ContextCommand>)
     }
 
+    async fn autocomplete(
+        &self,
+        client: DiscordClient,
+        _: &GuildConfigModel,
+        msg: &CommandInvocation,
+        option: &FocusedOption
+    ) -> Result<Vec<CommandOptionChoice>, Box<dyn Error + Send + Sync + 'static>> {
+        trigger_choices(&client, msg, option).await
+    }
+
     async fn run(
         &self,
         client: DiscordClient,
         config: &GuildConfigModel,
-        msg: &MessageCreate,
+        msg: &CommandInvocation,
         command_args: Vec<ParsedArg>
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         let guild_id = msg.guild_id.ok_or("command-guildonly")?;
@@ -100,9 +113,8 @@ impl ContextCommand for ColorUpdateAutoResCommand {
         embed.color = Some(color.as_u32());
         embed.author_name = Some(client.get_locale_string(&config.locale, key, Some(&args)));
 
-        let _ = client.reply_message(
-            msg.channel_id,
-            msg.id,
+        let _ = msg.reply(
+            &client,
             MessageContent::DiscordEmbeds(vec![embed])
         ).await;
 
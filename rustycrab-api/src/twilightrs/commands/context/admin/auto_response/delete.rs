@@ -1,7 +1,8 @@
 use async_trait::async_trait;
 use fluent_bundle::FluentArgs;
 use rustycrab_model::color::ColorResolvables;
-use twilight_model::gateway::payload::incoming::MessageCreate;
+use crate::twilightrs::commands::{ invocation::CommandInvocation, autocomplete::FocusedOption };
+use twilight_model::application::command::CommandOptionChoice;
 use std::error::Error;
 
 use crate::{
@@ -20,7 +21,7 @@ use crate::{
     default_queries::DefaultSeaQueries,
 };
 
-use super::AutoResCommand;
+use super::{ AutoResCommand, utils::trigger_choices };
 pub struct DeleteAutoResponseCommand;
 
 #[async_trait]
@@ -33,18 +34,28 @@ impl ContextCommand for DeleteAutoResponseCommand {
         vec!["del", "remove"]
     }
     fn args(&self) -> Vec<ArgSpec> {
-        vec![ArgSpec::new("trigger", ArgType::Text, false)]
+        vec![ArgSpec::new("trigger", ArgType::Text, false).with_autocomplete()]
     }
 
     fn parent_command(&self) -> Option<Box<dyn ContextCommand>> {
         Some(Box::new(AutoResCommand {}) as Box<dyn ContextCommand>)
     }
 
+    async fn autocomplete(
+        &self,
+        client: DiscordClient,
+        _: &GuildConfigModel,
+        msg: &CommandInvocation,
+        option: &FocusedOption
+    ) -> Result<Vec<CommandOptionChoice>, Box<dyn Error + Send + Sync + 'static>> {
+        trigger_choices(&client, msg, option).await
+    }
+
     async fn run(
         &self,
         client: DiscordClient,
         config: &GuildConfigModel,
-        msg: &MessageCreate,
+        msg: &CommandInvocation,
         command_args: Vec<ParsedArg>
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         let guild_id = msg.guild_id.ok_or("command-guildonly")?;
@@ -4,11 +4,11 @@ use futures_util::StreamExt;
 use rustycrab_model::color::ColorResolvables;
 use tokio::time::timeout;
 use twilight_model::{
-    gateway::payload::incoming::MessageCreate,
     application::interaction::{ InteractionData, Interaction },
     channel::message::{ component::{ Button, ActionRow }, Embed, MessageFlags, Component },
     http::interaction::{ InteractionResponse, InteractionResponseType },
 };
+use crate::twilightrs::commands::invocation::CommandInvocation;
 use std::{ error::Error, time::Duration };
 
 use crate::{
@@ -40,7 +40,7 @@ impl ContextCommand for ListAutoResponseCommand {
         &self,
         client: DiscordClient,
         config: &GuildConfigModel,
-        msg: &MessageCreate,
+        msg: &CommandInvocation,
         _: Vec<ParsedArg>
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         let guild_id = msg.guild_id.ok_or("command-guildonly")?;
@@ -98,9 +98,8 @@ impl ContextCommand for ListAutoResponseCommand {
         embed.description = Some(get_description(&list, current_page));
 
         if pages == 1 {
-            let _ = client.reply_message(
-                msg.channel_id,
-                msg.id,
+            let _ = msg.reply(
+                &client,
                 MessageContent::DiscordEmbeds(vec![embed.clone()])
             ).await;
             return Ok(());
@@ -129,18 +128,15 @@ impl ContextCommand for ListAutoResponseCommand {
             }
         ];
 
-        let sent_msg = client.http
-            .create_message(msg.channel_id)
-            .reply(msg.id)
-            .embeds(&vec![Embed::from(embed.clone())])?
-            .components(
-                &vec![
-                    Component::ActionRow(ActionRow {
-                        components: make_components(&buttons),
-                    })
-                ]
-            )?.await?
-            .model().await?;
+        let sent_msg = msg.reply_with_components(
+            &client,
+            MessageContent::DiscordEmbeds(vec![embed.clone()]),
+            &vec![
+                Component::ActionRow(ActionRow {
+                    components: make_components(&buttons),
+                })
+            ]
+        ).await?;
 
         // Set the timeout duration to 5 minutes
         let timeout_duration = Duration::from_secs(5 * 60);
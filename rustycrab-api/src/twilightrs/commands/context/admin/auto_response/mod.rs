@@ -1,4 +1,4 @@
-mod utils;
+pub mod utils;
 mod add;
 mod delete;
 mod list;
@@ -13,14 +13,15 @@ use delete::DeleteAutoResponseCommand;
 use fluent_bundle::FluentArgs;
 use list::ListAutoResponseCommand;
 use image::ImageUpdateAutoResCommand;
-use rustycrab_model::color::ColorResolvables;
+use rustycrab_model::{ color::ColorResolvables, modules::GuildModule };
 use thumbnail::ThumbnailUpdateAutoResCommand;
 use color::ColorUpdateAutoResCommand;
 use content::ContentUpdateAutoResCommand;
 use text_message::MessageUpdateAutoResCommand;
 
 use async_trait::async_trait;
-use twilight_model::{ gateway::payload::incoming::MessageCreate, guild::Permissions };
+use twilight_model::guild::Permissions;
+use crate::twilightrs::commands::invocation::CommandInvocation;
 use std::error::Error;
 
 use crate::{
@@ -39,6 +40,10 @@ impl ContextCommand for AutoResCommand {
         "autores"
     }
 
+    fn module(&self) -> Option<GuildModule> {
+        Some(GuildModule::AutoResponses)
+    }
+
     fn permissions(&self) -> Vec<Permissions> {
         vec![Permissions::ADMINISTRATOR]
     }
@@ -64,7 +69,7 @@ impl ContextCommand for AutoResCommand {
         &self,
         client: DiscordClient,
         config: &GuildConfigModel,
-        msg: &MessageCreate,
+        msg: &CommandInvocation,
         _: Vec<ParsedArg>
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         let _ = msg.guild_id.ok_or(
@@ -96,9 +101,8 @@ impl ContextCommand for AutoResCommand {
             .collect::<Vec<String>>()
             .join("\n");
 
-        let _ = client.reply_message(
-            msg.channel_id,
-            msg.id,
+        let _ = msg.reply(
+            &client,
             crate::twilightrs::discord_client::MessageContent::TextAndDiscordEmbeds(
                 format!("```fix\n{}```", description),
                 vec![DiscordEmbed {
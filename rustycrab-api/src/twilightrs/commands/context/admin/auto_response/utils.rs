@@ -1,4 +1,18 @@
-use crate::{ twilightrs::commands::context::ParsedArg, utilities::app_error::BoxedError };
+use twilight_model::application::command::CommandOptionChoice;
+
+use crate::{
+    twilightrs::{
+        commands::{
+            context::ParsedArg,
+            autocomplete::{ FocusedOption, MAX_AUTOCOMPLETE_CHOICES, string_choice, matches_typed },
+            invocation::CommandInvocation,
+        },
+        discord_client::DiscordClient,
+    },
+    queries::auto_responses_queries::AutoResponsesQueries,
+    multi_bot_guild_entities_queries::MultipleBotGuildEntityQueries,
+    utilities::app_error::BoxedError,
+};
 
 pub fn split_trigger_and_value(
     command_args: Vec<ParsedArg>
@@ -18,3 +32,41 @@ pub fn split_trigger_and_value(
         }
     )
 }
+
+/// Suggests the guild's triggers matching the trigger part of `trigger | value` arguments.
+///
+/// The value typed after `|` is kept in the suggestions, so picking one doesn't erase it.
+pub async fn trigger_choices(
+    client: &DiscordClient,
+    msg: &CommandInvocation,
+    option: &FocusedOption
+) -> Result<Vec<CommandOptionChoice>, BoxedError> {
+    let guild_id = msg.guild_id.ok_or("command-guildonly")?;
+
+    let (typed_trigger, value) = match option.value.find("|") {
+        Some(idx) => {
+            let (trigger_part, value_part) = option.value.split_at(idx);
+            (trigger_part.trim(), Some(value_part[1..].trim()))
+        }
+        None => (option.value.trim(), None),
+    };
+
+    Ok(
+        AutoResponsesQueries::find_by_discord_ids(
+            &client.db,
+            &client.bot_id.to_string(),
+            &guild_id.to_string()
+        ).await?
+            .iter()
+            .filter(|autores| matches_typed(&autores.trigger, typed_trigger))
+            .take(MAX_AUTOCOMPLETE_CHOICES)
+            .map(|autores| {
+                let choice = match value {
+                    Some(value) => format!("{} | {}", autores.trigger, value),
+                    None => autores.trigger.clone(),
+                };
+                string_choice(&choice, &choice)
+            })
+            .collect()
+    )
+}
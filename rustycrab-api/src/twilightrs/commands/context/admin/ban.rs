@@ -1,7 +1,8 @@
 use async_trait::async_trait;
 use fluent_bundle::FluentArgs;
-use rustycrab_model::color::ColorResolvables;
-use twilight_model::{ gateway::payload::incoming::MessageCreate, guild::Permissions };
+use rustycrab_model::{ color::ColorResolvables, modules::GuildModule };
+use twilight_model::guild::Permissions;
+use crate::twilightrs::commands::invocation::CommandInvocation;
 use std::error::Error;
 
 use crate::twilightrs::{
@@ -23,6 +24,10 @@ impl ContextCommand for BanMemberCommand {
         "ban"
     }
 
+    fn module(&self) -> Option<GuildModule> {
+        Some(GuildModule::Moderation)
+    }
+
     fn args(&self) -> Vec<ArgSpec> {
         vec![
             ArgSpec::new("users", ArgType::Users, false),
@@ -38,7 +43,7 @@ impl ContextCommand for BanMemberCommand {
         &self,
         client: DiscordClient,
         config: &GuildConfigModel,
-        msg: &MessageCreate,
+        msg: &CommandInvocation,
         command_args: Vec<ParsedArg>
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         let guild_id = msg.guild_id.ok_or("command-guildonly")?;
@@ -1,6 +1,7 @@
 use async_trait::async_trait;
 use rustycrab_model::response::bot_guild_config::RequestUpdateConfig;
-use twilight_model::{ gateway::payload::incoming::MessageCreate, guild::Permissions };
+use twilight_model::guild::Permissions;
+use crate::twilightrs::commands::invocation::CommandInvocation;
 use std::error::Error;
 
 use crate::{
@@ -41,7 +42,7 @@ impl ContextCommand for ChangeLanguageCommand {
         &self,
         client: DiscordClient,
         config: &GuildConfigModel,
-        msg: &MessageCreate,
+        msg: &CommandInvocation,
         command_args: Vec<ParsedArg>
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         let _ = msg.guild_id.ok_or(
@@ -71,7 +72,7 @@ impl ContextCommand for ChangeLanguageCommand {
                 }
             };
 
-            let _ = client.send_message(msg.channel_id, MessageContent::Text(content)).await;
+            let _ = msg.send(&client, MessageContent::Text(content)).await;
         }
 
         Ok(())
@@ -7,7 +7,9 @@ mod kick;
 mod timeout;
 mod untimeout;
 mod set_role;
-mod auto_response;
+mod role_menu;
+pub mod auto_response;
+mod modules;
 
 use self::{
     prefix::ChangePrefixCommand,
@@ -19,7 +21,9 @@ use self::{
     timeout::TimeoutMemberCommand,
     untimeout::UntimeoutMemberCommand,
     set_role::RoleCommand,
+    role_menu::RoleMenuCommand,
     auto_response::AutoResCommand,
+    modules::ModulesCommand,
 };
 use super::{ ContextCommandCategory, context_command::ContextCommand };
 
@@ -41,7 +45,9 @@ impl ContextCommandCategory for AdminCommands {
             Box::new(TimeoutMemberCommand) as Box<dyn ContextCommand>,
             Box::new(UntimeoutMemberCommand) as Box<dyn ContextCommand>,
             Box::new(RoleCommand) as Box<dyn ContextCommand>,
+            Box::new(RoleMenuCommand) as Box<dyn ContextCommand>,
             Box::new(AutoResCommand) as Box<dyn ContextCommand>,
+            Box::new(ModulesCommand) as Box<dyn ContextCommand>,
         ])
     }
 }
@@ -0,0 +1,140 @@
+use async_trait::async_trait;
+use fluent_bundle::FluentArgs;
+use rustycrab_model::{
+    color::ColorResolvables,
+    modules::GuildModule,
+    response::bot_guild_config::RequestUpdateConfig,
+};
+use twilight_model::guild::Permissions;
+use crate::twilightrs::commands::invocation::CommandInvocation;
+use std::error::Error;
+
+use crate::{
+    twilightrs::{
+        commands::context::{
+            ContextCommand,
+            ParsedArg,
+            ArgSpec,
+            ArgType,
+            context_command::GuildConfigModel,
+        },
+        discord_client::{ DiscordClient, MessageContent },
+        messages::DiscordEmbed,
+        utils::reply_command,
+    },
+    queries::guild_config_queries::GuildConfigQueries,
+    default_queries::DefaultSeaQueries,
+};
+
+pub struct ModulesCommand;
+
+#[async_trait]
+impl ContextCommand for ModulesCommand {
+    fn name(&self) -> &'static str {
+        "modules"
+    }
+
+    fn aliases(&self) -> Vec<&'static str> {
+        vec!["module"]
+    }
+
+    fn args(&self) -> Vec<ArgSpec> {
+        vec![
+            ArgSpec::new("enable/disable", ArgType::Arg, true),
+            ArgSpec::new("module", ArgType::Arg, true)
+        ]
+    }
+
+    fn permissions(&self) -> Vec<Permissions> {
+        vec![Permissions::ADMINISTRATOR]
+    }
+
+    async fn run(
+        &self,
+        client: DiscordClient,
+        config: &GuildConfigModel,
+        msg: &CommandInvocation,
+        command_args: Vec<ParsedArg>
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let _ = msg.guild_id.ok_or(
+            client.get_locale_string(&config.locale, "command-guildonly", None)
+        )?;
+
+        let (action, module_name) = match (command_args.get(0), command_args.get(1)) {
+            (Some(ParsedArg::Arg(action)), Some(ParsedArg::Arg(module_name))) => {
+                (action.to_lowercase(), module_name)
+            }
+            // without a module to update, list the modules
+            _ => {
+                let description = GuildModule::ALL.iter()
+                    .map(|module| {
+                        let state = if module.is_enabled(config.module_flags) { "✅" } else { "❌" };
+                        format!("{} `{}`", state, module.name())
+                    })
+                    .collect::<Vec<String>>()
+                    .join("\n");
+
+                msg.reply(
+                    &client,
+                    MessageContent::DiscordEmbeds(
+                        vec![DiscordEmbed {
+                            title: Some(
+                                client.get_locale_string(&config.locale, "command-modules-title", None)
+                            ),
+                            description: Some(description),
+                            color: Some(ColorResolvables::Blue.as_u32()),
+                            ..Default::default()
+                        }]
+                    )
+                ).await?;
+
+                return Ok(());
+            }
+        };
+
+        let mut args = FluentArgs::new();
+        let module = match GuildModule::from_name(module_name) {
+            Some(module) if action == "enable" || action == "disable" => module,
+            _ => {
+                args.set(
+                    "modules",
+                    GuildModule::ALL.iter()
+                        .map(|module| module.name())
+                        .collect::<Vec<&str>>()
+                        .join(", ")
+                );
+                let _ = reply_command(
+                    &client,
+                    &config,
+                    &msg,
+                    "command-modules-invalid",
+                    Some(args),
+                    ColorResolvables::Red
+                ).await;
+
+                return Ok(());
+            }
+        };
+
+        let enabled = action == "enable";
+        let update_result = GuildConfigQueries::update_by_id(
+            &client.db,
+            config.id,
+            RequestUpdateConfig {
+                module_flags: Some(module.set_enabled(config.module_flags, enabled)),
+                ..Default::default()
+            }
+        ).await;
+
+        args.set("module", module.name());
+        let (key, color) = match update_result {
+            Ok(_) if enabled => ("command-modules-enabled", ColorResolvables::Green),
+            Ok(_) => ("command-modules-disabled", ColorResolvables::Green),
+            Err(_) => ("command-modules-failed", ColorResolvables::Red),
+        };
+
+        let _ = reply_command(&client, &config, &msg, key, Some(args), color).await;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+use async_trait::async_trait;
+use fluent_bundle::FluentArgs;
+use rustycrab_model::color::ColorResolvables;
+use crate::twilightrs::commands::invocation::CommandInvocation;
+use std::error::Error;
+
+use crate::{
+    twilightrs::{
+        commands::context::{
+            ContextCommand,
+            ParsedArg,
+            ArgSpec,
+            ArgType,
+            context_command::GuildConfigModel,
+        },
+        discord_client::DiscordClient,
+        utils::reply_command,
+    },
+    queries::guild_config_queries::{ GuildConfigQueries, MAX_PREFIXES, MAX_PREFIX_LENGTH },
+};
+
+use super::{ ChangePrefixCommand, is_valid_prefix, save_extra_prefixes };
+
+pub struct AddPrefixCommand;
+
+#[async_trait]
+impl ContextCommand for AddPrefixCommand {
+    fn name(&self) -> &'static str {
+        "add"
+    }
+
+    fn args(&self) -> Vec<ArgSpec> {
+        vec![ArgSpec::new("prefix", ArgType::Arg, false)]
+    }
+
+    fn parent_command(&self) -> Option<Box<dyn ContextCommand>> {
+        Some(Box::new(ChangePrefixCommand {}) as Box<dyn ContextCommand>)
+    }
+
+    async fn run(
+        &self,
+        client: DiscordClient,
+        config: &GuildConfigModel,
+        msg: &CommandInvocation,
+        command_args: Vec<ParsedArg>
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let prefix = match command_args.first() {
+            Some(ParsedArg::Arg(prefix)) => prefix.clone(),
+            _ => {
+                return Ok(());
+            }
+        };
+
+        let mut args = FluentArgs::new();
+        args.set("prefix", prefix.clone());
+        let prefixes = GuildConfigQueries::prefixes(config);
+        let (key, color) = if !is_valid_prefix(&prefix) {
+            args.set("max", MAX_PREFIX_LENGTH);
+            ("command-prefix-invalid", ColorResolvables::Red)
+        } else if prefixes.iter().any(|known| known.to_lowercase() == prefix.to_lowercase()) {
+            ("command-prefix-exists", ColorResolvables::Yellow)
+        } else if prefixes.len() >= MAX_PREFIXES {
+            args.set("max", MAX_PREFIXES);
+            ("command-prefix-limit", ColorResolvables::Red)
+        } else {
+            let mut extra_prefixes = GuildConfigQueries::extra_prefixes(config);
+            extra_prefixes.push(prefix);
+
+            return save_extra_prefixes(
+                &client,
+                config,
+                msg,
+                extra_prefixes,
+                "command-prefix-added",
+                args
+            ).await;
+        };
+
+        let _ = reply_command(&client, &config, &msg, key, Some(args), color).await;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+use async_trait::async_trait;
+use fluent_bundle::FluentArgs;
+use rustycrab_model::{ color::ColorResolvables, response::bot_guild_config::RequestUpdateConfig };
+use crate::twilightrs::commands::invocation::CommandInvocation;
+use std::error::Error;
+
+use crate::{
+    twilightrs::{
+        commands::context::{
+            ContextCommand,
+            ParsedArg,
+            ArgSpec,
+            ArgType,
+            context_command::GuildConfigModel,
+        },
+        discord_client::DiscordClient,
+        utils::reply_command,
+    },
+    queries::guild_config_queries::GuildConfigQueries,
+    default_queries::DefaultSeaQueries,
+};
+
+use super::ChangePrefixCommand;
+
+/// Turns the mention of the bot as a prefix on or off.
+pub struct MentionPrefixCommand;
+
+#[async_trait]
+impl ContextCommand for MentionPrefixCommand {
+    fn name(&self) -> &'static str {
+        "mention"
+    }
+
+    fn args(&self) -> Vec<ArgSpec> {
+        vec![ArgSpec::new("on/off", ArgType::Bool, false)]
+    }
+
+    fn parent_command(&self) -> Option<Box<dyn ContextCommand>> {
+        Some(Box::new(ChangePrefixCommand {}) as Box<dyn ContextCommand>)
+    }
+
+    async fn run(
+        &self,
+        client: DiscordClient,
+        config: &GuildConfigModel,
+        msg: &CommandInvocation,
+        command_args: Vec<ParsedArg>
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let enabled = match command_args.first() {
+            Some(ParsedArg::Bool(enabled)) => *enabled,
+            _ => {
+                return Ok(());
+            }
+        };
+
+        let update_result = GuildConfigQueries::update_by_id(
+            &client.db,
+            config.id,
+            RequestUpdateConfig {
+                mention_prefix: Some(enabled as i8),
+                ..Default::default()
+            }
+        ).await;
+
+        let mut args = FluentArgs::new();
+        args.set("bot", format!("<@{}>", client.bot_id));
+        let (key, color) = match update_result {
+            Ok(_) if enabled => ("command-prefix-mention-on", ColorResolvables::Green),
+            Ok(_) => ("command-prefix-mention-off", ColorResolvables::Green),
+            Err(_) => ("command-prefix-failed", ColorResolvables::Red),
+        };
+        let _ = reply_command(&client, &config, &msg, key, Some(args), color).await;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,168 @@
+mod set;
+mod add;
+mod remove;
+mod mention;
+
+use async_trait::async_trait;
+use fluent_bundle::FluentArgs;
+use rustycrab_model::{ color::ColorResolvables, response::bot_guild_config::RequestUpdateConfig };
+use twilight_model::guild::Permissions;
+use crate::twilightrs::commands::invocation::CommandInvocation;
+use std::error::Error;
+
+use crate::{
+    twilightrs::{
+        commands::context::{
+            ContextCommand,
+            ParsedArg,
+            ArgSpec,
+            ArgType,
+            context_command::GuildConfigModel,
+        },
+        discord_client::DiscordClient,
+        utils::reply_command,
+    },
+    queries::guild_config_queries::{ GuildConfigQueries, MAX_PREFIX_LENGTH },
+    default_queries::DefaultSeaQueries,
+};
+
+use self::{
+    set::SetPrefixCommand,
+    add::AddPrefixCommand,
+    remove::RemovePrefixCommand,
+    mention::MentionPrefixCommand,
+};
+
+pub struct ChangePrefixCommand;
+
+#[async_trait]
+impl ContextCommand for ChangePrefixCommand {
+    fn name(&self) -> &'static str {
+        "changeprefix"
+    }
+
+    fn aliases(&self) -> Vec<&'static str> {
+        vec!["prefix", "chprefix"]
+    }
+
+    fn args(&self) -> Vec<ArgSpec> {
+        // without a new prefix, the prefixes are listed
+        vec![ArgSpec::new("new prefix", ArgType::Arg, true)]
+    }
+
+    fn permissions(&self) -> Vec<Permissions> {
+        vec![Permissions::ADMINISTRATOR]
+    }
+
+    fn subcommands(&self) -> Vec<Box<dyn ContextCommand>> {
+        vec![
+            Box::new(SetPrefixCommand {}) as Box<dyn ContextCommand>,
+            Box::new(AddPrefixCommand {}) as Box<dyn ContextCommand>,
+            Box::new(RemovePrefixCommand {}) as Box<dyn ContextCommand>,
+            Box::new(MentionPrefixCommand {}) as Box<dyn ContextCommand>
+        ]
+    }
+
+    async fn run(
+        &self,
+        client: DiscordClient,
+        config: &GuildConfigModel,
+        msg: &CommandInvocation,
+        command_args: Vec<ParsedArg>
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let _ = msg.guild_id.ok_or(
+            client.get_locale_string(&config.locale, "command-guildonly", None)
+        )?;
+        if let Some(ParsedArg::Arg(new_prefix)) = command_args.first() {
+            return set_prefix(&client, config, msg, new_prefix).await;
+        }
+
+        let mut prefixes: Vec<String> = GuildConfigQueries::prefixes(config)
+            .iter()
+            .map(|prefix| format!("`{}`", prefix))
+            .collect();
+        if config.mention_prefix != 0 {
+            prefixes.push(format!("<@{}>", client.bot_id));
+        }
+
+        let mut args = FluentArgs::new();
+        args.set("prefixes", prefixes.join(", "));
+        let _ = reply_command(
+            &client,
+            &config,
+            &msg,
+            "command-prefix-list",
+            Some(args),
+            ColorResolvables::Blue
+        ).await;
+
+        Ok(())
+    }
+}
+
+/// Whether a prefix can be used, it can't be blank or too long.
+fn is_valid_prefix(prefix: &str) -> bool {
+    !prefix.trim().is_empty() && prefix.chars().count() <= MAX_PREFIX_LENGTH
+}
+
+/// Changes the main prefix of the guild, the one shown in the help.
+async fn set_prefix(
+    client: &DiscordClient,
+    config: &GuildConfigModel,
+    msg: &CommandInvocation,
+    new_prefix: &str
+) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+    let mut args = FluentArgs::new();
+    let (key, color) = if !is_valid_prefix(new_prefix) {
+        args.set("max", MAX_PREFIX_LENGTH);
+        ("command-prefix-invalid", ColorResolvables::Red)
+    } else {
+        let update_result = GuildConfigQueries::update_by_id(
+            &client.db,
+            config.id,
+            RequestUpdateConfig {
+                prefix: Some(new_prefix.to_string()),
+                ..Default::default()
+            }
+        ).await;
+
+        if let Ok(updated_config) = update_result {
+            args.set("prefix", updated_config.prefix);
+
+            ("command-prefix-success", ColorResolvables::Green)
+        } else {
+            ("command-prefix-failed", ColorResolvables::Red)
+        }
+    };
+
+    let _ = reply_command(client, config, msg, key, Some(args), color).await;
+
+    Ok(())
+}
+
+/// Replaces the prefixes the guild accepts besides the main one, replies with `key` once saved.
+async fn save_extra_prefixes(
+    client: &DiscordClient,
+    config: &GuildConfigModel,
+    msg: &CommandInvocation,
+    extra_prefixes: Vec<String>,
+    key: &str,
+    args: FluentArgs<'_>
+) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+    let update_result = GuildConfigQueries::update_by_id(
+        &client.db,
+        config.id,
+        RequestUpdateConfig {
+            extra_prefixes: Some(extra_prefixes),
+            ..Default::default()
+        }
+    ).await;
+
+    let (key, color) = match update_result {
+        Ok(_) => (key, ColorResolvables::Green),
+        Err(_) => ("command-prefix-failed", ColorResolvables::Red),
+    };
+    let _ = reply_command(client, config, msg, key, Some(args), color).await;
+
+    Ok(())
+}
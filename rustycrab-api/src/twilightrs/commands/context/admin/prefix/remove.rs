@@ -0,0 +1,83 @@
+use async_trait::async_trait;
+use fluent_bundle::FluentArgs;
+use rustycrab_model::color::ColorResolvables;
+use crate::twilightrs::commands::invocation::CommandInvocation;
+use std::error::Error;
+
+use crate::{
+    twilightrs::{
+        commands::context::{
+            ContextCommand,
+            ParsedArg,
+            ArgSpec,
+            ArgType,
+            context_command::GuildConfigModel,
+        },
+        discord_client::DiscordClient,
+        utils::reply_command,
+    },
+    queries::guild_config_queries::GuildConfigQueries,
+};
+
+use super::{ ChangePrefixCommand, save_extra_prefixes };
+
+pub struct RemovePrefixCommand;
+
+#[async_trait]
+impl ContextCommand for RemovePrefixCommand {
+    fn name(&self) -> &'static str {
+        "remove"
+    }
+
+    fn aliases(&self) -> Vec<&'static str> {
+        vec!["delete", "del"]
+    }
+
+    fn args(&self) -> Vec<ArgSpec> {
+        vec![ArgSpec::new("prefix", ArgType::Arg, false)]
+    }
+
+    fn parent_command(&self) -> Option<Box<dyn ContextCommand>> {
+        Some(Box::new(ChangePrefixCommand {}) as Box<dyn ContextCommand>)
+    }
+
+    async fn run(
+        &self,
+        client: DiscordClient,
+        config: &GuildConfigModel,
+        msg: &CommandInvocation,
+        command_args: Vec<ParsedArg>
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let prefix = match command_args.first() {
+            Some(ParsedArg::Arg(prefix)) => prefix.to_lowercase(),
+            _ => {
+                return Ok(());
+            }
+        };
+
+        let mut args = FluentArgs::new();
+        args.set("prefix", prefix.clone());
+        let mut extra_prefixes = GuildConfigQueries::extra_prefixes(config);
+        let count = extra_prefixes.len();
+        extra_prefixes.retain(|known| known.to_lowercase() != prefix);
+
+        let (key, color) = if config.prefix.to_lowercase() == prefix {
+            ("command-prefix-main", ColorResolvables::Yellow)
+        } else if extra_prefixes.len() == count {
+            ("command-prefix-notfound", ColorResolvables::Yellow)
+        } else {
+            return save_extra_prefixes(
+                &client,
+                config,
+                msg,
+                extra_prefixes,
+                "command-prefix-removed",
+                args
+            ).await;
+        };
+
+        let _ = reply_command(&client, &config, &msg, key, Some(args), color).await;
+
+        Ok(())
+    }
+}
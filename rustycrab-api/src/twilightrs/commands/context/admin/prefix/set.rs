@@ -0,0 +1,47 @@
+use async_trait::async_trait;
+use crate::twilightrs::commands::invocation::CommandInvocation;
+use std::error::Error;
+
+use crate::twilightrs::{
+    commands::context::{
+        ContextCommand,
+        ParsedArg,
+        ArgSpec,
+        ArgType,
+        context_command::GuildConfigModel,
+    },
+    discord_client::DiscordClient,
+};
+
+use super::{ ChangePrefixCommand, set_prefix };
+
+pub struct SetPrefixCommand;
+
+#[async_trait]
+impl ContextCommand for SetPrefixCommand {
+    fn name(&self) -> &'static str {
+        "set"
+    }
+
+    fn args(&self) -> Vec<ArgSpec> {
+        vec![ArgSpec::new("new prefix", ArgType::Arg, false)]
+    }
+
+    fn parent_command(&self) -> Option<Box<dyn ContextCommand>> {
+        Some(Box::new(ChangePrefixCommand {}) as Box<dyn ContextCommand>)
+    }
+
+    async fn run(
+        &self,
+        client: DiscordClient,
+        config: &GuildConfigModel,
+        msg: &CommandInvocation,
+        command_args: Vec<ParsedArg>
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        if let Some(ParsedArg::Arg(new_prefix)) = command_args.first() {
+            set_prefix(&client, config, msg, new_prefix).await?;
+        }
+
+        Ok(())
+    }
+}
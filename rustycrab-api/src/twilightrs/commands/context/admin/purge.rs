@@ -1,5 +1,7 @@
 use async_trait::async_trait;
-use twilight_model::{ gateway::payload::incoming::MessageCreate, guild::Permissions };
+use rustycrab_model::{ cooldown::{ CommandCooldown, CooldownScope }, modules::GuildModule };
+use twilight_model::guild::Permissions;
+use crate::twilightrs::commands::invocation::CommandInvocation;
 use std::error::Error;
 
 use crate::twilightrs::{
@@ -21,6 +23,10 @@ impl ContextCommand for PurgeCommand {
         "purge"
     }
 
+    fn module(&self) -> Option<GuildModule> {
+        Some(GuildModule::Moderation)
+    }
+
     fn aliases(&self) -> Vec<&'static str> {
         vec!["clear"]
     }
@@ -29,15 +35,23 @@ impl ContextCommand for PurgeCommand {
         vec![ArgSpec::new("amount of messages", ArgType::Number, false)] // User argument is not optional
     }
 
+    fn flags(&self) -> Vec<ArgSpec> {
+        vec![ArgSpec::new("user", ArgType::User, true), ArgSpec::new("bots", ArgType::Bool, true)]
+    }
+
     fn permissions(&self) -> Vec<Permissions> {
         vec![Permissions::MANAGE_MESSAGES]
     }
 
+    fn cooldown(&self) -> Option<CommandCooldown> {
+        Some(CommandCooldown::new(CooldownScope::Channel, 1, 5))
+    }
+
     async fn run(
         &self,
         client: DiscordClient,
         config: &GuildConfigModel,
-        msg: &MessageCreate,
+        msg: &CommandInvocation,
         command_args: Vec<ParsedArg>
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         let _ = msg.guild_id.ok_or(
@@ -48,18 +62,37 @@ impl ContextCommand for PurgeCommand {
             let amount: u64 = (*amount).try_into().unwrap_or(100);
             let amount = amount.min(100).max(2); // Discord API limits bulk delete to 2-100 messages
 
-            // Fetch the messages from the channel
+            // `--user=<user>` and `--bots` only delete the matching messages, the user is
+            // resolved to its ID when the arguments are parsed
+            let (user_filter, bots_only) = match command_args.last() {
+                Some(ParsedArg::Flags(flags)) => (flags.value("user"), flags.has("bots")),
+                _ => (None, false),
+            };
+            let filtered = user_filter.is_some() || bots_only;
+
+            // Fetch the messages from the channel, filters look through the last 100 messages
             let messages = client.http
                 .channel_messages(msg.channel_id)
-                .limit(amount as u16)?.await?
+                .limit(if filtered { 100 } else { amount as u16 })?.await?
                 .model().await?
                 .into_iter()
+                .filter(|message| {
+                    user_filter.map_or(true, |user_id| message.author.id.to_string() == user_id) &&
+                        (!bots_only || message.author.bot)
+                })
+                .take(amount as usize)
                 .map(|message| message.id)
                 .collect::<Vec<_>>();
 
-            // Bulk delete messages
-            if !messages.is_empty() {
-                let _ = client.http.delete_messages(msg.channel_id, &messages)?.await;
+            // Bulk delete messages, which needs at least two of them
+            match messages.as_slice() {
+                [] => {}
+                [message_id] => {
+                    let _ = client.http.delete_message(msg.channel_id, *message_id).await;
+                }
+                _ => {
+                    let _ = client.http.delete_messages(msg.channel_id, &messages)?.await;
+                }
             }
         }
 
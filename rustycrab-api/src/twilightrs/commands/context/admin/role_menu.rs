@@ -0,0 +1,146 @@
+use async_trait::async_trait;
+use rustycrab_model::{ color::ColorResolvables, modules::GuildModule };
+use twilight_model::{
+    channel::message::component::{ SelectMenu, SelectMenuType },
+    guild::{ Permissions, Role },
+};
+use crate::twilightrs::commands::invocation::CommandInvocation;
+use std::error::Error;
+use crate::twilightrs::{
+    commands::context::{
+        ContextCommand,
+        ParsedArg,
+        ArgSpec,
+        ArgType,
+        context_command::GuildConfigModel,
+    },
+    discord_client::{ DiscordClient, MessageContent },
+    messages::DiscordEmbed,
+    events::interaction_handlers::select_menus::SelectMenuEvents,
+    utils::select_menus::{ select_menu, select_option, select_menu_row, MAX_SELECT_OPTIONS },
+};
+
+/// Permissions a role picked from a menu can't grant, members would moderate themselves in.
+const MODERATION_PERMISSIONS: Permissions = Permissions::ADMINISTRATOR
+    .union(Permissions::MANAGE_CHANNELS)
+    .union(Permissions::MANAGE_GUILD)
+    .union(Permissions::MANAGE_MESSAGES)
+    .union(Permissions::MANAGE_NICKNAMES)
+    .union(Permissions::MANAGE_ROLES)
+    .union(Permissions::MANAGE_WEBHOOKS)
+    .union(Permissions::MANAGE_GUILD_EXPRESSIONS)
+    .union(Permissions::MANAGE_EVENTS)
+    .union(Permissions::MANAGE_THREADS);
+
+/// Sends a menu members use to pick their own roles among the given ones.
+pub struct RoleMenuCommand;
+
+#[async_trait]
+impl ContextCommand for RoleMenuCommand {
+    fn name(&self) -> &'static str {
+        "rolemenu"
+    }
+
+    fn module(&self) -> Option<GuildModule> {
+        Some(GuildModule::Moderation)
+    }
+
+    fn args(&self) -> Vec<ArgSpec> {
+        vec![ArgSpec::new("roles", ArgType::Args, false)]
+    }
+
+    fn permissions(&self) -> Vec<Permissions> {
+        vec![Permissions::MANAGE_ROLES]
+    }
+
+    async fn run(
+        &self,
+        client: DiscordClient,
+        config: &GuildConfigModel,
+        msg: &CommandInvocation,
+        command_args: Vec<ParsedArg>
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let guild_id = msg.guild_id.ok_or("command-guildonly")?;
+        let role_args = match command_args.first() {
+            Some(ParsedArg::Args(role_args)) => role_args,
+            _ => {
+                return Ok(());
+            }
+        };
+
+        // members can only offer roles below their highest one, the owner can offer any
+        let owner_id = client.get_guild(guild_id).await?.owner_id;
+        let author_position = if msg.author.id == owner_id {
+            None
+        } else {
+            let guild_roles = client.http.roles(guild_id).await?.model().await?;
+            Some(
+                guild_roles
+                    .iter()
+                    .filter(|role| msg.member_roles.contains(&role.id))
+                    .map(|role| role.position)
+                    .max()
+                    .unwrap_or(0)
+            )
+        };
+
+        // only roles the bot can give are offered, @everyone has the guild's ID
+        let mut roles: Vec<Role> = Vec::new();
+        for role_arg in role_args.iter().take(MAX_SELECT_OPTIONS) {
+            if let Ok(role) = client.find_role(guild_id, role_arg).await {
+                if
+                    role.id.get() != guild_id.get() &&
+                    !role.permissions.intersects(MODERATION_PERMISSIONS) &&
+                    author_position.map_or(true, |position| role.position < position) &&
+                    !roles.iter().any(|added| added.id == role.id) &&
+                    client.can_bot_manage_role(guild_id, role.id).await?
+                {
+                    roles.push(role);
+                }
+            }
+        }
+
+        if roles.is_empty() {
+            let content = client.get_locale_string(&config.locale, "command-rolemenu-empty", None);
+            return Err(content.into());
+        }
+
+        let options = roles
+            .iter()
+            .map(|role| select_option(&role.name, &role.id.to_string()))
+            .collect();
+        let menu = SelectMenu {
+            max_values: Some(roles.len() as u8),
+            min_values: Some(0),
+            options: Some(options),
+            ..select_menu(
+                SelectMenuEvents::RoleMenu.custom_id(&[]),
+                SelectMenuType::Text,
+                &client.get_locale_string(&config.locale, "command-rolemenu-placeholder", None)
+            )
+        };
+
+        msg.send_with_components(
+            &client,
+            MessageContent::DiscordEmbeds(
+                vec![DiscordEmbed {
+                    title: Some(
+                        client.get_locale_string(&config.locale, "command-rolemenu-title", None)
+                    ),
+                    description: Some(
+                        roles
+                            .iter()
+                            .map(|role| format!("<@&{}>", role.id))
+                            .collect::<Vec<String>>()
+                            .join("\n")
+                    ),
+                    color: Some(ColorResolvables::Blue.as_u32()),
+                    ..Default::default()
+                }]
+            ),
+            &[select_menu_row(menu)]
+        ).await?;
+
+        Ok(())
+    }
+}
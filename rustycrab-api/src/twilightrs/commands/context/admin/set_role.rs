@@ -1,12 +1,8 @@
 use async_trait::async_trait;
 use fluent_bundle::FluentArgs;
-use rustycrab_model::color::ColorResolvables;
-use twilight_http::Client;
-use twilight_model::{
-    gateway::payload::incoming::MessageCreate,
-    guild::Permissions,
-    id::{ marker::{ GuildMarker, UserMarker, RoleMarker }, Id },
-};
+use rustycrab_model::{ color::ColorResolvables, modules::GuildModule };
+use twilight_model::guild::Permissions;
+use crate::twilightrs::commands::invocation::CommandInvocation;
 use std::error::Error;
 use crate::twilightrs::{
     commands::context::{
@@ -29,6 +25,10 @@ impl ContextCommand for RoleCommand {
         "role"
     }
 
+    fn module(&self) -> Option<GuildModule> {
+        Some(GuildModule::Moderation)
+    }
+
     fn aliases(&self) -> Vec<&'static str> {
         vec!["setrole"]
     }
@@ -36,7 +36,7 @@ impl ContextCommand for RoleCommand {
     fn args(&self) -> Vec<ArgSpec> {
         vec![
             ArgSpec::new("users", ArgType::Users, false),
-            ArgSpec::new("role", ArgType::Text, false)
+            ArgSpec::new("role", ArgType::Role, false)
         ]
     }
 
@@ -48,32 +48,22 @@ impl ContextCommand for RoleCommand {
         &self,
         client: DiscordClient,
         config: &GuildConfigModel,
-        msg: &MessageCreate,
+        msg: &CommandInvocation,
         command_args: Vec<ParsedArg>
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         let guild_id = msg.guild_id.ok_or("command-guildonly")?;
         if let Some(ParsedArg::Users(users)) = command_args.get(0) {
-            if let Some(ParsedArg::Text(role_arg)) = command_args.get(1) {
-                // Find the role by name, ID, or mention
-                let role = client.find_role(guild_id, role_arg).await?;
+            if let Some(ParsedArg::Role(role)) = command_args.get(1) {
                 let mut args = FluentArgs::new();
                 args.set("role", format!("<@&{}>", role.id.to_string()));
-                if
-                    !can_bot_manage_role(
-                        &client.http,
-                        guild_id,
-                        client.get_bot().await?.id,
-                        role.id
-                    ).await?
-                {
+                if !client.can_bot_manage_role(guild_id, role.id).await? {
                     let message = client.get_locale_string(
                         &config.locale,
                         "command-role-no-perm",
                         Some(&args)
                     );
-                    let _ = client.reply_message(
-                        msg.channel_id,
-                        msg.id,
+                    let _ = msg.reply(
+                        &client,
                         crate::twilightrs::discord_client::MessageContent::DiscordEmbeds(
                             vec![DiscordEmbed {
                                 description: Some(message),
@@ -127,33 +117,3 @@ impl ContextCommand for RoleCommand {
         Ok(())
     }
 }
-
-async fn can_bot_manage_role(
-    http_client: &Client,
-    guild_id: Id<GuildMarker>,
-    bot_id: Id<UserMarker>,
-    target_role_id: Id<RoleMarker>
-) -> Result<bool, Box<dyn Error + Send + Sync>> {
-    // Fetch the bot's member information
-    let bot_member = http_client.guild_member(guild_id, bot_id).await?.model().await?;
-
-    // Fetch all roles in the guild
-    let roles = http_client.roles(guild_id).await?.model().await?;
-
-    // Determine the bot's highest role position
-    let bot_highest_role_position = bot_member.roles
-        .iter()
-        .filter_map(|role_id| roles.iter().find(|role| &role.id == role_id))
-        .map(|role| role.position)
-        .max()
-        .unwrap_or(0);
-
-    // Fetch the target role information
-    let target_role = roles
-        .into_iter()
-        .find(|role| role.id == target_role_id)
-        .ok_or("Target role not found")?;
-
-    // Check if the bot's highest role is higher than the target role
-    Ok(bot_highest_role_position > target_role.position)
-}
@@ -1,11 +1,12 @@
 use async_trait::async_trait;
 use fluent_bundle::FluentArgs;
-use rustycrab_model::color::ColorResolvables;
+use rustycrab_model::{ color::ColorResolvables, modules::GuildModule };
+use twilight_http::request::AuditLogReason;
 use twilight_model::{
-    gateway::payload::incoming::MessageCreate,
     guild::Permissions,
     util::Timestamp,
 };
+use crate::twilightrs::commands::invocation::CommandInvocation;
 use std::{ error::Error, time::SystemTime };
 
 use crate::twilightrs::{
@@ -17,11 +18,9 @@ use crate::twilightrs::{
         context_command::GuildConfigModel,
     },
     discord_client::DiscordClient,
-    utils::send_command_response,
+    utils::{ send_command_response, arguments::format_duration_units },
 };
 
-use std::time::Duration;
-
 pub struct TimeoutMemberCommand;
 
 #[async_trait]
@@ -30,6 +29,10 @@ impl ContextCommand for TimeoutMemberCommand {
         "timeout"
     }
 
+    fn module(&self) -> Option<GuildModule> {
+        Some(GuildModule::Moderation)
+    }
+
     fn aliases(&self) -> Vec<&'static str> {
         vec!["mute"]
     }
@@ -37,7 +40,7 @@ impl ContextCommand for TimeoutMemberCommand {
     fn args(&self) -> Vec<ArgSpec> {
         vec![
             ArgSpec::new("users", ArgType::Users, false),
-            ArgSpec::new("duration", ArgType::Number, false), // Duration in minutes
+            ArgSpec::new("duration", ArgType::Duration, false),
             ArgSpec::new("reason", ArgType::Text, true)
         ]
     }
@@ -50,15 +53,22 @@ impl ContextCommand for TimeoutMemberCommand {
         &self,
         client: DiscordClient,
         config: &GuildConfigModel,
-        msg: &MessageCreate,
+        msg: &CommandInvocation,
         command_args: Vec<ParsedArg>
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         let guild_id = msg.guild_id.ok_or("command-guildonly")?;
 
+        // the reason is the text after the duration
+        let reason = command_args.iter().find_map(|arg| {
+            match arg {
+                ParsedArg::Text(reason) if !reason.is_empty() => Some(reason.as_str()),
+                _ => None,
+            }
+        });
+
         if let Some(ParsedArg::Users(users)) = command_args.first() {
-            if let Some(ParsedArg::Number(duration)) = command_args.get(1) {
-                let timeout_duration = Duration::from_secs(*duration as u64); // Convert minutes to seconds
-                let timeout_end = SystemTime::now() + timeout_duration;
+            if let Some(ParsedArg::Duration(duration)) = command_args.get(1) {
+                let timeout_end = SystemTime::now() + *duration;
 
                 // Convert SystemTime to Timestamp
                 let timestamp = Timestamp::from_secs(
@@ -67,13 +77,16 @@ impl ContextCommand for TimeoutMemberCommand {
                 for user in users {
                     let mut args = FluentArgs::new();
                     args.set("user", format!("<@{}>", user.id.to_string()));
-                    args.set("duration", format!("{}", duration));
+                    args.set("duration", format_duration_units(duration));
+
+                    let mut request = client.http
+                        .update_guild_member(guild_id, user.id)
+                        .communication_disabled_until(Some(timestamp))?;
+                    if let Some(reason) = reason {
+                        request = request.reason(reason)?;
+                    }
 
-                    let (key, color) = match
-                        client.http
-                            .update_guild_member(guild_id, user.id)
-                            .communication_disabled_until(Some(timestamp))?.await
-                    {
+                    let (key, color) = match request.await {
                         Ok(_) => { ("command-timeout-success", ColorResolvables::Green) }
                         Err(e) => {
                             args.set("err", format!("{}", e));
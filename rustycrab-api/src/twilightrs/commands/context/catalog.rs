@@ -0,0 +1,103 @@
+//! Description of every context command, for the help and the docs page of the dashboard.
+
+use rustycrab_model::response::command_catalog::{
+    ResponseCommand,
+    ResponseCommandArg,
+    ResponseCommandCategory,
+};
+use twilight_model::guild::Permissions;
+
+use super::{
+    ArgSpec,
+    context_command::ContextCommand,
+    context_command_dispatcher::ContextCommandDispatcher,
+};
+
+/// The commands of every category, with their descriptions in the given locale.
+pub fn command_catalog(locale: &str) -> Vec<ResponseCommandCategory> {
+    ContextCommandDispatcher::categories()
+        .into_iter()
+        .map(|category| ResponseCommandCategory {
+            name: category.name().to_string(),
+            commands: category
+                .collect_commands()
+                .iter()
+                .map(|command| catalog_command(command.as_ref(), locale))
+                .collect(),
+        })
+        .collect()
+}
+
+/// Describes a command and its subcommands.
+pub fn catalog_command(command: &dyn ContextCommand, locale: &str) -> ResponseCommand {
+    let full_name = command.get_root_command();
+
+    ResponseCommand {
+        name: command.name().to_string(),
+        usage: format!("{} {}", full_name, command.get_args_string()).trim_end().to_string(),
+        full_name,
+        aliases: command
+            .aliases()
+            .into_iter()
+            .map(|alias| alias.to_string())
+            .collect(),
+        description: command.description(locale),
+        args: command.args().iter().map(catalog_arg).collect(),
+        flags: command.flags().iter().map(catalog_arg).collect(),
+        permissions: permission_names(&command.permissions()),
+        module: command.module(),
+        subcommands: command
+            .subcommands()
+            .iter()
+            .map(|subcommand| catalog_command(subcommand.as_ref(), locale))
+            .collect(),
+    }
+}
+
+fn catalog_arg(arg: &ArgSpec) -> ResponseCommandArg {
+    ResponseCommandArg {
+        name: arg.name().to_string(),
+        kind: arg.arg_type().name().to_string(),
+        optional: arg.is_optional(),
+        choices: arg
+            .arg_type()
+            .choices()
+            .iter()
+            .map(|choice| choice.to_string())
+            .collect(),
+    }
+}
+
+/// Lowercase names of the permissions, like `administrator` or `manage_messages`, for each of
+/// the alternatives a command accepts.
+pub fn permission_names(permissions: &[Permissions]) -> Vec<Vec<String>> {
+    permissions
+        .iter()
+        .map(|permission| {
+            permission
+                .iter_names()
+                .map(|(name, _)| name.to_lowercase())
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use twilight_model::guild::Permissions;
+
+    use super::permission_names;
+
+    #[test]
+    fn permission_names_keep_the_alternatives_apart() {
+        let permissions = [
+            Permissions::ADMINISTRATOR,
+            Permissions::BAN_MEMBERS | Permissions::KICK_MEMBERS,
+        ];
+
+        assert_eq!(
+            permission_names(&permissions),
+            vec![vec!["administrator"], vec!["kick_members", "ban_members"]]
+        );
+    }
+}
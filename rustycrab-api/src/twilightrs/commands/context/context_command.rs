@@ -1,26 +1,53 @@
 use crate::{
-    database::bot_guild_configurations,
+    database::{ bot_guild_configurations, command_rules::Model as CommandRuleModel },
+    queries::guild_config_queries::GuildConfigQueries,
     twilightrs::{
         discord_client::{ DiscordClient, MessageContent },
-        utils::greedy::{ greedy_user, greedy_users, greedy_channel, greedy_channels },
+        utils::{
+            greedy::{
+                greedy_user,
+                greedy_users,
+                greedy_channel,
+                greedy_channels,
+                greedy_role,
+                greedy_member,
+            },
+            arguments::{
+                parse_duration,
+                parse_bool,
+                parse_emoji,
+                parse_choice,
+                arg_spans,
+                unquote,
+            },
+        },
         messages::DiscordEmbed,
+        commands::{
+            invocation::CommandInvocation,
+            rules::check_command_rules,
+            autocomplete::{ focused_option, FocusedOption, MAX_AUTOCOMPLETE_CHOICES },
+        },
     },
     locales::{ load_localization, get_localized_string },
 };
 
-use rustycrab_model::color::ColorResolvables;
-use twilight_http::Client as HttpClient;
+use fluent_bundle::FluentArgs;
+use rustycrab_model::{ color::ColorResolvables, cooldown::CommandCooldown, modules::GuildModule };
 use twilight_model::{
-    gateway::payload::incoming::MessageCreate,
     user::User,
     channel::Channel,
     guild::Permissions,
+    application::{
+        command::{ Command, CommandOption, CommandOptionChoice, CommandOptionType, CommandType },
+        interaction::application_command::{ CommandDataOption, CommandOptionValue },
+    },
+    id::{ Id, marker::GuildMarker },
 };
 use std::{ error::Error, sync::Arc };
 
 use async_trait::async_trait;
 
-use super::{ ArgSpec, ParsedArg, ArgType };
+use super::{ ArgSpec, ArgError, ParsedArg, ParsedFlags, ArgType, slash_name };
 
 pub type GuildConfigModel = bot_guild_configurations::Model;
 
@@ -47,6 +74,12 @@ pub type GuildConfigModel = bot_guild_configurations::Model;
 ///
 /// - `aliases`: Returns a list of alternative names for the command.
 /// - `args`: Defines the arguments that the command expects. Useful for automatic argument parsing.
+/// - `flags`: Defines the named flags the command accepts, like `--silent` or `--reason=...`.
+/// - `module`: The guild module the command belongs to, the command can't be used while the module is disabled.
+/// - `cooldown`: Limits how often the command can be used, guilds can override it in their configuration.
+/// - `rerun_on_edit`: Whether the command runs again when its message is edited, only commands
+///   without side effects should.
+/// - `autocomplete`: Suggests values for the slash command options of arguments declared `with_autocomplete`.
 /// - `subcommands`: Allows nesting of commands within a parent command, enabling hierarchical command structures.
 /// - `parse_args`: Handles the parsing of command arguments based on the specifications provided in `args`.
 #[async_trait]
@@ -70,6 +103,12 @@ pub trait ContextCommand: Send + Sync {
         Vec::new()
     }
 
+    /// Named flags, given anywhere in the arguments as `--name` for `Bool` flags or as
+    /// `--name=value` for the others. Parsed flags are passed as the last `ParsedArg::Flags`.
+    fn flags(&self) -> Vec<ArgSpec> {
+        Vec::new()
+    }
+
     fn subcommands(&self) -> Vec<Box<dyn ContextCommand>> {
         Vec::new()
     }
@@ -78,12 +117,43 @@ pub trait ContextCommand: Send + Sync {
         None
     }
 
+    /// Subcommands belong to the module of their parent command unless they override it.
+    fn module(&self) -> Option<GuildModule> {
+        self.parent_command().and_then(|parent| parent.module())
+    }
+
+    fn cooldown(&self) -> Option<CommandCooldown> {
+        None
+    }
+
+    /// Whether the prefix command runs again when its message is edited shortly after being sent,
+    /// its first reply then edits the reply of the previous run.
+    ///
+    /// Off by default, commands that change something would do it again on every edit.
+    fn rerun_on_edit(&self) -> bool {
+        false
+    }
+
+    /// Suggestions for the focused slash command option, while it's being typed.
+    ///
+    /// Only called for the arguments declared `with_autocomplete`, at most 25 choices are kept.
+    #[allow(unused_variables)]
+    async fn autocomplete(
+        &self,
+        client: DiscordClient,
+        config: &GuildConfigModel,
+        msg: &CommandInvocation,
+        option: &FocusedOption
+    ) -> Result<Vec<CommandOptionChoice>, Box<dyn Error + Send + Sync + 'static>> {
+        Ok(Vec::new())
+    }
+
     #[allow(unused_variables)]
     async fn run(
         &self,
         client: DiscordClient,
         config: &GuildConfigModel,
-        msg: &MessageCreate,
+        msg: &CommandInvocation,
         command_args: Vec<ParsedArg>
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         Ok(())
@@ -92,7 +162,8 @@ pub trait ContextCommand: Send + Sync {
     /// Executes a context command with preprocessing.
     ///
     /// This function serves as the entry point for executing any command that implements the `ContextCommand` trait.
-    /// It first checks if the message sender have the required permissions to execute the command.
+    /// It first checks if the message sender have the required permissions to execute the command, that the guild's
+    /// command rules allow them to use it in this channel, and that the command is not on cooldown.
     /// Then, it check the presence of subcommands. If a subcommand is specified in the command arguments (`cmd_args`),
     /// it delegates the execution to that subcommand. Otherwise, it proceeds with the current command execution.
     ///
@@ -100,8 +171,10 @@ pub trait ContextCommand: Send + Sync {
     ///
     /// * `client` - A reference to the `DiscordClient` which provides functionalities to interact with Discord's API.
     /// * `config` - A reference to the guild configuration model, which contains configuration details relevant to the command execution.
-    /// * `msg` - The `MessageCreate` payload received from Discord, containing details about the message that triggered the command.
+    /// * `msg` - The `CommandInvocation` that triggered the command, either a message or a slash command interaction.
     /// * `cmd_args` - A slice of strings representing the arguments passed with the command. The first argument is typically the command or subcommand name.
+    /// * `category_name` - The category the command is listed in, command rules can target it.
+    /// * `rules` - The guild's command rules, loaded once by the dispatcher.
     ///
     /// # Returns
     ///
@@ -116,29 +189,43 @@ pub trait ContextCommand: Send + Sync {
         &self,
         client: DiscordClient,
         config: &GuildConfigModel,
-        msg: &MessageCreate,
-        cmd_args: &[&str]
+        msg: &CommandInvocation,
+        cmd_args: &[&str],
+        category_name: &str,
+        rules: &[CommandRuleModel]
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        // denies commands that wasn't coming from a guild
+        if msg.guild_id.is_none() {
+            return Ok(());
+        }
+
         // Check permissions
-        if let Some(_) = msg.guild_id {
-            let required_permissions = self.permissions();
-            if !required_permissions.is_empty() {
-                let user_permissions = client.cache
-                    .permissions()
-                    .in_channel(msg.author.id, msg.channel_id)?;
-
-                let has_permission = required_permissions
-                    .iter()
-                    .any(|&req_perm| user_permissions.contains(req_perm));
-                if !has_permission {
-                    // User does not have any of the required permissions
-                    println!("User does not have required permissions");
-                    return Ok(()); // or return an appropriate error
-                }
+        let required_permissions = self.permissions();
+        if !required_permissions.is_empty() {
+            let user_permissions = client.cache
+                .permissions()
+                .in_channel(msg.author.id, msg.channel_id)?;
+
+            let has_permission = required_permissions
+                .iter()
+                .any(|&req_perm| user_permissions.contains(req_perm));
+            if !has_permission {
+                // User does not have any of the required permissions
+                let content = client.get_locale_string(
+                    &config.locale,
+                    "command-missing-permissions",
+                    None
+                );
+                return reply_denied(&client, msg, content).await;
             }
-        } else {
-            // denies commands that wasn't coming from a guild
-            return Ok(());
+        }
+
+        // Check the guild's command rules
+        let command_name = self.get_root_command();
+        let denied = check_command_rules(rules, &command_name, category_name, self.module(), msg);
+        if let Err(denial) = denied {
+            let content = client.get_locale_string(&config.locale, denial.locale_key(), None);
+            return reply_denied(&client, msg, content).await;
         }
 
         // run the command or subcommands
@@ -149,23 +236,57 @@ pub trait ContextCommand: Send + Sync {
                     subcommand.name() == command_name ||
                     subcommand.aliases().contains(&command_name)
                 {
-                    return subcommand.exec(client, config, msg, &cmd_args[1..]).await;
+                    return subcommand.exec(
+                        client,
+                        config,
+                        msg,
+                        &cmd_args[1..],
+                        category_name,
+                        rules
+                    ).await;
                 }
             }
         }
 
         // parse the arguments for the command if there is any
         let arg_specs = self.args();
-        let parsed_args = self.parse_args(cmd_args, &arg_specs, &client.http).await;
+        let parsed_args = self.parse_args(
+            cmd_args,
+            &arg_specs,
+            &client,
+            msg.guild_id,
+            msg.args_content.as_deref()
+        ).await;
         match parsed_args {
             // if the arguments are successfully parsed, we run the command
             Ok(args) => {
+                // Check cooldowns once the arguments are valid, so typos don't use them up.
+                // The guild's override replaces the command's own cooldown
+                let cooldown = GuildConfigQueries::command_cooldowns(config)
+                    .remove(&command_name)
+                    .or(self.cooldown());
+                if let Some(cooldown) = cooldown {
+                    let hit = client.command_cooldowns.hit(&command_name, &cooldown, msg);
+                    if let Err(retry_after) = hit {
+                        let mut locale_args = FluentArgs::new();
+                        locale_args.set("seconds", retry_after.as_secs_f64().ceil() as u64);
+                        let content = client.get_locale_string(
+                            &config.locale,
+                            "command-cooldown",
+                            Some(&locale_args)
+                        );
+                        return reply_denied(&client, msg, content).await;
+                    }
+                }
+
+                if let Some(message_id) = msg.message_id() {
+                    client.command_replies.record_executed(message_id);
+                }
                 if let Err(err) = self.run(Arc::clone(&client), config, msg, args).await {
                     let content = client.get_locale_string(&config.locale, &err.to_string(), None);
                     // if an error happened with the command, prompt the user of the erro
-                    client.reply_message(
-                        msg.channel_id,
-                        msg.id,
+                    msg.reply(
+                        &client,
                         MessageContent::DiscordEmbeds(
                             vec![DiscordEmbed {
                                 description: Some(content),
@@ -174,24 +295,56 @@ pub trait ContextCommand: Send + Sync {
                             }]
                         )
                     ).await?;
+
+                    // the user was told about it, the error is still returned for the metrics
+                    return Err(err);
                 }
             }
-            // if the message command does not have correct arguments, prompt user the command usage
-            Err(_) => {
-                let _ = client.reply_message(
-                    msg.channel_id,
-                    msg.id,
-                    crate::twilightrs::discord_client::MessageContent::Text(
-                        format!(
-                            "```fix\n{}```",
-                            self
-                                .get_full_command()
-                                .into_iter()
-                                .map(|usage| format!("{}{}", config.prefix, usage))
-                                .collect::<Vec<String>>()
-                                .join("\n")
-                        )
+            // if the message command does not have correct arguments, tell the user which one
+            // is wrong and prompt them the command usage
+            Err(err) => {
+                // editing the message to fix the arguments runs the command again
+                if let Some(message_id) = msg.message_id() {
+                    client.command_replies.record_not_executed(message_id);
+                }
+                let error = err.downcast_ref::<ArgError>().map(|arg_error| {
+                    let mut args = FluentArgs::new();
+                    args.set("choices", arg_error.choices.clone());
+                    let expected = client.get_locale_string(
+                        &config.locale,
+                        arg_error.expected_key,
+                        Some(&args)
+                    );
+
+                    let mut args = FluentArgs::new();
+                    args.set("arg", arg_error.arg);
+                    args.set("expected", expected);
+                    client.get_locale_string(
+                        &config.locale,
+                        if arg_error.missing {
+                            "command-arg-missing"
+                        } else {
+                            "command-arg-invalid"
+                        },
+                        Some(&args)
                     )
+                });
+                let usage = format!(
+                    "```fix\n{}```",
+                    self
+                        .get_full_command()
+                        .into_iter()
+                        .map(|usage| format!("{}{}", config.prefix, usage))
+                        .collect::<Vec<String>>()
+                        .join("\n")
+                );
+
+                let _ = msg.reply(
+                    &client,
+                    MessageContent::Text(match error {
+                        Some(error) => format!("{}\n{}", error, usage),
+                        None => usage,
+                    })
                 ).await;
             }
         }
@@ -200,35 +353,92 @@ pub trait ContextCommand: Send + Sync {
     }
 
     /// Function to parse command arguments
+    ///
+    /// Flags declared by `flags` are taken out of the arguments first, then the remaining
+    /// arguments are parsed in order following `arg_specs`. Fails with an [`ArgError`] naming
+    /// the argument that is missing or invalid.
+    ///
+    /// `args_content` is what was typed after the prefix, `Text` arguments are taken from it
+    /// with their quotes, spacing and newlines. The quotes of prefix command arguments read as
+    /// a single value are removed.
     async fn parse_args(
         &self,
         cmd_args: &[&str],
         arg_specs: &Vec<ArgSpec>,
-        http: &HttpClient
+        client: &DiscordClient,
+        guild_id: Option<Id<GuildMarker>>,
+        args_content: Option<&str>
     ) -> Result<Vec<ParsedArg>, Box<dyn Error + Send + Sync>> {
+        // slash command options are never quoted
+        let single_value = |arg: &str| {
+            if args_content.is_some() { unquote(arg) } else { arg.to_string() }
+        };
+        let flag_specs = self.flags();
+        let mut flags = ParsedFlags::default();
+        let mut positional_args: Vec<&str> = Vec::new();
+        for &arg in cmd_args {
+            let flag = arg.strip_prefix("--").and_then(|flag| {
+                let (name, value) = flag.split_once('=').unwrap_or((flag, ""));
+                flag_specs
+                    .iter()
+                    .find(|spec| spec.name == name)
+                    .map(|spec| (spec, value))
+            });
+
+            match flag {
+                Some((flag_spec, value)) => {
+                    let value = match flag_spec.arg_type {
+                        ArgType::Bool if value.is_empty() => Some(true.to_string()),
+                        ArgType::Bool => parse_bool(value).map(|value| value.to_string()),
+                        ArgType::Number => value.parse::<i64>().ok().map(|_| value.to_string()),
+                        ArgType::Duration => parse_duration(value).map(|_| value.to_string()),
+                        ArgType::Emoji => parse_emoji(value),
+                        ArgType::Choice(choices) => parse_choice(value, choices),
+                        _ if value.is_empty() => {
+                            return Err(flag_spec.error(true).into());
+                        }
+                        ArgType::User | ArgType::Member | ArgType::Role => {
+                            let value = single_value(value);
+                            resolve_flag_id(client, guild_id, &flag_spec.arg_type, &value).await
+                        }
+                        _ => Some(single_value(value)),
+                    };
+                    match value {
+                        // `--silent=false` is the same as not giving the flag
+                        Some(value) if value != "false" => flags.insert(flag_spec.name, value),
+                        Some(_) => {}
+                        None => {
+                            return Err(flag_spec.error(false).into());
+                        }
+                    }
+                }
+                None => positional_args.push(arg),
+            }
+        }
+
         let mut parsed_args: Vec<ParsedArg> = Vec::new();
-        let mut remaining_args: &[&str] = cmd_args;
+        let mut remaining_args: &[&str] = &positional_args;
 
         for (_, arg_spec) in arg_specs.iter().enumerate() {
             match arg_spec.arg_type {
                 ArgType::Arg => {
                     if let Some(arg) = remaining_args.first() {
-                        parsed_args.push(ParsedArg::Arg(arg.to_string()));
+                        parsed_args.push(ParsedArg::Arg(single_value(*arg)));
                         remaining_args = &remaining_args[1..];
                     } else if !arg_spec.optional {
-                        return Err("Missing required number argument".into());
+                        return Err(arg_spec.error(true).into());
                     }
                 }
                 ArgType::Args => {
                     if remaining_args.is_empty() && !arg_spec.optional {
-                        return Err("Missing required string argument".into());
+                        return Err(arg_spec.error(true).into());
                     }
                     if !remaining_args.is_empty() {
                         parsed_args.push(
                             ParsedArg::Args(
                                 remaining_args
                                     .iter()
-                                    .map(|arg| arg.to_string())
+                                    .map(|&arg| single_value(arg))
                                     .collect()
                             )
                         );
@@ -237,36 +447,44 @@ pub trait ContextCommand: Send + Sync {
                 }
                 ArgType::Text => {
                     if remaining_args.is_empty() && !arg_spec.optional {
-                        return Err("Missing required string argument".into());
+                        return Err(arg_spec.error(true).into());
                     }
-                    let concatenated_string: String = remaining_args.join(" ");
-                    parsed_args.push(ParsedArg::Text(concatenated_string));
+                    let text = args_content
+                        .and_then(|content| typed_text(content, cmd_args, remaining_args))
+                        .unwrap_or_else(|| remaining_args.join(" "));
+                    parsed_args.push(ParsedArg::Text(text));
                     break; // Consume all remaining arguments
                 }
                 ArgType::Number => {
-                    if let Some(arg) = remaining_args.first() {
-                        if let Ok(number) = arg.parse::<i64>() {
+                    match remaining_args.first().map(|arg| arg.parse::<i64>()) {
+                        Some(Ok(number)) => {
                             parsed_args.push(ParsedArg::Number(number));
                             remaining_args = &remaining_args[1..];
-                        } else if !arg_spec.optional {
-                            return Err("Invalid number argument".into());
                         }
-                    } else if !arg_spec.optional {
-                        return Err("Missing required number argument".into());
+                        Some(Err(_)) if !arg_spec.optional => {
+                            return Err(arg_spec.error(false).into());
+                        }
+                        None if !arg_spec.optional => {
+                            return Err(arg_spec.error(true).into());
+                        }
+                        _ => {}
                     }
                 }
 
                 ArgType::User => {
-                    let (user, args) = greedy_user(http, remaining_args).await;
+                    let (user, args) = greedy_user(&client.http, remaining_args).await;
                     if let Some(user) = user {
                         parsed_args.push(ParsedArg::User(user));
                     } else if !arg_spec.optional {
-                        return Err("Missing required user argument".into());
+                        return Err(arg_spec.error(remaining_args.is_empty()).into());
                     }
                     remaining_args = args;
                 }
                 ArgType::Users => {
-                    let (users, args) = greedy_users(http, remaining_args).await;
+                    let (users, args) = greedy_users(&client.http, remaining_args).await;
+                    if users.is_empty() && !arg_spec.optional {
+                        return Err(arg_spec.error(remaining_args.is_empty()).into());
+                    }
                     let user_ids: Vec<User> = users
                         .into_iter()
                         .map(|user| user)
@@ -275,16 +493,16 @@ pub trait ContextCommand: Send + Sync {
                     remaining_args = args;
                 }
                 ArgType::Channel => {
-                    let (channel, args) = greedy_channel(http, remaining_args).await;
+                    let (channel, args) = greedy_channel(&client.http, remaining_args).await;
                     if let Some(channel) = channel {
                         parsed_args.push(ParsedArg::Channel(channel));
                     } else if !arg_spec.optional {
-                        return Err("Missing required user argument".into());
+                        return Err(arg_spec.error(remaining_args.is_empty()).into());
                     }
                     remaining_args = args;
                 }
                 ArgType::Channels => {
-                    let (channels, args) = greedy_channels(http, remaining_args).await;
+                    let (channels, args) = greedy_channels(&client.http, remaining_args).await;
                     let channels: Vec<Channel> = channels
                         .into_iter()
                         .map(|channel| channel)
@@ -292,12 +510,278 @@ pub trait ContextCommand: Send + Sync {
                     parsed_args.push(ParsedArg::Channels(channels));
                     remaining_args = args;
                 }
+                ArgType::Role | ArgType::Member => {
+                    let parsed = match guild_id {
+                        Some(guild_id) => {
+                            if let ArgType::Role = arg_spec.arg_type {
+                                let (role, args) = greedy_role(
+                                    client,
+                                    guild_id,
+                                    remaining_args
+                                ).await;
+                                role.map(|role| (ParsedArg::Role(role), args))
+                            } else {
+                                let (member, args) = greedy_member(
+                                    client,
+                                    guild_id,
+                                    remaining_args
+                                ).await;
+                                member.map(|member| (ParsedArg::Member(member), args))
+                            }
+                        }
+                        None => None,
+                    };
+
+                    if let Some((parsed, args)) = parsed {
+                        parsed_args.push(parsed);
+                        remaining_args = args;
+                    } else if !arg_spec.optional {
+                        return Err(arg_spec.error(remaining_args.is_empty()).into());
+                    }
+                }
+                ArgType::Duration | ArgType::Bool | ArgType::Emoji | ArgType::Choice(_) => {
+                    let parsed = remaining_args.first().map(|arg| {
+                        match arg_spec.arg_type {
+                            ArgType::Duration => parse_duration(arg).map(ParsedArg::Duration),
+                            ArgType::Bool => parse_bool(arg).map(ParsedArg::Bool),
+                            ArgType::Emoji => parse_emoji(arg).map(ParsedArg::Emoji),
+                            ArgType::Choice(choices) => {
+                                parse_choice(arg, choices).map(ParsedArg::Choice)
+                            }
+                            _ => None,
+                        }
+                    });
+
+                    match parsed {
+                        Some(Some(parsed)) => {
+                            parsed_args.push(parsed);
+                            remaining_args = &remaining_args[1..];
+                        }
+                        Some(None) if !arg_spec.optional => {
+                            return Err(arg_spec.error(false).into());
+                        }
+                        None if !arg_spec.optional => {
+                            return Err(arg_spec.error(true).into());
+                        }
+                        _ => {}
+                    }
+                }
             }
         }
 
+        if !flag_specs.is_empty() {
+            parsed_args.push(ParsedArg::Flags(flags));
+        }
+
         Ok(parsed_args)
     }
 
+    /// Builds the slash command registered for this command.
+    ///
+    /// Subcommands become slash subcommands, and their own subcommands become subcommand groups.
+    fn to_slash_command(&self) -> Command {
+        let permissions = self.permissions();
+
+        Command {
+            application_id: None,
+            // Discord requires every default permission, only use it when there is a single one
+            default_member_permissions: if permissions.len() == 1 {
+                permissions.first().copied()
+            } else {
+                None
+            },
+            dm_permission: Some(false),
+            description: slash_description(self.description("en"), self.name()),
+            description_localizations: None,
+            guild_id: None,
+            id: None,
+            kind: CommandType::ChatInput,
+            name: slash_name(self.name()),
+            name_localizations: None,
+            nsfw: None,
+            options: self.slash_options(0),
+            version: Id::new(1),
+        }
+    }
+
+    /// Options of the slash command, `depth` being how deep this command is nested.
+    fn slash_options(&self, depth: usize) -> Vec<CommandOption> {
+        let subcommands = self.subcommands();
+        if subcommands.is_empty() {
+            // flags are always optional, and Discord wants the required options first
+            let mut options: Vec<CommandOption> = self
+                .args()
+                .iter()
+                .chain(self.flags().iter())
+                .map(|arg| arg.to_command_option())
+                .collect();
+            for option in options.iter_mut().skip(self.args().len()) {
+                option.required = Some(false);
+            }
+
+            return options;
+        }
+
+        subcommands
+            .iter()
+            // slash commands can't be nested deeper than subcommands of a group
+            .filter(|subcommand| depth == 0 || subcommand.subcommands().is_empty())
+            .map(|subcommand| {
+                let kind = if depth == 0 && !subcommand.subcommands().is_empty() {
+                    CommandOptionType::SubCommandGroup
+                } else {
+                    CommandOptionType::SubCommand
+                };
+
+                CommandOption {
+                    autocomplete: None,
+                    channel_types: None,
+                    choices: None,
+                    description: slash_description(
+                        subcommand.description("en"),
+                        subcommand.name()
+                    ),
+                    description_localizations: None,
+                    kind,
+                    max_length: None,
+                    max_value: None,
+                    min_length: None,
+                    min_value: None,
+                    name: slash_name(subcommand.name()),
+                    name_localizations: None,
+                    options: Some(subcommand.slash_options(depth + 1)),
+                    required: None,
+                }
+            })
+            .collect()
+    }
+
+    /// Finds the command or subcommand an autocomplete interaction is for, and suggests values
+    /// for its focused option.
+    async fn complete(
+        &self,
+        client: DiscordClient,
+        config: &GuildConfigModel,
+        msg: &CommandInvocation,
+        options: &[CommandDataOption]
+    ) -> Result<Vec<CommandOptionChoice>, Box<dyn Error + Send + Sync + 'static>> {
+        // suggestions can reveal the guild's setup, they need the command's permissions
+        let required_permissions = self.permissions();
+        if !required_permissions.is_empty() {
+            let user_permissions = client.cache
+                .permissions()
+                .in_channel(msg.author.id, msg.channel_id)?;
+            if !required_permissions.iter().any(|&req_perm| user_permissions.contains(req_perm)) {
+                return Ok(Vec::new());
+            }
+        }
+
+        for option in options {
+            if
+                let
+                | CommandOptionValue::SubCommand(sub_options)
+                | CommandOptionValue::SubCommandGroup(sub_options) = &option.value
+            {
+                for subcommand in self.subcommands() {
+                    if slash_name(subcommand.name()) == option.name {
+                        return subcommand.complete(client, config, msg, sub_options).await;
+                    }
+                }
+                return Ok(Vec::new());
+            }
+        }
+
+        let focused = match focused_option(options) {
+            Some(focused) => focused,
+            None => {
+                return Ok(Vec::new());
+            }
+        };
+        let mut choices = self.autocomplete(client, config, msg, &focused).await?;
+        choices.truncate(MAX_AUTOCOMPLETE_CHOICES);
+
+        Ok(choices)
+    }
+
+    /// Turns the options of a slash command into arguments, as if they were typed after a prefix.
+    fn slash_args(&self, options: &[CommandDataOption]) -> Vec<String> {
+        for option in options {
+            if
+                let
+                | CommandOptionValue::SubCommand(sub_options)
+                | CommandOptionValue::SubCommandGroup(sub_options) = &option.value
+            {
+                for subcommand in self.subcommands() {
+                    if slash_name(subcommand.name()) == option.name {
+                        let mut args = vec![subcommand.name().to_string()];
+                        args.extend(subcommand.slash_args(sub_options));
+                        return args;
+                    }
+                }
+                return vec![];
+            }
+        }
+
+        let mut args: Vec<String> = Vec::new();
+        for arg_spec in self.args() {
+            let value = options
+                .iter()
+                .find(|option| option.name == arg_spec.slash_name())
+                .map(|option| &option.value);
+
+            match value {
+                // arguments taking several values are split like prefix command arguments,
+                // text is kept as typed
+                Some(CommandOptionValue::String(value)) => {
+                    match arg_spec.arg_type {
+                        ArgType::Args | ArgType::Users | ArgType::Channels => {
+                            args.extend(value.split_whitespace().map(String::from));
+                        }
+                        _ => args.push(value.to_string()),
+                    }
+                }
+                Some(CommandOptionValue::Integer(value)) => args.push(value.to_string()),
+                Some(CommandOptionValue::Number(value)) => args.push(value.to_string()),
+                Some(CommandOptionValue::Boolean(value)) => args.push(value.to_string()),
+                Some(CommandOptionValue::User(id)) => args.push(format!("<@{}>", id)),
+                Some(CommandOptionValue::Channel(id)) => args.push(format!("<#{}>", id)),
+                Some(CommandOptionValue::Role(id)) => args.push(format!("<@&{}>", id)),
+                _ => {}
+            }
+        }
+
+        for flag_spec in self.flags() {
+            let value = options
+                .iter()
+                .find(|option| option.name == flag_spec.slash_name())
+                .map(|option| &option.value);
+
+            match value {
+                Some(CommandOptionValue::Boolean(true)) => {
+                    args.push(format!("--{}", flag_spec.name));
+                }
+                Some(CommandOptionValue::String(value)) => {
+                    args.push(format!("--{}={}", flag_spec.name, value));
+                }
+                Some(CommandOptionValue::Integer(value)) => {
+                    args.push(format!("--{}={}", flag_spec.name, value));
+                }
+                Some(CommandOptionValue::User(id)) => {
+                    args.push(format!("--{}=<@{}>", flag_spec.name, id));
+                }
+                Some(CommandOptionValue::Channel(id)) => {
+                    args.push(format!("--{}=<#{}>", flag_spec.name, id));
+                }
+                Some(CommandOptionValue::Role(id)) => {
+                    args.push(format!("--{}=<@&{}>", flag_spec.name, id));
+                }
+                _ => {}
+            }
+        }
+
+        args
+    }
+
     fn get_help(
         &self,
         locale: &str,
@@ -337,10 +821,29 @@ pub trait ContextCommand: Send + Sync {
         self.args()
             .into_iter()
             .map(|arg| arg.to_string())
+            .chain(
+                self
+                    .flags()
+                    .into_iter()
+                    .map(|flag| flag.to_flag_string())
+            )
             .collect::<Vec<String>>()
             .join(" ")
     }
 
+    /// Full name of the command or subcommand the arguments run, like `autores add`.
+    fn command_path(&self, cmd_args: &[&str]) -> String {
+        if let Some(&name) = cmd_args.first() {
+            for subcommand in self.subcommands() {
+                if subcommand.name() == name || subcommand.aliases().contains(&name) {
+                    return subcommand.command_path(&cmd_args[1..]);
+                }
+            }
+        }
+
+        self.get_root_command()
+    }
+
     fn get_root_command(&self) -> String {
         if let Some(parent_command) = self.parent_command() {
             return format!("{} {}", parent_command.get_root_command(), self.name());
@@ -384,3 +887,76 @@ pub trait ContextCommand: Send + Sync {
         usages
     }
 }
+
+/// The ID of the user, member or role named by a flag value, resolved like positional arguments.
+async fn resolve_flag_id(
+    client: &DiscordClient,
+    guild_id: Option<Id<GuildMarker>>,
+    arg_type: &ArgType,
+    value: &str
+) -> Option<String> {
+    let args = [value];
+    if let Some(guild_id) = guild_id {
+        match arg_type {
+            ArgType::Role => {
+                return greedy_role(client, guild_id, &args).await.0.map(|role| role.id.to_string());
+            }
+            _ => {
+                let (member, _) = greedy_member(client, guild_id, &args).await;
+                if let Some(member) = member {
+                    return Some(member.user.id.to_string());
+                }
+            }
+        }
+    }
+
+    // users who aren't members can still be given by mention or ID
+    match arg_type {
+        ArgType::User => greedy_user(&client.http, &args).await.0.map(|user| user.id.to_string()),
+        _ => None,
+    }
+}
+
+/// The arguments from the first to the last of `remaining` as they were typed in `content`.
+///
+/// `cmd_args` are the last arguments of `content`, `remaining` are taken from them.
+fn typed_text(content: &str, cmd_args: &[&str], remaining: &[&str]) -> Option<String> {
+    let spans = arg_spans(content);
+    let offset = spans.len().checked_sub(cmd_args.len())?;
+    // the arguments are found by address, the same text can be typed twice
+    let position = |arg: &str| cmd_args.iter().position(|&cmd_arg| std::ptr::eq(cmd_arg, arg));
+
+    let (start, _) = spans[offset + position(remaining.first()?)?];
+    let (_, end) = spans[offset + position(remaining.last()?)?];
+
+    Some(content[start..end].to_string())
+}
+
+/// Tells the user why they can't use a command.
+async fn reply_denied(
+    client: &DiscordClient,
+    msg: &CommandInvocation,
+    content: String
+) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+    msg.reply(
+        client,
+        MessageContent::DiscordEmbeds(
+            vec![DiscordEmbed {
+                description: Some(content),
+                color: Some(ColorResolvables::Red.as_u32()),
+                ..Default::default()
+            }]
+        )
+    ).await?;
+
+    Ok(())
+}
+
+/// Slash command descriptions are required, and limited to 100 characters.
+fn slash_description(description: Option<String>, name: &str) -> String {
+    let description = description
+        .filter(|description| !description.trim().is_empty())
+        .unwrap_or_else(|| name.to_string());
+
+    description.chars().take(100).collect()
+}
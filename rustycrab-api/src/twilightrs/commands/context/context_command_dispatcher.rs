@@ -1,28 +1,62 @@
-use std::collections::HashMap;
+use std::{ collections::HashMap, error::Error, sync::{ Arc, OnceLock }, time::Instant };
 
-use twilight_model::gateway::payload::incoming::MessageCreate;
+use fluent_bundle::FluentArgs;
+use twilight_model::{
+    application::{ command::Command, interaction::application_command::CommandData },
+    gateway::payload::incoming::InteractionCreate,
+};
 
 use crate::{
     twilightrs::{
-        commands::context::{
-            ContextCommandCategory,
-            general::GeneralCommands,
-            ContextCommandHandler,
-            admin::AdminCommands,
-            voice::VoiceCommands,
+        commands::{
+            context::{
+                ContextCommandCategory,
+                general::GeneralCommands,
+                ContextCommandHandler,
+                admin::AdminCommands,
+                voice::VoiceCommands,
+                slash_name,
+            },
+            invocation::CommandInvocation,
         },
-        discord_client::DiscordClient,
+        discord_client::{ DiscordClient, MessageContent },
+        utils::{ module_disabled_message, record_command_usage },
+    },
+    database::{
+        bot_guild_configurations::Model as GuildConfigModel,
+        command_rules::Model as CommandRuleModel,
     },
-    database::bot_guild_configurations::Model as GuildConfigModel,
+    metrics::metrics,
+    multi_bot_guild_entities_queries::MultipleBotGuildEntityQueries,
+    queries::{
+        command_rules_queries::CommandRulesQueries,
+        command_usages_queries::CommandUsageRecord,
+    },
+    utilities::edit_distance,
 };
 
+/// Most suggestions given for an unknown command.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// A name an unknown command can be matched against.
+struct SuggestionCandidate {
+    /// Command name, alias or subcommand name
+    name: String,
+    /// What is suggested to the user, like `autores add` for the `add` subcommand
+    suggestion: String,
+    /// Name of the command's handler
+    handler_name: String,
+}
+
 pub struct ContextCommandDispatcher {
     pub commands_aliases: HashMap<String, String>,
     pub handlers: HashMap<String, ContextCommandHandler>,
+    suggestion_candidates: Vec<SuggestionCandidate>,
 }
 
 impl ContextCommandDispatcher {
-    pub fn categories(&self) -> Vec<Box<dyn ContextCommandCategory>> {
+    /// Categories of the context commands, in the order the help lists them.
+    pub fn categories() -> Vec<Box<dyn ContextCommandCategory>> {
         Vec::from([
             Box::new(GeneralCommands {}) as Box<dyn ContextCommandCategory>,
             Box::new(AdminCommands {}) as Box<dyn ContextCommandCategory>,
@@ -30,15 +64,17 @@ impl ContextCommandDispatcher {
         ])
     }
 
+    /// A dispatcher shared by everything that only looks the commands up.
+    pub fn shared() -> &'static ContextCommandDispatcher {
+        static DISPATCHER: OnceLock<ContextCommandDispatcher> = OnceLock::new();
+        DISPATCHER.get_or_init(ContextCommandDispatcher::new)
+    }
+
     pub fn new() -> Self {
         println!("creating new command dispatcher");
         let mut handlers: HashMap<String, ContextCommandHandler> = HashMap::new();
 
-        let categories: Vec<Box<dyn ContextCommandCategory>> = Vec::from([
-            Box::new(GeneralCommands {}) as Box<dyn ContextCommandCategory>,
-            Box::new(AdminCommands {}) as Box<dyn ContextCommandCategory>,
-            Box::new(VoiceCommands {}) as Box<dyn ContextCommandCategory>,
-        ]);
+        let categories = Self::categories();
 
         let mut commands_aliases: HashMap<String, String> = HashMap::new();
 
@@ -71,14 +107,108 @@ impl ContextCommandDispatcher {
             }
         }
 
-        ContextCommandDispatcher { commands_aliases, handlers }
+        let mut suggestion_candidates: Vec<SuggestionCandidate> = Vec::new();
+        for (name, handler_name) in &commands_aliases {
+            suggestion_candidates.push(SuggestionCandidate {
+                name: name.clone(),
+                suggestion: handler_name.clone(),
+                handler_name: handler_name.clone(),
+            });
+        }
+        for (handler_name, handler) in &handlers {
+            for subcommand in handler.command.subcommands() {
+                for name in std::iter::once(subcommand.name()).chain(subcommand.aliases()) {
+                    suggestion_candidates.push(SuggestionCandidate {
+                        name: name.to_string(),
+                        suggestion: format!("{} {}", handler_name, subcommand.name()),
+                        handler_name: handler_name.clone(),
+                    });
+                }
+            }
+        }
+
+        ContextCommandDispatcher { commands_aliases, handlers, suggestion_candidates }
+    }
+
+    /// Commands whose name, alias or subcommand name is close to the unknown command name,
+    /// closest first. Commands of disabled modules are never suggested.
+    pub fn suggest_commands(&self, config: &GuildConfigModel, command_name: &str) -> Vec<String> {
+        let command_name = command_name.to_lowercase();
+        // allow a typo for every 3 characters, so short names don't match everything
+        let max_distance = (command_name.chars().count() / 3).max(1);
+
+        let mut matches: Vec<(usize, &str)> = self.suggestion_candidates
+            .iter()
+            .filter(|candidate| {
+                self.handlers
+                    .get(&candidate.handler_name)
+                    .and_then(|handler| handler.command.module())
+                    .map_or(true, |module| module.is_enabled(config.module_flags))
+            })
+            .filter_map(|candidate| {
+                let distance = edit_distance(&command_name, &candidate.name);
+                (distance <= max_distance).then_some((distance, candidate.suggestion.as_str()))
+            })
+            .collect();
+        matches.sort();
+
+        let mut suggestions: Vec<String> = Vec::new();
+        for (_, suggestion) in matches {
+            if !suggestions.iter().any(|existing| existing == suggestion) {
+                suggestions.push(suggestion.to_string());
+            }
+        }
+        suggestions.truncate(MAX_SUGGESTIONS);
+
+        suggestions
+    }
+
+    /// Whether the command runs again when its message is edited.
+    ///
+    /// Unknown command names do, so a typo fixed by editing the message runs the command.
+    pub fn reruns_on_edit(&self, command_name: &str) -> bool {
+        self.commands_aliases
+            .get(command_name)
+            .and_then(|name| self.handlers.get(name))
+            .map_or(true, |handler| handler.command.rerun_on_edit())
+    }
+
+    /// The full name of a command or subcommand given by its name or aliases, like
+    /// `autores add` for `ar add`, `None` if there is no such command.
+    pub fn canonical_command_name(&self, name: &str) -> Option<String> {
+        let words: Vec<&str> = name.split_whitespace().collect();
+        let handler = self.commands_aliases
+            .get(*words.first()?)
+            .and_then(|name| self.handlers.get(name))?;
+
+        // the path stops at the first word that isn't a subcommand
+        let path = handler.command.command_path(&words[1..]);
+        (path.split(' ').count() == words.len()).then_some(path)
+    }
+
+    /// The command rules of the guild the command was invoked in.
+    async fn guild_rules(
+        client: &DiscordClient,
+        message: &CommandInvocation
+    ) -> Result<Vec<CommandRuleModel>, Box<dyn Error + Send + Sync + 'static>> {
+        match message.guild_id {
+            Some(guild_id) =>
+                Ok(
+                    CommandRulesQueries::find_by_discord_ids(
+                        &client.db,
+                        &client.bot_id.to_string(),
+                        &guild_id.to_string()
+                    ).await?
+                ),
+            None => Ok(Vec::new()),
+        }
     }
 
     pub async fn dispatch_command(
         &self,
         client: DiscordClient,
         config: &GuildConfigModel,
-        message: &MessageCreate,
+        message: &CommandInvocation,
         command_name: &str,
         args: &[&str]
     ) {
@@ -86,10 +216,167 @@ impl ContextCommandDispatcher {
         if let Some(name) = self.commands_aliases.get(command_name) {
             // println!("dispatching commands");
             if let Some(handler) = self.handlers.get(name) {
-                let _ = handler.command.exec(client, config, message, args).await;
+                if let Some(module) = handler.command.module() {
+                    if !module.is_enabled(config.module_flags) {
+                        let content = module_disabled_message(&client, config, module);
+                        let _ = message.reply(&client, MessageContent::Text(content)).await;
+                        return;
+                    }
+                }
+
+                let kind = if message.message_id().is_some() { "context" } else { "slash" };
+                let bot_label = client.bot_id.to_string();
+                let started_at = Instant::now();
+                // rules are loaded once, every subcommand level checks them
+                let result = match Self::guild_rules(&client, message).await {
+                    Ok(rules) =>
+                        handler.command.exec(
+                            Arc::clone(&client),
+                            config,
+                            message,
+                            args,
+                            handler.category_name,
+                            &rules
+                        ).await,
+                    Err(err) => Err(err),
+                };
+                let duration = started_at.elapsed();
+                metrics().record_command(
+                    &bot_label,
+                    kind,
+                    handler.command_name,
+                    duration,
+                    result.is_err()
+                );
+                record_command_usage(&client, config, CommandUsageRecord {
+                    command: handler.command.command_path(args),
+                    kind,
+                    channel_id: message.channel_id.to_string(),
+                    user_id: message.author.id.to_string(),
+                    duration,
+                    success: result.is_ok(),
+                });
             } else {
                 // handler not mapped
             }
+        } else {
+            if let Some(message_id) = message.message_id() {
+                client.command_replies.record_not_executed(message_id);
+            }
+            if config.command_suggestions == 0 {
+                return;
+            }
+
+            let suggestions = self.suggest_commands(config, command_name);
+            if suggestions.is_empty() {
+                return;
+            }
+
+            let mut args = FluentArgs::new();
+            args.set("command", command_name.to_string());
+            args.set(
+                "suggestions",
+                suggestions
+                    .iter()
+                    .map(|suggestion| format!("`{}{}`", config.prefix, suggestion))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            );
+            let content = client.get_locale_string(
+                &config.locale,
+                "command-suggestion",
+                Some(&args)
+            );
+            let _ = message.reply(&client, MessageContent::Text(content)).await;
         }
     }
+
+    /// The slash commands registered for every context command.
+    ///
+    /// With `module_flags`, the commands of the modules disabled by the flags are left out.
+    pub fn slash_commands(&self, module_flags: Option<i32>) -> Vec<Command> {
+        self.handlers
+            .values()
+            .filter(|handler| {
+                match (handler.command.module(), module_flags) {
+                    (Some(module), Some(flags)) => module.is_enabled(flags),
+                    _ => true,
+                }
+            })
+            .map(|handler| handler.command.to_slash_command())
+            .collect()
+    }
+
+    /// Runs the context command matching a slash command, returns `false` if there is none.
+    pub async fn dispatch_interaction(
+        &self,
+        client: DiscordClient,
+        config: &GuildConfigModel,
+        interaction: &Box<InteractionCreate>,
+        command_data: &Box<CommandData>
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync + 'static>> {
+        let handler = match
+            self.handlers.values().find(|handler| slash_name(handler.command_name) == command_data.name)
+        {
+            Some(handler) => handler,
+            None => {
+                return Ok(false);
+            }
+        };
+        let invocation = CommandInvocation::from_interaction(interaction).ok_or(
+            "interaction has no channel or author"
+        )?;
+
+        // commands may take longer than the 3 seconds Discord waits for an answer
+        client.defer_interaction(interaction).await?;
+
+        let args = handler.command.slash_args(&command_data.options);
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        self.dispatch_command(client, config, &invocation, handler.command_name, &args).await;
+
+        Ok(true)
+    }
+
+    /// Suggests values for the focused option of the context command matching a slash command,
+    /// returns `false` if there is none.
+    pub async fn dispatch_autocomplete(
+        &self,
+        client: DiscordClient,
+        config: &GuildConfigModel,
+        interaction: &Box<InteractionCreate>,
+        command_data: &Box<CommandData>
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync + 'static>> {
+        let handler = match
+            self.handlers.values().find(|handler| slash_name(handler.command_name) == command_data.name)
+        {
+            Some(handler) => handler,
+            None => {
+                return Ok(false);
+            }
+        };
+        let invocation = CommandInvocation::from_interaction(interaction).ok_or(
+            "interaction has no channel or author"
+        )?;
+
+        let module_enabled = handler.command
+            .module()
+            .map_or(true, |module| module.is_enabled(config.module_flags));
+        let choices = if module_enabled {
+            let result = handler.command.complete(
+                Arc::clone(&client),
+                config,
+                &invocation,
+                &command_data.options
+            ).await;
+            result.unwrap_or_else(|e| {
+                eprintln!("Error autocompleting {}: {e}", command_data.name);
+                Vec::new()
+            })
+        } else {
+            Vec::new()
+        };
+        client.autocomplete_interaction_reply(interaction, choices).await?;
+
+        Ok(true)
+    }
 }
@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use fluent_bundle::FluentArgs;
-use rustycrab_model::afk::UserAfkStatus;
-use twilight_model::gateway::payload::incoming::MessageCreate;
+use rustycrab_model::modules::GuildModule;
+use crate::twilightrs::commands::invocation::CommandInvocation;
 use std::error::Error;
 
 use crate::{
@@ -13,9 +13,9 @@ use crate::{
             ArgType,
             context_command::GuildConfigModel,
         },
-        discord_client::DiscordClient,
+        discord_client::{ DiscordClient, MessageContent },
+        utils::afk::set_afk,
     },
-    utilities::utils::current_unix_timestamp,
 };
 
 pub struct AfkCommand;
@@ -26,6 +26,10 @@ impl ContextCommand for AfkCommand {
         "afk"
     }
 
+    fn module(&self) -> Option<GuildModule> {
+        Some(GuildModule::Afk)
+    }
+
     fn args(&self) -> Vec<ArgSpec> {
         vec![ArgSpec::new("message from you", ArgType::Text, true)] // User argument is optional
     }
@@ -33,7 +37,7 @@ impl ContextCommand for AfkCommand {
         &self,
         client: DiscordClient,
         config: &GuildConfigModel,
-        msg: &MessageCreate,
+        msg: &CommandInvocation,
         command_args: Vec<ParsedArg>
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         let guild_id = msg.guild_id.unwrap(); // Assuming command is used in a guild
@@ -48,38 +52,11 @@ impl ContextCommand for AfkCommand {
             if let ParsedArg::Text(message) = arg { Some(message.clone()) } else { None }
         });
 
-        // Get the current timestamp
-        let since = current_unix_timestamp()?; // Implement this function to get the current time
-
-        let guild_member = client.http.guild_member(guild_id, user_id).await?.model().await?;
-        // Update or set the AFK status
-        {
-            let mut afk_users = client.afk_users.write().unwrap();
-            let user_afk_status = afk_users
-                .entry(guild_id)
-                .or_default()
-                .entry(user_id)
-                .or_insert(UserAfkStatus::new(None, since));
-
-            user_afk_status.message = afk_message;
-            user_afk_status.since = since;
-        } // The lock is dropped here
-
-        let current_name = if let Some(nickname) = guild_member.nick {
-            nickname
-        } else {
-            guild_member.user.name
-        };
-
-        if !current_name.trim().starts_with("[AFK]") {
-            let _ = client.http
-                .update_guild_member(guild_id, user_id)
-                .nick(Some(&format!("[AFK] {}", current_name)))?.await;
-        }
+        set_afk(&client, guild_id, user_id, afk_message).await?;
 
         // Send confirmation message
         let message = client.get_locale_string(&config.locale, "command-afk-success", Some(&args));
-        client.http.create_message(msg.channel_id).content(&message)?.await?;
+        msg.send(&client, MessageContent::Text(message)).await?;
 
         Ok(())
     }
@@ -1,6 +1,6 @@
 use async_trait::async_trait;
 use fluent_bundle::FluentArgs;
-use twilight_model::gateway::payload::incoming::MessageCreate;
+use crate::twilightrs::commands::invocation::CommandInvocation;
 use std::error::Error;
 
 use crate::{
@@ -33,11 +33,15 @@ impl ContextCommand for AvatarCommand {
         vec![ArgSpec::new("user", ArgType::User, true)] // User argument is optional
     }
 
+    fn rerun_on_edit(&self) -> bool {
+        true
+    }
+
     async fn run(
         &self,
         client: DiscordClient,
         config: &GuildConfigModel,
-        msg: &MessageCreate,
+        msg: &CommandInvocation,
         command_args: Vec<ParsedArg>
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         let bot = client.get_bot().await?;
@@ -49,9 +53,8 @@ impl ContextCommand for AvatarCommand {
         };
 
         if let Some(avatar_hash) = user.avatar {
-            let _ = client.reply_message(
-                msg.channel_id,
-                msg.id,
+            let _ = msg.reply(
+                &client,
                 MessageContent::DiscordEmbeds(
                     vec![DiscordEmbed {
                         title: Some(format!("{}'s avatar", &user.name)),
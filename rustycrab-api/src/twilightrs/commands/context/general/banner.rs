@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use twilight_model::gateway::payload::incoming::MessageCreate;
+use crate::twilightrs::commands::invocation::CommandInvocation;
 use std::error::Error;
 
 use crate::{
@@ -28,11 +28,15 @@ impl ContextCommand for BannerCommand {
         vec![ArgSpec::new("user", ArgType::User, true)] // User argument is optional
     }
 
+    fn rerun_on_edit(&self) -> bool {
+        true
+    }
+
     async fn run(
         &self,
         client: DiscordClient,
         _: &GuildConfigModel,
-        msg: &MessageCreate,
+        msg: &CommandInvocation,
         command_args: Vec<ParsedArg>
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         let bot = client.get_bot().await?;
@@ -46,9 +50,8 @@ impl ContextCommand for BannerCommand {
         match client.get_user_banner_url(user.id).await {
             Ok(Some(banner_url)) => {
                 println!("{}", banner_url);
-                let _ = client.reply_message(
-                    msg.channel_id,
-                    msg.id,
+                let _ = msg.reply(
+                    &client,
                     MessageContent::DiscordEmbeds(
                         vec![DiscordEmbed {
                             title: Some(format!("{}'s banner", &user.name)),
@@ -78,15 +81,13 @@ impl ContextCommand for BannerCommand {
                 ).await;
             }
             Ok(None) => {
-                client.send_message(
-                    msg.channel_id,
-                    MessageContent::Text(format!("No banner found for user {}", msg.author.name))
+                msg.send(
+                    &client, MessageContent::Text(format!("No banner found for user {}", msg.author.name))
                 ).await?;
             }
             Err(_) => {
-                client.send_message(
-                    msg.channel_id,
-                    MessageContent::Text("Error fetching banner".to_string())
+                msg.send(
+                    &client, MessageContent::Text("Error fetching banner".to_string())
                 ).await?;
             }
         }
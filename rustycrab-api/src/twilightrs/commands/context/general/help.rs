@@ -1,7 +1,15 @@
 use async_trait::async_trait;
-use rustycrab_model::color::ColorResolvables;
-use twilight_model::gateway::payload::incoming::MessageCreate;
-use std::{ error::Error, collections::HashMap };
+use fluent_bundle::FluentArgs;
+use rustycrab_model::{ color::ColorResolvables, response::command_catalog::ResponseCommand };
+use twilight_model::{
+    channel::message::{
+        Component,
+        component::{ ActionRow, Button, SelectMenu, SelectMenuType },
+    },
+    id::{ Id, marker::UserMarker },
+};
+use crate::twilightrs::commands::invocation::CommandInvocation;
+use std::error::Error;
 
 use crate::{
     twilightrs::{
@@ -13,15 +21,214 @@ use crate::{
             ArgType,
             ContextCommandHandler,
             context_command::GuildConfigModel,
+            catalog::{ command_catalog, permission_names },
         },
         discord_client::{ DiscordClient, MessageContent },
         messages::{ DiscordEmbed, DiscordEmbedField },
+        events::interaction_handlers::{
+            buttons::ButtonEvents,
+            select_menus::SelectMenuEvents,
+        },
+        utils::{ make_components, select_menus::{ select_menu, select_option, select_menu_row } },
     },
+    utilities::utils::color_to_button_style,
     cdn_guild_icon,
     cdn_avatar,
     queries::bot_queries::BotQueries,
 };
 
+/// Commands listed on each page of the help.
+const COMMANDS_PER_PAGE: usize = 10;
+
+/// Longest search kept in the custom ids of the page buttons, which are limited to 100 characters.
+const MAX_SEARCH_LENGTH: usize = 50;
+
+/// Capitalizes the first letter of a category name.
+fn capitalize(category: &str) -> String {
+    category
+        .chars()
+        .next()
+        .map(|c| c.to_uppercase().collect::<String>())
+        .unwrap_or_default() + &category[1..]
+}
+
+/// What the pages of the help list.
+pub enum HelpView {
+    /// The commands of a category, by lowercase category name
+    Category(String),
+    /// The commands and subcommands whose name, aliases or description contain the search
+    Search(String),
+}
+
+impl HelpView {
+    /// The view as custom id args, read back by `from_args`.
+    fn to_args(&self) -> [&str; 2] {
+        match self {
+            HelpView::Category(category) => ["c", category.as_str()],
+            HelpView::Search(search) => ["s", search.as_str()],
+        }
+    }
+
+    pub fn from_args(args: &[&str]) -> Option<Self> {
+        let (kind, value) = args.split_first()?;
+        // searches can contain the `:` separating the args
+        let value = value.join(":");
+        match *kind {
+            "c" => Some(HelpView::Category(value)),
+            "s" => Some(HelpView::Search(value)),
+            _ => None,
+        }
+    }
+
+    /// The commands listed, in the order of the categories.
+    fn commands(&self, locale: &str) -> Vec<ResponseCommand> {
+        let catalog = command_catalog(locale);
+        match self {
+            HelpView::Category(name) =>
+                catalog
+                    .into_iter()
+                    .find(|category| category.name.to_lowercase() == *name)
+                    .map(|category| category.commands)
+                    .unwrap_or_default(),
+            HelpView::Search(search) => {
+                let search = search.to_lowercase();
+                catalog
+                    .into_iter()
+                    .flat_map(|category| category.commands)
+                    .flat_map(with_subcommands)
+                    .filter(|command| matches_search(command, &search))
+                    .collect()
+            }
+        }
+    }
+}
+
+/// The command followed by its subcommands, and theirs.
+fn with_subcommands(mut command: ResponseCommand) -> Vec<ResponseCommand> {
+    let subcommands = std::mem::take(&mut command.subcommands);
+    let mut commands = vec![command];
+    commands.extend(subcommands.into_iter().flat_map(with_subcommands));
+    commands
+}
+
+/// Whether the name, an alias or the description of the command contains the lowercase search.
+fn matches_search(command: &ResponseCommand, search: &str) -> bool {
+    command.full_name.to_lowercase().contains(search) ||
+        command.aliases.iter().any(|alias| alias.to_lowercase().contains(search)) ||
+        command.description
+            .as_ref()
+            .map_or(false, |description| description.to_lowercase().contains(search))
+}
+
+/// Menu picking a category to list the commands of, only the user who asked for help can use it.
+pub fn help_category_menu(user_id: Id<UserMarker>, selected: Option<&str>) -> Component {
+    let options = ContextCommandDispatcher::categories()
+        .iter()
+        .map(|category| {
+            let value = category.name().to_lowercase();
+            let mut option = select_option(category.name(), &value);
+            option.default = selected == Some(value.as_str());
+            option
+        })
+        .collect();
+
+    select_menu_row(SelectMenu {
+        options: Some(options),
+        ..select_menu(
+            SelectMenuEvents::HelpCategory.custom_id(&[&user_id.to_string()]),
+            SelectMenuType::Text,
+            "Pick a category"
+        )
+    })
+}
+
+/// A page of the help with the category menu and, when there are several pages, the buttons
+/// turning them. Only the user who asked for help can use them.
+pub async fn help_page(
+    client: &DiscordClient,
+    config: &GuildConfigModel,
+    user_id: Id<UserMarker>,
+    view: &HelpView,
+    page: usize
+) -> (DiscordEmbed, Vec<Component>) {
+    let bot_info = BotQueries::find_by_discord_id(&client.db, &client.bot_id.to_string()).await;
+
+    let commands = view.commands(&config.locale);
+    let pages = ((commands.len() + COMMANDS_PER_PAGE - 1) / COMMANDS_PER_PAGE).max(1);
+    let page = page.min(pages - 1);
+
+    let description = if commands.is_empty() {
+        client.get_locale_string(&config.locale, "help-empty", None)
+    } else {
+        commands
+            .iter()
+            .skip(page * COMMANDS_PER_PAGE)
+            .take(COMMANDS_PER_PAGE)
+            .map(|command| {
+                match &command.description {
+                    Some(description) => {
+                        format!("`{}{}` {}", config.prefix, command.usage, description)
+                    }
+                    None => format!("`{}{}`", config.prefix, command.usage),
+                }
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    };
+
+    let mut page_args = FluentArgs::new();
+    page_args.set("page", page + 1);
+    page_args.set("pages", pages);
+
+    let embed = DiscordEmbed {
+        title: Some(match view {
+            HelpView::Category(category) => format!("{} Commands", capitalize(category)),
+            HelpView::Search(search) => {
+                let mut args = FluentArgs::new();
+                args.set("search", search.clone());
+                client.get_locale_string(&config.locale, "help-search-title", Some(&args))
+            }
+        }),
+        description: Some(description),
+        footer_text: Some(client.get_locale_string(&config.locale, "help-page", Some(&page_args))),
+        timestamp: Some(true),
+        color: if let Ok(info) = bot_info {
+            Some(ColorResolvables::HexString(format!("{}", &info.theme_hex_color)).as_u32())
+        } else {
+            None
+        },
+        ..Default::default()
+    };
+
+    let selected = match view {
+        HelpView::Category(category) => Some(category.as_str()),
+        HelpView::Search(_) => None,
+    };
+    let mut components = vec![help_category_menu(user_id, selected)];
+    if pages > 1 {
+        let user_id = user_id.to_string();
+        let [kind, value] = view.to_args();
+        let page_button = |target: usize, key: &str, disabled: bool| Button {
+            custom_id: Some(
+                ButtonEvents::HelpPage.custom_id(&[&user_id, &target.to_string(), kind, value])
+            ),
+            disabled,
+            emoji: None,
+            label: Some(client.get_locale_string(&config.locale, key, None)),
+            style: color_to_button_style("blue"),
+            url: None,
+        };
+
+        let buttons = vec![
+            page_button(page.saturating_sub(1), "help-prev", page == 0),
+            page_button(page + 1, "help-next", page + 1 == pages)
+        ];
+        components.push(Component::ActionRow(ActionRow { components: make_components(&buttons) }));
+    }
+
+    (embed, components)
+}
+
 pub struct HelpCommand;
 
 impl HelpCommand {
@@ -29,8 +236,7 @@ impl HelpCommand {
         &self,
         client: DiscordClient,
         config: &GuildConfigModel,
-        msg: &MessageCreate,
-        dispatcher: ContextCommandDispatcher
+        msg: &CommandInvocation
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         let guild = if let Some(guild_id) = msg.guild_id {
             Some(client.get_guild(guild_id).await?)
@@ -42,61 +248,32 @@ impl HelpCommand {
         let bot_info = BotQueries::find_by_discord_id(&client.db, &bot_id).await;
         // General help logic
         // Display a list of commands with brief descriptions
-        let mut categories: HashMap<String, Vec<String>> = HashMap::new();
-
-        // let user_permissions = client.cache
-        //     .permissions()
-        //     .in_channel(msg.author.id, msg.channel_id)?;
-
-        for (command_name, command_handler) in &dispatcher.handlers {
-            let category = command_handler.category_name.to_lowercase();
-            let command = command_name.to_lowercase();
-
-            // let required_permissions = command_handler.command.permissions();
-            // if !required_permissions.is_empty() {
-            //     let has_permission = required_permissions
-            //         .iter()
-            //         .any(|&req_perm| user_permissions.contains(req_perm));
-            //     if !has_permission {
-            //         // User does not have any of the required permissions
-            //         continue;
-            //     }
-            // }
-            categories
-                .entry(category)
-                .and_modify(|commands| commands.push(command.clone()))
-                .or_insert_with(|| vec![command]);
-        }
-        client.reply_message(
-            msg.channel_id,
-            msg.id,
+        let category_menu = help_category_menu(msg.author.id, None);
+
+        msg.reply_with_components(
+            &client,
             MessageContent::DiscordEmbeds(
                 vec![DiscordEmbed {
                     title: Some(format!("{}'s Commands", bot.name)),
                     description: Some(
                         format!(
-                            "More details on a command, use:\n`{}help [command name]`",
+                            "More details on a command, use:\n`{}help [command name]`\n\
+                            Search the commands, use:\n`{}help [keyword]`",
+                            config.prefix,
                             config.prefix
                         )
                     ),
                     fields: Some(
-                        categories
+                        ContextCommandDispatcher::categories()
                             .into_iter()
-                            // .filter(|x| )
-                            .map(|(category, command_names)| {
-                                let capitalized_category =
-                                    category
-                                        .chars()
-                                        .next()
-                                        .map(|c| c.to_uppercase().collect::<String>())
-                                        .unwrap_or_default() + &category[1..];
-
+                            .map(|category| {
                                 DiscordEmbedField {
-                                    name: capitalized_category,
-                                    value: command_names
+                                    name: category.name().to_string(),
+                                    value: category
+                                        .collect_commands()
                                         .into_iter()
-                                        .filter(|command_name| command_name != "help")
-                                        .map(|command_name| format!("`{}` ", command_name))
+                                        .filter(|command| command.name() != "help")
+                                        .map(|command| format!("`{}` ", command.name()))
                                         .collect(),
                                     inline: false,
                                 }
@@ -122,7 +299,8 @@ impl HelpCommand {
                     },
                     ..Default::default()
                 }]
-            )
+            ),
+            &[category_menu]
         ).await?;
 
         Ok(())
@@ -132,7 +310,7 @@ impl HelpCommand {
         &self,
         client: DiscordClient,
         config: &GuildConfigModel,
-        msg: &MessageCreate,
+        msg: &CommandInvocation,
         command_handler: &ContextCommandHandler,
         args: &[String]
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
@@ -148,9 +326,8 @@ impl HelpCommand {
         let (command_usage, command_aliases, subcommand_usage, command_description) =
             command_handler.command.get_help(&config.locale, config.prefix.to_string(), args);
 
-        let _ = client.reply_message(
-            msg.channel_id,
-            msg.id,
+        let _ = msg.reply(
+            &client,
             MessageContent::DiscordEmbeds(
                 vec![DiscordEmbed {
                     description: command_description,
@@ -159,14 +336,7 @@ impl HelpCommand {
                         let mut discord_fields: Vec<DiscordEmbedField> = Vec::new();
                         discord_fields.push(DiscordEmbedField {
                             name: "Category".to_string(),
-                            value: format!(
-                                "{}",
-                                command_handler.category_name
-                                    .chars()
-                                    .next()
-                                    .map(|c| c.to_uppercase().collect::<String>())
-                                    .unwrap_or_default() + &command_handler.category_name[1..]
-                            ),
+                            value: capitalize(command_handler.category_name),
                             inline: false,
                         });
                         // if command_handler.command.aliases
@@ -180,15 +350,12 @@ impl HelpCommand {
                         if command_handler.command.permissions().len() > 0 {
                             discord_fields.push(DiscordEmbedField {
                                 name: "Permission(s)".to_string(),
-                                value: format!(
-                                    "{}",
-                                    command_handler.command
-                                        .permissions()
-                                        .into_iter()
-                                        .map(|perm| format!("{:?}", perm).to_lowercase())
-                                        .collect::<Vec<String>>()
-                                        .join(", ")
-                                ),
+                                // any one of the alternatives is enough
+                                value: permission_names(&command_handler.command.permissions())
+                                    .iter()
+                                    .map(|names| names.join(" + "))
+                                    .collect::<Vec<String>>()
+                                    .join(" or "),
                                 inline: false,
                             });
                         }
@@ -253,11 +420,15 @@ impl ContextCommand for HelpCommand {
         vec![ArgSpec::new("command", ArgType::Args, true)] // User argument is optional
     }
 
+    fn rerun_on_edit(&self) -> bool {
+        true
+    }
+
     async fn run(
         &self,
         client: DiscordClient,
         config: &GuildConfigModel,
-        msg: &MessageCreate,
+        msg: &CommandInvocation,
         command_args: Vec<ParsedArg>
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         let dispatcher = ContextCommandDispatcher::new();
@@ -277,11 +448,22 @@ impl ContextCommand for HelpCommand {
                         return Ok(());
                     }
                 }
+
+                // not a command, its descriptions are searched instead
+                let search: String = args.join(" ").chars().take(MAX_SEARCH_LENGTH).collect();
+                let view = HelpView::Search(search);
+                let (embed, components) = help_page(&client, config, msg.author.id, &view, 0).await;
+                msg.reply_with_components(
+                    &client,
+                    MessageContent::DiscordEmbeds(vec![embed]),
+                    &components
+                ).await?;
+                return Ok(());
             }
             _ => {}
         }
 
-        self.display_general_help(client, config, msg, dispatcher).await?;
+        self.display_general_help(client, config, msg).await?;
         Ok(())
     }
 }
@@ -1,5 +1,6 @@
 use async_trait::async_trait;
-use twilight_model::gateway::payload::incoming::MessageCreate;
+use rustycrab_model::cooldown::{ CommandCooldown, CooldownScope };
+use crate::twilightrs::commands::invocation::CommandInvocation;
 use std::error::Error;
 
 use crate::twilightrs::{
@@ -29,19 +30,26 @@ impl ContextCommand for MathCommand {
         vec![ArgSpec::new("math expression", ArgType::Text, false)]
     }
 
+    fn cooldown(&self) -> Option<CommandCooldown> {
+        Some(CommandCooldown::new(CooldownScope::User, 5, 10))
+    }
+
+    fn rerun_on_edit(&self) -> bool {
+        true
+    }
+
     async fn run(
         &self,
         client: DiscordClient,
         config: &GuildConfigModel,
-        msg: &MessageCreate,
+        msg: &CommandInvocation,
         command_args: Vec<ParsedArg>
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         if let Some(ParsedArg::Text(expression)) = command_args.first() {
             match meval::eval_str(expression) {
                 Ok(result) => {
-                    let _ = client.reply_message(
-                        msg.channel_id,
-                        msg.id,
+                    let _ = msg.reply(
+                        &client,
                         MessageContent::Text(result.to_string())
                     ).await;
                 }
@@ -51,9 +59,8 @@ impl ContextCommand for MathCommand {
                         "command-math-invalid",
                         None
                     );
-                    let _ = client.reply_message(
-                        msg.channel_id,
-                        msg.id,
+                    let _ = msg.reply(
+                        &client,
                         MessageContent::DiscordEmbeds(
                             vec![DiscordEmbed {
                                 description: Some(message),
@@ -13,9 +13,9 @@ use super::{ ContextCommandCategory, ContextCommand };
 mod ping;
 mod avatar;
 mod math;
-mod help;
+pub mod help;
 mod banner;
-mod snipe;
+pub mod snipe;
 mod afk;
 
 pub struct GeneralCommands;
@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use twilight_model::gateway::payload::incoming::MessageCreate;
+use crate::twilightrs::commands::invocation::CommandInvocation;
 use std::{ error::Error, time::Instant };
 
 use crate::twilightrs::{
@@ -14,24 +14,26 @@ impl ContextCommand for PingCommand {
         "ping"
     }
 
+    fn rerun_on_edit(&self) -> bool {
+        true
+    }
+
     async fn run(
         &self,
         client: DiscordClient,
         _: &GuildConfigModel,
-        msg: &MessageCreate,
+        msg: &CommandInvocation,
         _: Vec<ParsedArg>
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         // Example: Use command_args if needed for the logic
         // If PingCommand doesn't need arguments, this part can remain unchanged
 
         let start_time = Instant::now();
-        let response = client
-            .reply_message(
-                msg.channel_id,
-                msg.id,
+        let response = msg
+            .reply(
+                &client,
                 MessageContent::Text("Ping...".to_string())
-            ).await?
-            .model().await?;
+            ).await?;
         let duration = start_time.elapsed();
         let response_time = duration.as_millis();
 
@@ -1,6 +1,8 @@
 use async_trait::async_trait;
-use twilight_model::gateway::payload::incoming::MessageCreate;
+use rustycrab_model::modules::GuildModule;
+use crate::twilightrs::commands::invocation::CommandInvocation;
 use std::error::Error;
+use twilight_model::{ id::{ Id, marker::ChannelMarker }, user::User };
 
 use crate::{
     twilightrs::{
@@ -16,6 +18,43 @@ use crate::{
     },
     cdn_avatar,
 };
+/// Embed of a deleted message of the channel, `position` 0 being the last deleted one.
+pub fn sniped_message_embed(
+    client: &DiscordClient,
+    channel_id: Id<ChannelMarker>,
+    position: usize,
+    sniper: &User
+) -> Option<DiscordEmbed> {
+    // Extracting the deleted messages within a smaller scope to ensure the lock is released before await
+    let message = {
+        let deleted_messages = client.deleted_messages.read().unwrap();
+        deleted_messages.get(&channel_id).and_then(|messages| {
+            if position < messages.len() {
+                Some(messages[messages.len() - 1 - position].clone())
+            } else {
+                None
+            }
+        })
+    }?;
+    let message_author = client.cache.user(message.author())?;
+    let message_user = message_author.value();
+
+    Some(DiscordEmbed {
+        description: if message.content().len() > 0 {
+            Some(message.content().to_string())
+        } else {
+            None
+        },
+        author_name: Some(message_user.name.to_string()),
+        author_icon_url: message_user.avatar.map(|avatar_hash|
+            cdn_avatar!(message_user.id, avatar_hash)
+        ),
+        footer_text: Some(format!("Sniped by @{}", sniper.name)),
+        timestamp: Some(true),
+        ..Default::default()
+    })
+}
+
 pub struct SnipeCommand;
 
 #[async_trait]
@@ -24,6 +63,10 @@ impl ContextCommand for SnipeCommand {
         "snipe"
     }
 
+    fn module(&self) -> Option<GuildModule> {
+        Some(GuildModule::Snipe)
+    }
+
     fn args(&self) -> Vec<ArgSpec> {
         vec![
             ArgSpec::new("user", ArgType::User, true),
@@ -31,11 +74,15 @@ impl ContextCommand for SnipeCommand {
         ]
     }
 
+    fn rerun_on_edit(&self) -> bool {
+        true
+    }
+
     async fn run(
         &self,
         client: DiscordClient,
         config: &GuildConfigModel,
-        msg: &MessageCreate,
+        msg: &CommandInvocation,
         command_args: Vec<ParsedArg>
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         let position = if let Some(ParsedArg::Number(pos)) = command_args.get(0) {
@@ -44,49 +91,15 @@ impl ContextCommand for SnipeCommand {
             0 // Default to the most recent deleted message
         };
 
-        // Extracting the deleted messages within a smaller scope to ensure the lock is released before await
-        let sniped_message = {
-            let deleted_messages = client.deleted_messages.read().unwrap();
-            deleted_messages.get(&msg.channel_id).and_then(|messages| {
-                if position < messages.len() {
-                    Some(messages[messages.len() - 1 - position].clone())
-                } else {
-                    None
-                }
-            })
-        };
-
-        if let Some(message) = sniped_message {
-            if let Some(message_author) = client.cache.user(message.author()) {
-                let message_user = message_author.value();
-                client.reply_message(
-                    msg.channel_id,
-                    msg.id,
-                    MessageContent::DiscordEmbeds(
-                        vec![DiscordEmbed {
-                            description: if message.content().len() > 0 {
-                                Some(message.content().to_string())
-                            } else {
-                                None
-                            },
-                            author_name: Some(message_user.name.to_string()),
-                            author_icon_url: message_user.avatar.map(|avatar_hash|
-                                cdn_avatar!(message_user.id, avatar_hash)
-                            ),
-                            footer_text: Some(format!("Sniped by @{}", msg.author.name)),
-                            timestamp: Some(true),
-                            ..Default::default()
-                        }]
-                    )
-                ).await?;
-            }
+        if let Some(embed) = sniped_message_embed(&client, msg.channel_id, position, &msg.author) {
+            msg.reply(&client, MessageContent::DiscordEmbeds(vec![embed])).await?;
         } else {
             let message = client.get_locale_string(
                 &config.locale,
                 "command-snipe-invalid-position",
                 None
             );
-            client.send_message(msg.channel_id, MessageContent::Text(message)).await?;
+            msg.send(&client, MessageContent::Text(message)).await?;
         }
 
         Ok(())
@@ -4,8 +4,21 @@ pub mod voice;
 
 pub mod context_command_dispatcher;
 pub mod context_command;
+pub mod catalog;
 
-use twilight_model::{ user::User, channel::Channel };
+use std::{ collections::HashMap, fmt, time::Duration };
+
+use twilight_model::{
+    user::User,
+    channel::Channel,
+    guild::{ Member, Role },
+    application::command::{
+        CommandOption,
+        CommandOptionChoice,
+        CommandOptionChoiceValue,
+        CommandOptionType,
+    },
+};
 
 use self::context_command::ContextCommand;
 
@@ -20,6 +33,57 @@ pub enum ArgType {
     Channel,
     Users, // List of user IDs
     Channels, // List of channel IDs
+    Role, // Role mention, ID or name
+    Member, // Guild member mention, ID, name or nickname
+    Duration, // Duration like 1h30m
+    Bool,
+    Emoji, // Custom or unicode emoji
+    Choice(&'static [&'static str]), // One of the fixed options
+}
+
+impl ArgType {
+    /// Locale key describing what is expected for this argument type.
+    pub fn expected_key(&self) -> &'static str {
+        match self {
+            ArgType::Arg | ArgType::Args | ArgType::Text => "arg-expected-text",
+            ArgType::Number => "arg-expected-number",
+            ArgType::User | ArgType::Users | ArgType::Member => "arg-expected-user",
+            ArgType::Channel | ArgType::Channels => "arg-expected-channel",
+            ArgType::Role => "arg-expected-role",
+            ArgType::Duration => "arg-expected-duration",
+            ArgType::Bool => "arg-expected-bool",
+            ArgType::Emoji => "arg-expected-emoji",
+            ArgType::Choice(_) => "arg-expected-choice",
+        }
+    }
+
+    /// Name of the argument type, as shown in the command catalog.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ArgType::Arg => "arg",
+            ArgType::Args => "args",
+            ArgType::Text => "text",
+            ArgType::Number => "number",
+            ArgType::User => "user",
+            ArgType::Channel => "channel",
+            ArgType::Users => "users",
+            ArgType::Channels => "channels",
+            ArgType::Role => "role",
+            ArgType::Member => "member",
+            ArgType::Duration => "duration",
+            ArgType::Bool => "bool",
+            ArgType::Emoji => "emoji",
+            ArgType::Choice(_) => "choice",
+        }
+    }
+
+    /// The accepted values of `Choice` arguments, empty for the other types.
+    pub fn choices(&self) -> &'static [&'static str] {
+        match self {
+            ArgType::Choice(choices) => choices,
+            _ => &[],
+        }
+    }
 }
 
 /// Specification for command arguments
@@ -27,19 +91,136 @@ pub struct ArgSpec {
     name: &'static str,
     arg_type: ArgType,
     optional: bool,
+    autocomplete: bool,
 }
 
 impl ArgSpec {
     /// Create a new argument specification
     pub fn new(name: &'static str, arg_type: ArgType, optional: bool) -> Self {
-        ArgSpec { name, arg_type, optional }
+        ArgSpec { name, arg_type, optional, autocomplete: false }
+    }
+
+    /// Suggests values while the slash command option is typed, using the command's
+    /// `autocomplete` method. `Choice` arguments already list their values and ignore it.
+    pub fn with_autocomplete(mut self) -> Self {
+        self.autocomplete = true;
+        self
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn arg_type(&self) -> &ArgType {
+        &self.arg_type
+    }
+
+    pub fn is_optional(&self) -> bool {
+        self.optional
     }
 
     pub fn to_string(&self) -> String {
-        if self.optional { format!("[{}]", self.name) } else { format!("<{}>", self.name) }
+        let name = match self.arg_type {
+            ArgType::Choice(choices) => format!("{}: {}", self.name, choices.join("|")),
+            _ => self.name.to_string(),
+        };
+        if self.optional { format!("[{}]", name) } else { format!("<{}>", name) }
+    }
+
+    /// Usage of the argument when it's a flag, like `[--silent]` or `[--reason=<reason>]`.
+    pub fn to_flag_string(&self) -> String {
+        match self.arg_type {
+            ArgType::Bool => format!("[--{}]", self.name),
+            ArgType::Choice(choices) => format!("[--{}={}]", self.name, choices.join("|")),
+            _ => format!("[--{}=<{}>]", self.name, self.name),
+        }
+    }
+
+    /// Error for when this argument is missing or its value is invalid.
+    pub fn error(&self, missing: bool) -> ArgError {
+        ArgError {
+            arg: self.name,
+            missing,
+            expected_key: self.arg_type.expected_key(),
+            choices: match self.arg_type {
+                ArgType::Choice(choices) => choices.join(", "),
+                _ => String::new(),
+            },
+        }
+    }
+
+    /// Name of the matching slash command option.
+    pub fn slash_name(&self) -> String {
+        slash_name(self.name)
+    }
+
+    /// Maps the argument to a slash command option.
+    ///
+    /// Arguments taking several values (`Args`, `Users` and `Channels`) become string options,
+    /// which are parsed the same way as prefix command arguments.
+    pub fn to_command_option(&self) -> CommandOption {
+        let kind = match self.arg_type {
+            ArgType::Number => CommandOptionType::Integer,
+            ArgType::User | ArgType::Member => CommandOptionType::User,
+            ArgType::Channel => CommandOptionType::Channel,
+            ArgType::Role => CommandOptionType::Role,
+            ArgType::Bool => CommandOptionType::Boolean,
+            | ArgType::Arg
+            | ArgType::Args
+            | ArgType::Text
+            | ArgType::Users
+            | ArgType::Channels
+            | ArgType::Duration
+            | ArgType::Emoji
+            | ArgType::Choice(_) => CommandOptionType::String,
+        };
+        let choices = match self.arg_type {
+            ArgType::Choice(choices) =>
+                Some(
+                    choices
+                        .iter()
+                        .map(|choice| CommandOptionChoice {
+                            name: choice.to_string(),
+                            name_localizations: None,
+                            value: CommandOptionChoiceValue::String(choice.to_string()),
+                        })
+                        .collect()
+                ),
+            _ => None,
+        };
+        // Discord rejects options with both choices and autocomplete
+        let autocomplete = self.autocomplete && choices.is_none();
+
+        CommandOption {
+            autocomplete: Some(autocomplete),
+            channel_types: None,
+            choices,
+            description: self.name.to_string(),
+            description_localizations: None,
+            kind,
+            max_length: None,
+            max_value: None,
+            min_length: None,
+            min_value: None,
+            name: self.slash_name(),
+            name_localizations: None,
+            options: None,
+            required: Some(!self.optional),
+        }
     }
 }
 
+/// Turns a command or argument name into a valid slash command name.
+///
+/// Slash command names are lowercase, at most 32 characters, without spaces or symbols.
+pub fn slash_name(name: &str) -> String {
+    name.to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .take(32)
+        .collect()
+}
+
 /// Parsed command argument types
 pub enum ParsedArg {
     None,
@@ -51,8 +232,64 @@ pub enum ParsedArg {
     Users(Vec<User>),
     Channel(Channel),
     Channels(Vec<Channel>),
+    Role(Role),
+    Member(Member),
+    Duration(Duration),
+    Bool(bool),
+    Emoji(String),
+    Choice(String),
+    /// The command's flags, always the last parsed argument of commands declaring flags
+    Flags(ParsedFlags),
 }
 
+/// Flags given to a command, like `--silent` or `--reason=spam`.
+#[derive(Default)]
+pub struct ParsedFlags {
+    values: HashMap<String, String>,
+}
+
+impl ParsedFlags {
+    pub fn insert(&mut self, name: &str, value: String) {
+        self.values.insert(name.to_string(), value);
+    }
+
+    /// Whether a switch like `--silent` was given.
+    pub fn has(&self, name: &str) -> bool {
+        self.values.contains_key(name)
+    }
+
+    /// The value of a flag like `--reason=spam`.
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.values
+            .get(name)
+            .map(|value| value.as_str())
+            .filter(|value| !value.is_empty())
+    }
+}
+
+/// A command argument that is missing or couldn't be parsed.
+#[derive(Debug)]
+pub struct ArgError {
+    pub arg: &'static str,
+    pub missing: bool,
+    /// Locale key describing the expected value
+    pub expected_key: &'static str,
+    /// The accepted values of `Choice` arguments
+    pub choices: String,
+}
+
+impl fmt::Display for ArgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.missing {
+            write!(f, "Missing required argument `{}`", self.arg)
+        } else {
+            write!(f, "Invalid argument `{}`", self.arg)
+        }
+    }
+}
+
+impl std::error::Error for ArgError {}
+
 /// Trait defining a context command category
 pub trait ContextCommandCategory {
     fn name(&self) -> &'static str;
@@ -2,8 +2,8 @@ use std::error::Error;
 
 use async_trait::async_trait;
 use fluent_bundle::FluentArgs;
-use rustycrab_model::color::ColorResolvables;
-use twilight_model::gateway::payload::incoming::MessageCreate;
+use rustycrab_model::{ color::ColorResolvables, modules::GuildModule };
+use crate::twilightrs::commands::invocation::CommandInvocation;
 
 use crate::twilightrs::{
     commands::context::{
@@ -24,6 +24,10 @@ impl ContextCommand for JoinCommand {
         "join"
     }
 
+    fn module(&self) -> Option<GuildModule> {
+        Some(GuildModule::Music)
+    }
+
     fn args(&self) -> Vec<ArgSpec> {
         vec![ArgSpec::new("voice channel", ArgType::Channel, true)]
     }
@@ -32,7 +36,7 @@ impl ContextCommand for JoinCommand {
         &self,
         client: DiscordClient,
         config: &GuildConfigModel,
-        msg: &MessageCreate,
+        msg: &CommandInvocation,
         _: Vec<ParsedArg>
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         let guild_id = msg.guild_id.ok_or("command-guildonly")?;
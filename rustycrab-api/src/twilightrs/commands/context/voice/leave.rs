@@ -1,8 +1,8 @@
 use std::error::Error;
 
 use async_trait::async_trait;
-use rustycrab_model::{ color::ColorResolvables, music::PlayerLoopState };
-use twilight_model::gateway::payload::incoming::MessageCreate;
+use rustycrab_model::{ color::ColorResolvables, music::PlayerLoopState, modules::GuildModule };
+use crate::twilightrs::commands::invocation::CommandInvocation;
 
 use crate::twilightrs::{
     commands::context::{ context_command::{ ContextCommand, GuildConfigModel }, ParsedArg },
@@ -17,11 +17,15 @@ impl ContextCommand for LeaveChannelCommand {
         "leave"
     }
 
+    fn module(&self) -> Option<GuildModule> {
+        Some(GuildModule::Music)
+    }
+
     async fn run(
         &self,
         client: DiscordClient,
         config: &GuildConfigModel,
-        msg: &MessageCreate,
+        msg: &CommandInvocation,
         _: Vec<ParsedArg>
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         let guild_id = msg.guild_id.ok_or("command-guildonly")?;
@@ -1,8 +1,8 @@
 use std::error::Error;
 
 use async_trait::async_trait;
-use rustycrab_model::{ color::ColorResolvables, music::PlayerLoopState };
-use twilight_model::gateway::payload::incoming::MessageCreate;
+use rustycrab_model::{ color::ColorResolvables, music::PlayerLoopState, modules::GuildModule };
+use crate::twilightrs::commands::invocation::CommandInvocation;
 
 use crate::twilightrs::{
     commands::context::{
@@ -22,6 +22,10 @@ impl ContextCommand for LoopMusicCommand {
         "loop"
     }
 
+    fn module(&self) -> Option<GuildModule> {
+        Some(GuildModule::Music)
+    }
+
     fn args(&self) -> Vec<ArgSpec> {
         vec![ArgSpec::new("type: current/one/all/queue", ArgType::Arg, true)]
     }
@@ -30,7 +34,7 @@ impl ContextCommand for LoopMusicCommand {
         &self,
         client: DiscordClient,
         config: &GuildConfigModel,
-        msg: &MessageCreate,
+        msg: &CommandInvocation,
         command_args: Vec<ParsedArg>
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         let guild_id = msg.guild_id.ok_or("command-guildonly")?;
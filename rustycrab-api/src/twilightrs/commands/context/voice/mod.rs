@@ -13,6 +13,7 @@ mod skip_to;
 mod loop_music;
 mod loopq_music;
 mod unloop_music;
+mod search;
 
 use self::{
     play::PlayCommand,
@@ -29,6 +30,7 @@ use self::{
     unloop_music::UnloopMusicCommand,
     music::MusicHelpCommand,
     loopq_music::LoopQueueMusicCommand,
+    search::SearchMusicCommand,
 };
 
 use super::{ ContextCommandCategory, context_command::ContextCommand };
@@ -46,6 +48,7 @@ impl ContextCommandCategory for VoiceCommands {
             Box::new(LeaveChannelCommand {}) as Box<dyn ContextCommand>,
             Box::new(MusicHelpCommand {}) as Box<dyn ContextCommand>,
             Box::new(PlayCommand {}) as Box<dyn ContextCommand>,
+            Box::new(SearchMusicCommand {}) as Box<dyn ContextCommand>,
             Box::new(PauseMusicCommand {}) as Box<dyn ContextCommand>,
             Box::new(ResumeMusicCommand {}) as Box<dyn ContextCommand>,
             Box::new(StopMusicCommand {}) as Box<dyn ContextCommand>,
@@ -1,9 +1,9 @@
 use std::error::Error;
 
 use async_trait::async_trait;
-use rustycrab_model::color::ColorResolvables;
+use rustycrab_model::{ color::ColorResolvables, modules::GuildModule };
 use songbird::tracks::PlayMode;
-use twilight_model::gateway::payload::incoming::MessageCreate;
+use crate::twilightrs::commands::invocation::CommandInvocation;
 
 use crate::twilightrs::{
     commands::context::{ context_command::{ ContextCommand, GuildConfigModel }, ParsedArg },
@@ -18,11 +18,15 @@ impl ContextCommand for PauseMusicCommand {
         "pause"
     }
 
+    fn module(&self) -> Option<GuildModule> {
+        Some(GuildModule::Music)
+    }
+
     async fn run(
         &self,
         client: DiscordClient,
         config: &GuildConfigModel,
-        msg: &MessageCreate,
+        msg: &CommandInvocation,
         _: Vec<ParsedArg>
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         let guild_id = msg.guild_id.ok_or("command-guildonly")?;
@@ -3,35 +3,52 @@ use std::{ error::Error, sync::Arc };
 
 use async_trait::async_trait;
 use fluent_bundle::FluentArgs;
-use rustycrab_model::color::ColorResolvables;
+use rustycrab_model::{
+    color::ColorResolvables,
+    cooldown::{ CommandCooldown, CooldownScope },
+    modules::GuildModule,
+};
 use songbird::input::{ YoutubeDl, Compose };
 
 use spotify::models::SpotifyPlaylistResponse;
-use twilight_model::gateway::payload::incoming::MessageCreate;
+use crate::twilightrs::commands::invocation::CommandInvocation;
 use twilight_model::id::Id;
 use twilight_model::id::marker::GuildMarker;
+use twilight_model::application::command::CommandOptionChoice;
 
 use crate::utilities::app_error::BoxedError;
 use crate::utilities::format_duration;
 use crate::{
     twilightrs::{
         messages::DiscordEmbedField,
-        commands::context::{
-            context_command::{ ContextCommand, GuildConfigModel },
-            ParsedArg,
-            ArgSpec,
-            ArgType,
+        commands::{
+            context::{
+                context_command::{ ContextCommand, GuildConfigModel },
+                ParsedArg,
+                ArgSpec,
+                ArgType,
+            },
+            autocomplete::{ FocusedOption, string_choice },
         },
         discord_client::{ DiscordClient, MessageContent },
         messages::DiscordEmbed,
         bot::voice_music::{
-            utils::parse_url::parse_url_or_search_query,
+            utils::{
+                parse_url::parse_url_or_search_query,
+                youtube_dl::search_youtube_tracks,
+            },
             player::{ track_info::track_info_fields, add_track_to_queue::add_track_to_queue },
         },
     },
     cdn_avatar,
 };
 
+/// Shortest text searched while typing, shorter ones match too many tracks.
+const MIN_SEARCH_LENGTH: usize = 3;
+/// Discord drops autocomplete responses after 3 seconds.
+const SEARCH_TIMEOUT: Duration = Duration::from_millis(2500);
+const MAX_SEARCH_RESULTS: usize = 5;
+
 pub struct PlayCommand {}
 
 #[async_trait]
@@ -40,15 +57,57 @@ impl ContextCommand for PlayCommand {
         "play"
     }
 
+    fn module(&self) -> Option<GuildModule> {
+        Some(GuildModule::Music)
+    }
+
     fn args(&self) -> Vec<ArgSpec> {
-        vec![ArgSpec::new("url/search", ArgType::Text, false)]
+        vec![ArgSpec::new("url/search", ArgType::Text, false).with_autocomplete()]
+    }
+
+    async fn autocomplete(
+        &self,
+        _: DiscordClient,
+        _: &GuildConfigModel,
+        _: &CommandInvocation,
+        option: &FocusedOption
+    ) -> Result<Vec<CommandOptionChoice>, Box<dyn Error + Send + Sync + 'static>> {
+        let query = option.value.trim();
+        // urls are played as they are
+        if
+            query.chars().count() < MIN_SEARCH_LENGTH ||
+            query.starts_with("http://") ||
+            query.starts_with("https://")
+        {
+            return Ok(Vec::new());
+        }
+
+        let tracks = tokio::time
+            ::timeout(SEARCH_TIMEOUT, search_youtube_tracks(query, MAX_SEARCH_RESULTS)).await
+            .unwrap_or_else(|_| Ok(Vec::new()))?;
+
+        Ok(
+            tracks
+                .iter()
+                .map(|(title, url)| string_choice(title, url))
+                .collect()
+        )
+    }
+
+    fn cooldown(&self) -> Option<CommandCooldown> {
+        Some(CommandCooldown::new(CooldownScope::User, 3, 10))
+    }
+
+    fn rerun_on_edit(&self) -> bool {
+        // a search that found nothing is fixed by editing it
+        true
     }
 
     async fn run(
         &self,
         client: DiscordClient,
         config: &GuildConfigModel,
-        msg: &MessageCreate,
+        msg: &CommandInvocation,
         command_args: Vec<ParsedArg>
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         // make sure the command is in a guild
@@ -79,10 +138,9 @@ impl ContextCommand for PlayCommand {
             }
         }
 
-        let sent_msg = client
-            .reply_message(
-                msg.channel_id,
-                msg.id,
+        let sent_msg = msg
+            .reply(
+                &client,
                 MessageContent::DiscordEmbeds(
                     vec![DiscordEmbed {
                         description: Some(
@@ -92,8 +150,7 @@ impl ContextCommand for PlayCommand {
                         ..Default::default()
                     }]
                 )
-            ).await?
-            .model().await?;
+            ).await?;
 
         // now the bot has connect to the channel
         // get the url(s) to play audio
@@ -127,7 +184,7 @@ impl PlayCommand {}
 async fn build_response(
     client: &DiscordClient,
     config: &GuildConfigModel,
-    msg: &MessageCreate,
+    msg: &CommandInvocation,
     guild_id: Id<GuildMarker>,
     urls: Vec<String>,
     playlist_data: Option<SpotifyPlaylistResponse>
@@ -238,9 +295,8 @@ async fn build_response(
         embeds.push(embed);
     }
 
-    let _ = client.reply_message(
-        msg.channel_id,
-        msg.id,
+    let _ = msg.reply(
+        &client,
         MessageContent::DiscordEmbeds(embeds)
     ).await;
 
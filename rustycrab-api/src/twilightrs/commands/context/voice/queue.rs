@@ -2,14 +2,14 @@ use std::error::Error;
 use fluent_bundle::FluentArgs;
 use futures_util::{ stream::StreamExt, future::join_all };
 use async_trait::async_trait;
-use rustycrab_model::color::ColorResolvables;
+use rustycrab_model::{ color::ColorResolvables, modules::GuildModule };
 use songbird::input::{ YoutubeDl, Compose };
 use twilight_model::{
-    gateway::payload::incoming::MessageCreate,
     channel::message::{ Component, component::{ ActionRow, Button }, Embed, MessageFlags },
     application::interaction::{ Interaction, InteractionData },
     http::interaction::{ InteractionResponseType, InteractionResponse },
 };
+use crate::twilightrs::commands::invocation::CommandInvocation;
 use tokio::time::{ timeout, Duration };
 
 use crate::{
@@ -29,11 +29,15 @@ impl ContextCommand for QueueCommand {
         "queue"
     }
 
+    fn module(&self) -> Option<GuildModule> {
+        Some(GuildModule::Music)
+    }
+
     async fn run(
         &self,
         client: DiscordClient,
         config: &GuildConfigModel,
-        msg: &MessageCreate,
+        msg: &CommandInvocation,
         _: Vec<ParsedArg>
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         let guild_id = msg.guild_id.ok_or("command-guildonly")?;
@@ -43,9 +47,8 @@ impl ContextCommand for QueueCommand {
 
         let queued_urls = client.voice_music_manager.get_waiting_queue(guild_id);
         if queued_urls.len() == 0 {
-            let _ = client.reply_message(
-                msg.channel_id,
-                msg.id,
+            let _ = msg.reply(
+                &client,
                 MessageContent::DiscordEmbeds(
                     vec![DiscordEmbed {
                         description: Some(
@@ -79,13 +82,11 @@ impl ContextCommand for QueueCommand {
             embed.thumbnail = metadata.thumbnail;
         }
 
-        let sent_msg = client
-            .reply_message(
-                msg.channel_id,
-                msg.id,
+        let sent_msg = msg
+            .reply(
+                &client,
                 MessageContent::DiscordEmbeds(vec![embed.clone()])
-            ).await?
-            .model().await?;
+            ).await?;
 
         async fn get_one_url(url: &str) -> String {
             let mut source = YoutubeDl::new(reqwest::Client::new(), url.to_string());
@@ -0,0 +1,116 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use fluent_bundle::FluentArgs;
+use rustycrab_model::{
+    color::ColorResolvables,
+    cooldown::{ CommandCooldown, CooldownScope },
+    modules::GuildModule,
+};
+use twilight_model::channel::message::component::{ SelectMenu, SelectMenuType };
+
+use crate::twilightrs::{
+    commands::{
+        context::{
+            context_command::{ ContextCommand, GuildConfigModel },
+            ParsedArg,
+            ArgSpec,
+            ArgType,
+        },
+        invocation::CommandInvocation,
+    },
+    discord_client::{ DiscordClient, MessageContent },
+    messages::DiscordEmbed,
+    bot::voice_music::utils::youtube_dl::search_youtube_tracks,
+    events::interaction_handlers::select_menus::SelectMenuEvents,
+    utils::select_menus::{ select_menu, select_option, select_menu_row },
+};
+
+const MAX_SEARCH_RESULTS: usize = 10;
+
+/// Searches YouTube and lets the author pick the track to play in a select menu.
+pub struct SearchMusicCommand {}
+
+#[async_trait]
+impl ContextCommand for SearchMusicCommand {
+    fn name(&self) -> &'static str {
+        "search"
+    }
+
+    fn module(&self) -> Option<GuildModule> {
+        Some(GuildModule::Music)
+    }
+
+    fn args(&self) -> Vec<ArgSpec> {
+        vec![ArgSpec::new("query", ArgType::Text, false)]
+    }
+
+    fn cooldown(&self) -> Option<CommandCooldown> {
+        Some(CommandCooldown::new(CooldownScope::User, 3, 10))
+    }
+
+    async fn run(
+        &self,
+        client: DiscordClient,
+        config: &GuildConfigModel,
+        msg: &CommandInvocation,
+        command_args: Vec<ParsedArg>
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let query = match command_args.first() {
+            Some(ParsedArg::Text(query)) => query,
+            _ => {
+                return Err("command-play-invalid-url".into());
+            }
+        };
+
+        let tracks = search_youtube_tracks(query, MAX_SEARCH_RESULTS).await?;
+        let mut args = FluentArgs::new();
+        args.set("query", query.clone());
+        if tracks.is_empty() {
+            let content = client.get_locale_string(
+                &config.locale,
+                "command-search-noresults",
+                Some(&args)
+            );
+            return Err(content.into());
+        }
+
+        let options = tracks
+            .iter()
+            .map(|(title, url)| select_option(title, url))
+            .collect();
+        let menu = SelectMenu {
+            options: Some(options),
+            ..select_menu(
+                SelectMenuEvents::MusicSearch.custom_id(&[&msg.author.id.to_string()]),
+                SelectMenuType::Text,
+                &client.get_locale_string(&config.locale, "command-search-placeholder", None)
+            )
+        };
+
+        let title = client.get_locale_string(&config.locale, "command-search-title", Some(&args));
+        msg.reply_with_components(
+            &client,
+            MessageContent::DiscordEmbeds(
+                vec![DiscordEmbed {
+                    title: Some(title),
+                    description: Some(
+                        tracks
+                            .iter()
+                            .enumerate()
+                            .map(|(index, (title, url))| {
+                                format!("{}. [{}]({})", index + 1, title, url)
+                            })
+                            .collect::<Vec<String>>()
+                            .join("\n")
+                    ),
+                    color: Some(ColorResolvables::Blue.as_u32()),
+                    ..Default::default()
+                }]
+            ),
+            &[select_menu_row(menu)]
+        ).await?;
+
+        Ok(())
+    }
+}
@@ -2,8 +2,8 @@ use std::error::Error;
 
 use async_trait::async_trait;
 use fluent_bundle::FluentArgs;
-use rustycrab_model::color::ColorResolvables;
-use twilight_model::gateway::payload::incoming::MessageCreate;
+use rustycrab_model::{ color::ColorResolvables, modules::GuildModule };
+use crate::twilightrs::commands::invocation::CommandInvocation;
 
 use crate::{
     twilightrs::{
@@ -21,6 +21,10 @@ impl ContextCommand for SkipCurrentTrackCommand {
         "skip"
     }
 
+    fn module(&self) -> Option<GuildModule> {
+        Some(GuildModule::Music)
+    }
+
     fn aliases(&self) -> Vec<&'static str> {
         vec!["next"]
     }
@@ -29,7 +33,7 @@ impl ContextCommand for SkipCurrentTrackCommand {
         &self,
         client: DiscordClient,
         config: &GuildConfigModel,
-        msg: &MessageCreate,
+        msg: &CommandInvocation,
         _: Vec<ParsedArg>
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         let guild_id = msg.guild_id.ok_or("command-guildonly")?;
@@ -105,9 +109,8 @@ impl ContextCommand for SkipCurrentTrackCommand {
             }
         };
 
-        client.reply_message(
-            msg.channel_id,
-            msg.id,
+        msg.reply(
+            &client,
             MessageContent::DiscordEmbeds(vec![embed])
         ).await?;
 
@@ -2,15 +2,20 @@ use std::error::Error;
 
 use async_trait::async_trait;
 use fluent_bundle::FluentArgs;
-use rustycrab_model::color::ColorResolvables;
-use twilight_model::gateway::payload::incoming::MessageCreate;
+use rustycrab_model::{ color::ColorResolvables, modules::GuildModule };
+use crate::twilightrs::commands::invocation::CommandInvocation;
+
+use twilight_model::application::command::CommandOptionChoice;
 
 use crate::twilightrs::{
-    commands::context::{
-        context_command::{ ContextCommand, GuildConfigModel },
-        ParsedArg,
-        ArgSpec,
-        ArgType,
+    commands::{
+        context::{
+            context_command::{ ContextCommand, GuildConfigModel },
+            ParsedArg,
+            ArgSpec,
+            ArgType,
+        },
+        autocomplete::{ FocusedOption, MAX_AUTOCOMPLETE_CHOICES, integer_choice, matches_typed },
     },
     discord_client::DiscordClient,
     utils::reply_command,
@@ -23,15 +28,45 @@ impl ContextCommand for SkipToTrackCommand {
         "skipto"
     }
 
+    fn module(&self) -> Option<GuildModule> {
+        Some(GuildModule::Music)
+    }
+
     fn args(&self) -> Vec<ArgSpec> {
-        vec![ArgSpec::new("position", ArgType::Number, false)]
+        vec![ArgSpec::new("position", ArgType::Number, false).with_autocomplete()]
+    }
+
+    async fn autocomplete(
+        &self,
+        client: DiscordClient,
+        _: &GuildConfigModel,
+        msg: &CommandInvocation,
+        option: &FocusedOption
+    ) -> Result<Vec<CommandOptionChoice>, Box<dyn Error + Send + Sync + 'static>> {
+        let guild_id = msg.guild_id.ok_or("command-guildonly")?;
+
+        // the waiting queue holds urls, or search queries for the tracks found by name
+        Ok(
+            client.voice_music_manager
+                .get_waiting_queue(guild_id)
+                .iter()
+                .enumerate()
+                .map(|(index, track)| {
+                    let name = format!("{}. {}", index + 1, track.trim_start_matches("ytsearch1:"));
+                    (name, (index as i64) + 1)
+                })
+                .filter(|(name, _)| matches_typed(name, &option.value))
+                .take(MAX_AUTOCOMPLETE_CHOICES)
+                .map(|(name, position)| integer_choice(&name, position))
+                .collect()
+        )
     }
 
     async fn run(
         &self,
         client: DiscordClient,
         config: &GuildConfigModel,
-        msg: &MessageCreate,
+        msg: &CommandInvocation,
         command_args: Vec<ParsedArg>
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         let guild_id = msg.guild_id.ok_or("command-guildonly")?;
@@ -1,12 +1,12 @@
 use std::error::Error;
 
 use async_trait::async_trait;
-use rustycrab_model::music::PlayerLoopState;
-use twilight_model::gateway::payload::incoming::MessageCreate;
+use rustycrab_model::{ music::PlayerLoopState, modules::GuildModule };
+use crate::twilightrs::commands::invocation::CommandInvocation;
 
 use crate::twilightrs::{
     commands::context::{ context_command::{ ContextCommand, GuildConfigModel }, ParsedArg },
-    discord_client::DiscordClient,
+    discord_client::{ DiscordClient, MessageContent },
 };
 
 pub struct StopMusicCommand {}
@@ -17,11 +17,15 @@ impl ContextCommand for StopMusicCommand {
         "stop"
     }
 
+    fn module(&self) -> Option<GuildModule> {
+        Some(GuildModule::Music)
+    }
+
     async fn run(
         &self,
         client: DiscordClient,
         _config: &GuildConfigModel,
-        msg: &MessageCreate,
+        msg: &CommandInvocation,
         _: Vec<ParsedArg>
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         let guild_id = msg.guild_id.ok_or("command-guildonly")?;
@@ -35,7 +39,7 @@ impl ContextCommand for StopMusicCommand {
 
         let _ = handle.stop();
 
-        client.http.create_message(msg.channel_id).content("Stopped playing music")?.await?;
+        msg.send(&client, MessageContent::Text("Stopped playing music".to_string())).await?;
 
         Ok(())
     }
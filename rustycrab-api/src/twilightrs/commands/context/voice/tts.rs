@@ -1,7 +1,8 @@
 use std::error::Error;
 
 use async_trait::async_trait;
-use twilight_model::gateway::payload::incoming::MessageCreate;
+use rustycrab_model::modules::GuildModule;
+use crate::twilightrs::commands::invocation::CommandInvocation;
 
 use crate::twilightrs::{
     commands::context::{
@@ -21,6 +22,10 @@ impl ContextCommand for TtsCommand {
         "tts"
     }
 
+    fn module(&self) -> Option<GuildModule> {
+        Some(GuildModule::Music)
+    }
+
     fn aliases(&self) -> Vec<&'static str> {
         Vec::new()
     }
@@ -37,7 +42,7 @@ impl ContextCommand for TtsCommand {
         &self,
         client: DiscordClient,
         _: &GuildConfigModel,
-        msg: &MessageCreate,
+        msg: &CommandInvocation,
         command_args: Vec<ParsedArg>
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         Ok(())
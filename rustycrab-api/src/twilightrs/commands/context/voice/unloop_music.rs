@@ -1,8 +1,8 @@
 use std::error::Error;
 
 use async_trait::async_trait;
-use rustycrab_model::music::PlayerLoopState;
-use twilight_model::gateway::payload::incoming::MessageCreate;
+use rustycrab_model::{ music::PlayerLoopState, modules::GuildModule };
+use crate::twilightrs::commands::invocation::CommandInvocation;
 
 use crate::twilightrs::{
     commands::context::{ context_command::{ ContextCommand, GuildConfigModel }, ParsedArg },
@@ -16,21 +16,26 @@ impl ContextCommand for UnloopMusicCommand {
         "unloop"
     }
 
+    fn module(&self) -> Option<GuildModule> {
+        Some(GuildModule::Music)
+    }
+
     async fn run(
         &self,
         client: DiscordClient,
         _: &GuildConfigModel,
-        msg: &MessageCreate,
+        msg: &CommandInvocation,
         _: Vec<ParsedArg>
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         let guild_id = msg.guild_id.ok_or("Command not used in a guild")?;
 
         if !client.is_user_in_same_channel_as_bot(guild_id, msg.author.id).await? {
-            client.http
-                .create_message(msg.channel_id)
-                .content(
-                    "You need to be in the same voice channel as the bot to use this command"
-                )?.await?;
+            msg.send(
+                &client,
+                MessageContent::Text(
+                    "You need to be in the same voice channel as the bot to use this command".to_string()
+                )
+            ).await?;
             return Ok(());
         }
 
@@ -48,9 +53,8 @@ impl ContextCommand for UnloopMusicCommand {
         }
 
         client.voice_music_manager.set_loop_state(guild_id, PlayerLoopState::NoLoop);
-        client.reply_message(
-            msg.channel_id,
-            msg.id,
+        msg.reply(
+            &client,
             MessageContent::Text("Looping disabled".to_string())
         ).await?;
 
@@ -0,0 +1,73 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use twilight_model::{
+    application::command::CommandType,
+    gateway::payload::incoming::InteractionCreate,
+};
+
+use crate::{
+    twilightrs::{
+        commands::context::context_command::GuildConfigModel,
+        discord_client::DiscordClient,
+        messages::DiscordEmbed,
+    },
+    cdn_avatar,
+};
+
+use super::context_menu_command::{ ContextMenuCommand, ContextMenuTarget };
+
+/// Shows the avatar and the banner of a user, only to the user who asked.
+pub struct AvatarMenuCommand {}
+
+#[async_trait]
+impl ContextMenuCommand for AvatarMenuCommand {
+    fn name(&self) -> &'static str {
+        "Avatar / Banner"
+    }
+
+    fn kind(&self) -> CommandType {
+        CommandType::User
+    }
+
+    async fn run(
+        &self,
+        client: DiscordClient,
+        _: &GuildConfigModel,
+        interaction: &Box<InteractionCreate>,
+        target: &ContextMenuTarget
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let user = match target {
+            ContextMenuTarget::User(user) => user,
+            _ => {
+                return Ok(());
+            }
+        };
+
+        let mut embeds = Vec::new();
+        if let Some(avatar_hash) = user.avatar {
+            embeds.push(DiscordEmbed {
+                title: Some(format!("{}'s avatar", &user.name)),
+                image: Some(cdn_avatar!(user.id, avatar_hash)),
+                ..Default::default()
+            });
+        }
+        if let Some(banner_url) = client.get_user_banner_url(user.id).await? {
+            embeds.push(DiscordEmbed {
+                title: Some(format!("{}'s banner", &user.name)),
+                image: Some(banner_url),
+                ..Default::default()
+            });
+        }
+
+        if embeds.is_empty() {
+            let content = format!("No avatar or banner found for user {}", user.name);
+            client.ephemeral_interaction_reply(interaction, content).await?;
+            return Ok(());
+        }
+
+        client.embed_interaction_reply(interaction, embeds, true).await?;
+
+        Ok(())
+    }
+}
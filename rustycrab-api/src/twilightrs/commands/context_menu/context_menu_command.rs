@@ -0,0 +1,104 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use rustycrab_model::modules::GuildModule;
+use twilight_model::{
+    application::{
+        command::{ Command, CommandType },
+        interaction::application_command::CommandData,
+    },
+    channel::Message,
+    gateway::payload::incoming::InteractionCreate,
+    guild::Permissions,
+    id::{ Id, marker::{ MessageMarker, UserMarker } },
+    user::User,
+};
+
+use crate::twilightrs::{
+    discord_client::DiscordClient,
+    commands::context::context_command::GuildConfigModel,
+};
+
+/// The user or message a context menu command was used on.
+pub enum ContextMenuTarget {
+    User(User),
+    Message(Message),
+}
+
+impl ContextMenuTarget {
+    /// Finds the target in the resolved data of the interaction.
+    pub fn from_data(command_data: &CommandData) -> Option<Self> {
+        let target_id = command_data.target_id?;
+        let resolved = command_data.resolved.as_ref()?;
+
+        match command_data.kind {
+            CommandType::User => {
+                let user_id: Id<UserMarker> = target_id.cast();
+                resolved.users.get(&user_id).cloned().map(ContextMenuTarget::User)
+            }
+            CommandType::Message => {
+                let message_id: Id<MessageMarker> = target_id.cast();
+                resolved.messages.get(&message_id).cloned().map(ContextMenuTarget::Message)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A command shown when right-clicking a user or a message.
+///
+/// Context menu commands have no description or options, their name is what is shown in the
+/// menu and can contain spaces and capital letters.
+#[async_trait]
+pub trait ContextMenuCommand: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    /// `CommandType::User` or `CommandType::Message`.
+    fn kind(&self) -> CommandType;
+
+    fn permissions(&self) -> Option<Permissions> {
+        None
+    }
+
+    /// The guild module the command belongs to, the command can't be used while it's disabled.
+    fn module(&self) -> Option<GuildModule> {
+        None
+    }
+
+    /// Builds the command, to register it together with the other commands.
+    fn to_command(&self) -> Command {
+        Command {
+            application_id: None,
+            default_member_permissions: self.permissions(),
+            dm_permission: None,
+            description: String::new(),
+            description_localizations: None,
+            guild_id: None,
+            id: None,
+            kind: self.kind(),
+            name: self.name().to_string(),
+            name_localizations: None,
+            nsfw: Some(false),
+            options: Vec::new(),
+            version: Id::new(1),
+        }
+    }
+
+    async fn exec(
+        &self,
+        client: DiscordClient,
+        config: &GuildConfigModel,
+        interaction: &Box<InteractionCreate>,
+        target: &ContextMenuTarget
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.run(client, config, interaction, target).await
+    }
+
+    async fn run(
+        &self,
+        client: DiscordClient,
+        config: &GuildConfigModel,
+        interaction: &Box<InteractionCreate>,
+        target: &ContextMenuTarget
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>>;
+}
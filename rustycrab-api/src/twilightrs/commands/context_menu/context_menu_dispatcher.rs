@@ -0,0 +1,106 @@
+use std::{ error::Error, collections::HashMap, sync::Arc, time::Instant };
+
+use twilight_model::{
+    application::{ command::Command, interaction::application_command::CommandData },
+    gateway::payload::incoming::InteractionCreate,
+};
+
+use crate::{
+    twilightrs::{
+        discord_client::DiscordClient,
+        commands::context::context_command::GuildConfigModel,
+        utils::{ module_disabled_message, record_command_usage },
+    },
+    metrics::metrics,
+    queries::command_usages_queries::CommandUsageRecord,
+};
+
+use super::{
+    context_menu_command::{ ContextMenuCommand, ContextMenuTarget },
+    snipe::SnipeMenuCommand,
+    open_ticket::OpenTicketMenuCommand,
+    report::ReportMessageMenuCommand,
+    avatar::AvatarMenuCommand,
+};
+
+pub struct ContextMenuDispatcher {
+    pub commands: HashMap<String, Box<dyn ContextMenuCommand>>,
+}
+
+impl ContextMenuDispatcher {
+    pub fn new() -> Self {
+        let mut commands: HashMap<String, Box<dyn ContextMenuCommand>> = HashMap::new();
+        let menu_commands: Vec<Box<dyn ContextMenuCommand>> = vec![
+            Box::new(SnipeMenuCommand {}),
+            Box::new(OpenTicketMenuCommand {}),
+            Box::new(ReportMessageMenuCommand {}),
+            Box::new(AvatarMenuCommand {})
+        ];
+        for command in menu_commands {
+            commands.entry(command.name().to_string()).or_insert(command);
+        }
+        Self {
+            commands,
+        }
+    }
+
+    /// The context menu commands to register.
+    ///
+    /// With `module_flags`, the commands of the modules disabled by the flags are left out.
+    pub fn menu_commands(&self, module_flags: Option<i32>) -> Vec<Command> {
+        self.commands
+            .values()
+            .filter(|command| {
+                match (command.module(), module_flags) {
+                    (Some(module), Some(flags)) => module.is_enabled(flags),
+                    _ => true,
+                }
+            })
+            .map(|command| command.to_command())
+            .collect()
+    }
+
+    pub async fn dispatch(
+        &self,
+        client: DiscordClient,
+        config: &GuildConfigModel,
+        interaction: &Box<InteractionCreate>,
+        command_data: &Box<CommandData>
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let command = match self.commands.get(&command_data.name) {
+            Some(command) if command.kind() == command_data.kind => command,
+            _ => {
+                return Ok(());
+            }
+        };
+        let target = ContextMenuTarget::from_data(command_data).ok_or(
+            "context menu interaction has no target"
+        )?;
+
+        if let Some(module) = command.module() {
+            if !module.is_enabled(config.module_flags) {
+                let content = module_disabled_message(&client, config, module);
+                client.ephemeral_interaction_reply(interaction, content).await?;
+                return Ok(());
+            }
+        }
+
+        let bot_label = client.bot_id.to_string();
+        let started_at = Instant::now();
+        let result = command.exec(Arc::clone(&client), config, interaction, &target).await;
+        let duration = started_at.elapsed();
+        metrics().record_command(&bot_label, "menu", command.name(), duration, result.is_err());
+        if let (Some(channel), Some(user_id)) = (&interaction.channel, interaction.author_id()) {
+            record_command_usage(&client, config, CommandUsageRecord {
+                command: command.name().to_string(),
+                kind: "menu",
+                channel_id: channel.id.to_string(),
+                user_id: user_id.to_string(),
+                duration,
+                success: result.is_ok(),
+            });
+        }
+
+        result
+    }
+}
@@ -0,0 +1,7 @@
+pub mod context_menu_command;
+pub mod context_menu_dispatcher;
+
+mod snipe;
+mod open_ticket;
+mod report;
+mod avatar;
@@ -0,0 +1,122 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use rustycrab_model::modules::GuildModule;
+use twilight_model::{
+    application::command::CommandType,
+    channel::message::component::{ SelectMenu, SelectMenuType },
+    gateway::payload::incoming::InteractionCreate,
+};
+
+use crate::{
+    twilightrs::{
+        commands::{
+            context::context_command::GuildConfigModel,
+            invocation::CommandInvocation,
+        },
+        discord_client::{ DiscordClient, MessageContent },
+        events::interaction_handlers::{
+            modals::tickets::open_ticket_for_panel,
+            select_menus::SelectMenuEvents,
+        },
+        utils::select_menus::{ select_menu, select_option, select_menu_row, MAX_SELECT_OPTIONS },
+    },
+    queries::{
+        message_button_queries::MessageButtonQueries,
+        tickets_system::ticket_panels_queries::TicketPanelsQueries,
+    },
+    default_queries::DefaultSeaQueries,
+};
+
+use super::context_menu_command::{ ContextMenuCommand, ContextMenuTarget };
+
+/// Opens a ticket linking to the message, asking for the panel when the guild has several.
+pub struct OpenTicketMenuCommand {}
+
+#[async_trait]
+impl ContextMenuCommand for OpenTicketMenuCommand {
+    fn name(&self) -> &'static str {
+        "Open ticket about this message"
+    }
+
+    fn kind(&self) -> CommandType {
+        CommandType::Message
+    }
+
+    fn module(&self) -> Option<GuildModule> {
+        Some(GuildModule::Tickets)
+    }
+
+    async fn run(
+        &self,
+        client: DiscordClient,
+        _: &GuildConfigModel,
+        interaction: &Box<InteractionCreate>,
+        target: &ContextMenuTarget
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let (guild_id, message) = match (interaction.guild_id, target) {
+            (Some(guild_id), ContextMenuTarget::Message(message)) => (guild_id, message),
+            _ => {
+                return Ok(());
+            }
+        };
+        let channel_id = message.channel_id.to_string();
+        let message_id = message.id.to_string();
+        let linked_message = [channel_id.as_str(), message_id.as_str()];
+
+        let panels = TicketPanelsQueries::find_panels_by_discord_ids(
+            &client.db,
+            &client.bot_id.to_string(),
+            &guild_id.to_string()
+        ).await?;
+
+        match panels.as_slice() {
+            [] => {
+                client.ephemeral_interaction_reply(
+                    interaction,
+                    "This server has no ticket panels".to_string()
+                ).await?;
+            }
+            [panel] => {
+                let panel_id = panel.id.to_string();
+                open_ticket_for_panel(
+                    client,
+                    interaction,
+                    guild_id,
+                    panel_id,
+                    &linked_message
+                ).await?;
+            }
+            panels => {
+                let mut options = Vec::new();
+                for panel in panels.iter().take(MAX_SELECT_OPTIONS) {
+                    let button = MessageButtonQueries::find_by_id(
+                        &client.db,
+                        panel.button_id
+                    ).await?;
+                    options.push(select_option(&button.text, &panel.id.to_string()));
+                }
+                let menu = SelectMenu {
+                    options: Some(options),
+                    ..select_menu(
+                        SelectMenuEvents::TicketAboutMessage.custom_id(&linked_message),
+                        SelectMenuType::Text,
+                        "Pick a ticket type"
+                    )
+                };
+
+                let invocation = CommandInvocation::from_interaction(interaction).ok_or(
+                    "interaction has no channel or author"
+                )?;
+                client.defer_ephemeral_interaction(interaction).await?;
+                invocation.reply_with_components(
+                    &client,
+                    MessageContent::Text("Which kind of ticket should be opened?".to_string()),
+                    &[select_menu_row(menu)]
+                ).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
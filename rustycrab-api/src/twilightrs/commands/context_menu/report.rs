@@ -0,0 +1,71 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use rustycrab_model::modules::GuildModule;
+use twilight_model::{
+    application::command::CommandType,
+    gateway::payload::incoming::InteractionCreate,
+};
+
+use crate::twilightrs::{
+    commands::context::context_command::GuildConfigModel,
+    discord_client::DiscordClient,
+    bot::action_logs::message_logs::log_message_report,
+};
+
+use super::context_menu_command::{ ContextMenuCommand, ContextMenuTarget };
+
+/// Reports a message to the moderators, in the guild's action-log channels.
+pub struct ReportMessageMenuCommand {}
+
+#[async_trait]
+impl ContextMenuCommand for ReportMessageMenuCommand {
+    fn name(&self) -> &'static str {
+        "Report message"
+    }
+
+    fn kind(&self) -> CommandType {
+        CommandType::Message
+    }
+
+    fn module(&self) -> Option<GuildModule> {
+        Some(GuildModule::Logging)
+    }
+
+    async fn run(
+        &self,
+        client: DiscordClient,
+        config: &GuildConfigModel,
+        interaction: &Box<InteractionCreate>,
+        target: &ContextMenuTarget
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let (guild_id, reporter, message) = match
+            (interaction.guild_id, interaction.author(), target)
+        {
+            (Some(guild_id), Some(reporter), ContextMenuTarget::Message(message)) => {
+                (guild_id, reporter, message)
+            }
+            _ => {
+                return Ok(());
+            }
+        };
+
+        // answer first, looking up the log channels can take longer than Discord waits
+        client.defer_ephemeral_interaction(interaction).await?;
+        let key = match log_message_report(&client, guild_id, reporter, message).await {
+            Ok(true) => "report-sent",
+            Ok(false) => "report-not-configured",
+            Err(e) => {
+                eprintln!("Failed to report message {}: {:?}", message.id, e);
+                "report-failed"
+            }
+        };
+        let content = client.get_locale_string(&config.locale, key, None);
+        client.http
+            .interaction(interaction.application_id)
+            .update_response(&interaction.token)
+            .content(Some(&content))?.await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use rustycrab_model::modules::GuildModule;
+use twilight_model::{
+    application::command::CommandType,
+    gateway::payload::incoming::InteractionCreate,
+};
+
+use crate::twilightrs::{
+    commands::context::{
+        context_command::GuildConfigModel,
+        general::snipe::sniped_message_embed,
+    },
+    discord_client::DiscordClient,
+};
+
+use super::context_menu_command::{ ContextMenuCommand, ContextMenuTarget };
+
+/// Shows the last deleted message of the channel of the message.
+pub struct SnipeMenuCommand {}
+
+#[async_trait]
+impl ContextMenuCommand for SnipeMenuCommand {
+    fn name(&self) -> &'static str {
+        "Snipe in this channel"
+    }
+
+    fn kind(&self) -> CommandType {
+        CommandType::Message
+    }
+
+    fn module(&self) -> Option<GuildModule> {
+        Some(GuildModule::Snipe)
+    }
+
+    async fn run(
+        &self,
+        client: DiscordClient,
+        config: &GuildConfigModel,
+        interaction: &Box<InteractionCreate>,
+        target: &ContextMenuTarget
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let (channel_id, sniper) = match (target, interaction.author()) {
+            (ContextMenuTarget::Message(message), Some(sniper)) => (message.channel_id, sniper),
+            _ => {
+                return Ok(());
+            }
+        };
+
+        match sniped_message_embed(&client, channel_id, 0, sniper) {
+            Some(embed) => {
+                client.embed_interaction_reply(interaction, vec![embed], false).await?;
+            }
+            None => {
+                let content = client.get_locale_string(
+                    &config.locale,
+                    "command-snipe-invalid-position",
+                    None
+                );
+                client.ephemeral_interaction_reply(interaction, content).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use std::{ collections::{ HashMap, VecDeque }, sync::Mutex, time::{ Duration, Instant } };
+
+use rustycrab_model::cooldown::{ CommandCooldown, CooldownScope };
+
+use super::invocation::CommandInvocation;
+
+/// Number of tracked buckets above which expired buckets are dropped.
+const CLEANUP_THRESHOLD: usize = 10_000;
+
+/// Uses of a command, shared by a user, a channel or a guild depending on the cooldown scope.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct CooldownBucket {
+    command: String,
+    scope: CooldownScope,
+    id: u64,
+}
+
+/// Tracks recent command uses to enforce their cooldowns.
+///
+/// A cooldown allows `burst` uses within a sliding window of `window` seconds.
+#[derive(Default)]
+pub struct CommandCooldowns {
+    /// Window of each bucket's cooldown, with the times the command was used in it
+    uses: Mutex<HashMap<CooldownBucket, (Duration, VecDeque<Instant>)>>,
+}
+
+impl CommandCooldowns {
+    /// Records a use of the command if its cooldown allows it.
+    ///
+    /// Returns the time left before the command can be used again when it's on cooldown.
+    pub fn hit(
+        &self,
+        command: &str,
+        cooldown: &CommandCooldown,
+        msg: &CommandInvocation
+    ) -> Result<(), Duration> {
+        let id = match cooldown.scope {
+            CooldownScope::User => msg.author.id.get(),
+            CooldownScope::Channel => msg.channel_id.get(),
+            CooldownScope::Guild => msg.guild_id.map_or(msg.channel_id.get(), |id| id.get()),
+        };
+        let bucket = CooldownBucket { command: command.to_string(), scope: cooldown.scope, id };
+        let window = Duration::from_secs(cooldown.window);
+        let now = Instant::now();
+
+        let mut uses = self.uses.lock().unwrap();
+        if uses.len() > CLEANUP_THRESHOLD {
+            uses.retain(|_, (window, timestamps)| {
+                timestamps.back().map_or(false, |last| now.duration_since(*last) < *window)
+            });
+        }
+
+        let (bucket_window, timestamps) = uses
+            .entry(bucket)
+            .or_insert_with(|| (window, VecDeque::new()));
+        // guild overrides can change the window of an existing bucket
+        *bucket_window = window;
+        while timestamps.front().map_or(false, |first| now.duration_since(*first) >= window) {
+            timestamps.pop_front();
+        }
+
+        if timestamps.len() >= (cooldown.burst as usize) {
+            let oldest = timestamps.front().copied().unwrap_or(now);
+            return Err(window.saturating_sub(now.duration_since(oldest)));
+        }
+
+        timestamps.push_back(now);
+        Ok(())
+    }
+}
@@ -0,0 +1,217 @@
+use std::sync::atomic::{ AtomicBool, Ordering };
+
+use twilight_model::{
+    application::interaction::Interaction,
+    channel::{ Message, message::{ Component, Embed }, Attachment },
+    gateway::payload::incoming::MessageCreate,
+    id::{
+        Id,
+        marker::{ ApplicationMarker, ChannelMarker, GuildMarker, MessageMarker, RoleMarker },
+    },
+    user::User,
+};
+
+use crate::{
+    twilightrs::discord_client::{ DiscordClient, MessageContent },
+    utilities::app_error::BoxedError,
+};
+
+/// Where a command was invoked from.
+pub enum InvocationSource {
+    /// A prefix command, with the ID of the message that invoked it
+    Message(Id<MessageMarker>),
+    /// A slash command, answered through the interaction's token
+    Interaction {
+        application_id: Id<ApplicationMarker>,
+        token: String,
+    },
+}
+
+/// A command invocation, from either a message or a slash command interaction.
+///
+/// Commands reply through the invocation so they don't have to care where they were invoked
+/// from. Slash commands are deferred before they run, the first reply then edits the deferred
+/// response and the next ones are sent as followups. Prefix commands re-run after their message
+/// was edited edit their previous reply the same way.
+pub struct CommandInvocation {
+    pub author: User,
+    pub channel_id: Id<ChannelMarker>,
+    pub guild_id: Option<Id<GuildMarker>>,
+    /// Roles of the author in the guild
+    pub member_roles: Vec<Id<RoleMarker>>,
+    /// Files attached to the invoking message, always empty for slash commands
+    pub attachments: Vec<Attachment>,
+    pub source: InvocationSource,
+    /// What was typed after the prefix, `Text` arguments are read from it as typed. `None` for
+    /// slash commands
+    pub args_content: Option<String>,
+    /// Reply of the previous run of the command, when it's re-run after its message was edited
+    previous_reply: Option<Id<MessageMarker>>,
+    responded: AtomicBool,
+}
+
+impl CommandInvocation {
+    pub fn from_message(msg: &MessageCreate) -> Self {
+        Self {
+            author: msg.author.clone(),
+            channel_id: msg.channel_id,
+            guild_id: msg.guild_id,
+            member_roles: msg.member.as_ref().map_or(vec![], |member| member.roles.clone()),
+            attachments: msg.attachments.clone(),
+            source: InvocationSource::Message(msg.id),
+            args_content: None,
+            previous_reply: None,
+            responded: AtomicBool::new(false),
+        }
+    }
+
+    /// Sets what was typed after the prefix of the invoking message.
+    pub fn with_args_content(mut self, args_content: &str) -> Self {
+        self.args_content = Some(args_content.to_string());
+        self
+    }
+
+    /// Makes the first reply edit the reply of a previous run of the command instead.
+    pub fn with_previous_reply(mut self, previous_reply: Option<Id<MessageMarker>>) -> Self {
+        self.previous_reply = previous_reply;
+        self
+    }
+
+    /// Creates the invocation of a slash command, `None` if the interaction has no channel or author.
+    pub fn from_interaction(interaction: &Interaction) -> Option<Self> {
+        Some(Self {
+            author: interaction.author()?.clone(),
+            channel_id: interaction.channel.as_ref()?.id,
+            guild_id: interaction.guild_id,
+            member_roles: interaction.member.as_ref().map_or(vec![], |member| member.roles.clone()),
+            attachments: vec![],
+            source: InvocationSource::Interaction {
+                application_id: interaction.application_id,
+                token: interaction.token.clone(),
+            },
+            args_content: None,
+            previous_reply: None,
+            responded: AtomicBool::new(false),
+        })
+    }
+
+    /// ID of the invoking message, `None` for slash commands.
+    pub fn message_id(&self) -> Option<Id<MessageMarker>> {
+        match self.source {
+            InvocationSource::Message(message_id) => Some(message_id),
+            InvocationSource::Interaction { .. } => None,
+        }
+    }
+
+    /// Replies to the invoking message, or answers the interaction.
+    pub async fn reply(
+        &self,
+        client: &DiscordClient,
+        content: MessageContent
+    ) -> Result<Message, BoxedError> {
+        self.respond(client, content, &[], true).await
+    }
+
+    /// Replies with message components, like pagination buttons.
+    pub async fn reply_with_components(
+        &self,
+        client: &DiscordClient,
+        content: MessageContent,
+        components: &[Component]
+    ) -> Result<Message, BoxedError> {
+        self.respond(client, content, components, true).await
+    }
+
+    /// Sends a message to the channel without replying, or answers the interaction.
+    pub async fn send(
+        &self,
+        client: &DiscordClient,
+        content: MessageContent
+    ) -> Result<Message, BoxedError> {
+        self.respond(client, content, &[], false).await
+    }
+
+    /// Sends message components to the channel without replying, or answers the interaction.
+    pub async fn send_with_components(
+        &self,
+        client: &DiscordClient,
+        content: MessageContent,
+        components: &[Component]
+    ) -> Result<Message, BoxedError> {
+        self.respond(client, content, components, false).await
+    }
+
+    async fn respond(
+        &self,
+        client: &DiscordClient,
+        content: MessageContent,
+        components: &[Component],
+        as_reply: bool
+    ) -> Result<Message, BoxedError> {
+        let (text, embeds): (Option<String>, Vec<Embed>) = content.into_parts();
+        if text.is_none() && embeds.is_empty() {
+            return Err("No content provided for reply".into());
+        }
+
+        match &self.source {
+            InvocationSource::Message(message_id) => {
+                let first_response = !self.responded.swap(true, Ordering::SeqCst);
+                if first_response {
+                    if let Some(reply_id) = self.previous_reply {
+                        // the previous reply may have been deleted, then a new one is sent
+                        let update = client.http
+                            .update_message(self.channel_id, reply_id)
+                            .content(text.as_deref())?
+                            .embeds(Some(&embeds))?
+                            .components(Some(components))?.await;
+                        if let Ok(response) = update {
+                            let reply = response.model().await?;
+                            client.command_replies.record(*message_id, reply.id);
+                            return Ok(reply);
+                        }
+                    }
+                }
+
+                let mut create_message = client.http.create_message(self.channel_id);
+                if as_reply {
+                    create_message = create_message.reply(*message_id);
+                }
+                if let Some(text) = &text {
+                    create_message = create_message.content(text)?;
+                }
+
+                let reply = create_message
+                    .embeds(&embeds)?
+                    .components(components)?.await?
+                    .model().await?;
+                if first_response {
+                    client.command_replies.record(*message_id, reply.id);
+                }
+
+                Ok(reply)
+            }
+            InvocationSource::Interaction { application_id, token } => {
+                let interaction_client = client.http.interaction(*application_id);
+
+                // the first answer replaces the deferred response
+                if !self.responded.swap(true, Ordering::SeqCst) {
+                    return Ok(
+                        interaction_client
+                            .update_response(token)
+                            .content(text.as_deref())?
+                            .embeds(Some(&embeds))?
+                            .components(Some(components))?.await?
+                            .model().await?
+                    );
+                }
+
+                let mut followup = interaction_client.create_followup(token);
+                if let Some(text) = &text {
+                    followup = followup.content(text)?;
+                }
+
+                Ok(followup.embeds(&embeds)?.components(components)?.await?.model().await?)
+            }
+        }
+    }
+}
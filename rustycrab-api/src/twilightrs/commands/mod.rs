@@ -1,5 +1,11 @@
 pub mod context;
 pub mod slash;
+pub mod context_menu;
+pub mod invocation;
+pub mod cooldowns;
+pub mod command_replies;
+pub mod rules;
+pub mod autocomplete;
 
 use twilight_model::gateway::payload::incoming::MessageCreate;
 use std::{ error::Error, sync::Arc };
@@ -8,18 +14,21 @@ use crate::database::bot_guild_configurations;
 
 use super::{ discord_client::DiscordClient, dispatchers::ClientDispatchers };
 
+use self::invocation::CommandInvocation;
+
 pub async fn context_commands_handler(
     client: DiscordClient,
     config: &bot_guild_configurations::Model,
     dispatchers: &Arc<ClientDispatchers>,
     msg: &MessageCreate,
+    args_content: &str,
     command_name: &str,
     command_args: &[&str]
 ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
     dispatchers.context_commands.dispatch_command(
         client,
         config,
-        msg,
+        &CommandInvocation::from_message(msg).with_args_content(args_content),
         command_name,
         command_args
     ).await;
@@ -0,0 +1,104 @@
+use rustycrab_model::modules::GuildModule;
+
+use crate::database::command_rules::Model as CommandRuleModel;
+
+use super::invocation::CommandInvocation;
+
+/// Why a command rule denied an invocation.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RuleDenial {
+    Channel,
+    Role,
+    User,
+}
+
+impl RuleDenial {
+    pub fn locale_key(&self) -> &'static str {
+        match self {
+            RuleDenial::Channel => "command-rule-channel",
+            RuleDenial::Role => "command-rule-role",
+            RuleDenial::User => "command-rule-user",
+        }
+    }
+}
+
+/// Checks the guild's command rules for an invocation of a command.
+///
+/// A rule targets a command, which includes its subcommands, a whole category of the help or a
+/// whole module. Rules are evaluated by subject, the first subject deciding wins:
+///
+/// 1. user rules, an allow rule for the author lets them use the command whatever the other
+///    rules say
+/// 2. role rules, denied if the author has a denied role, or if there are allow rules and the
+///    author has none of the allowed roles
+/// 3. channel rules, same as roles with the invoking channel
+pub fn check_command_rules(
+    rules: &[CommandRuleModel],
+    command_name: &str,
+    category_name: &str,
+    module: Option<GuildModule>,
+    msg: &CommandInvocation
+) -> Result<(), RuleDenial> {
+    let rules: Vec<&CommandRuleModel> = rules
+        .iter()
+        .filter(|rule| rule_targets(rule, command_name, category_name, module))
+        .collect();
+
+    let author_id = msg.author.id.to_string();
+    let user_rule = rules
+        .iter()
+        .find(|rule| rule.subject == "user" && rule.subject_id == author_id);
+    if let Some(rule) = user_rule {
+        return if rule.allow != 0 { Ok(()) } else { Err(RuleDenial::User) };
+    }
+
+    let role_ids: Vec<String> = msg.member_roles
+        .iter()
+        .map(|role_id| role_id.to_string())
+        .collect();
+    if !subject_allows(&rules, "role", |id| role_ids.iter().any(|role_id| role_id == id)) {
+        return Err(RuleDenial::Role);
+    }
+
+    let channel_id = msg.channel_id.to_string();
+    if !subject_allows(&rules, "channel", |id| id == channel_id) {
+        return Err(RuleDenial::Channel);
+    }
+
+    Ok(())
+}
+
+fn rule_targets(
+    rule: &CommandRuleModel,
+    command_name: &str,
+    category_name: &str,
+    module: Option<GuildModule>
+) -> bool {
+    match rule.target.as_str() {
+        "command" => {
+            command_name == rule.name || command_name.starts_with(&format!("{} ", rule.name))
+        }
+        // rule names are saved lowercase
+        "category" => category_name.to_lowercase() == rule.name,
+        "module" => module.map_or(false, |module| module.name() == rule.name),
+        _ => false,
+    }
+}
+
+fn subject_allows(
+    rules: &[&CommandRuleModel],
+    subject: &str,
+    matches: impl Fn(&str) -> bool
+) -> bool {
+    let rules: Vec<&&CommandRuleModel> = rules
+        .iter()
+        .filter(|rule| rule.subject == subject)
+        .collect();
+
+    if rules.iter().any(|rule| rule.allow == 0 && matches(&rule.subject_id)) {
+        return false;
+    }
+
+    let mut allow_rules = rules.iter().filter(|rule| rule.allow != 0).peekable();
+    allow_rules.peek().is_none() || allow_rules.any(|rule| matches(&rule.subject_id))
+}
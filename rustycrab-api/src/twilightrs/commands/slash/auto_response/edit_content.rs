@@ -0,0 +1,159 @@
+use std::error::Error;
+
+use fluent_bundle::FluentArgs;
+use twilight_model::{
+    gateway::payload::incoming::InteractionCreate,
+    application::{
+        command::{ CommandOption, CommandOptionChoice, CommandOptionType },
+        interaction::application_command::{ CommandData, CommandOptionValue },
+    },
+    channel::message::component::{ TextInput, TextInputStyle },
+    guild::Permissions,
+};
+
+use async_trait::async_trait;
+use rustycrab_model::modules::GuildModule;
+
+use crate::{
+    twilightrs::{
+        commands::{
+            slash::slash_command::SlashCommand,
+            autocomplete::FocusedOption,
+            context::admin::auto_response::utils::trigger_choices,
+            invocation::CommandInvocation,
+        },
+        discord_client::DiscordClient,
+        events::interaction_handlers::modals::ModalEvents,
+        utils::modals::text_input,
+    },
+    queries::{ auto_responses_queries::AutoResponsesQueries, message_queries::MessageQueries },
+};
+
+/// Longest message content.
+const MAX_CONTENT_LENGTH: u16 = 2000;
+/// Longest embed description.
+const MAX_DESCRIPTION_LENGTH: u16 = 4000;
+
+/// Edits the content of an auto-response in a modal, instead of long `autores` commands.
+pub struct EditAutoResContentSlashCommand {}
+
+#[async_trait]
+impl SlashCommand for EditAutoResContentSlashCommand {
+    fn name(&self) -> &'static str {
+        "autoresedit"
+    }
+
+    fn description(&self) -> &'static str {
+        "edit the message and embed text of an auto-response"
+    }
+
+    fn command_options(&self) -> Vec<CommandOption> {
+        vec![CommandOption {
+            autocomplete: Some(true),
+            channel_types: None,
+            choices: None,
+            description: "trigger of the auto-response".to_string(),
+            description_localizations: None,
+            kind: CommandOptionType::String,
+            max_length: None,
+            max_value: None,
+            min_length: None,
+            min_value: None,
+            name: "trigger".to_string(),
+            name_localizations: None,
+            options: None,
+            required: Some(true),
+        }]
+    }
+
+    fn permissions(&self) -> Option<Permissions> {
+        Some(Permissions::ADMINISTRATOR)
+    }
+
+    fn module(&self) -> Option<GuildModule> {
+        Some(GuildModule::AutoResponses)
+    }
+
+    async fn autocomplete(
+        &self,
+        client: DiscordClient,
+        interaction: &Box<InteractionCreate>,
+        option: &FocusedOption
+    ) -> Result<Vec<CommandOptionChoice>, Box<dyn Error + Send + Sync + 'static>> {
+        let invocation = CommandInvocation::from_interaction(interaction).ok_or(
+            "interaction has no channel or author"
+        )?;
+
+        trigger_choices(&client, &invocation, option).await
+    }
+
+    async fn run(
+        &self,
+        client: DiscordClient,
+        interaction: &Box<InteractionCreate>,
+        command_data: &Box<CommandData>
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let guild_id = interaction.guild_id.ok_or("command-guildonly")?;
+        let trigger = command_data.options
+            .iter()
+            .find_map(|option| {
+                match &option.value {
+                    CommandOptionValue::String(value) if option.name == "trigger" => Some(value),
+                    _ => None,
+                }
+            })
+            .ok_or("missing trigger option")?;
+
+        let autores = match
+            AutoResponsesQueries::find_by_trigger(
+                &client.db,
+                &client.bot_id.to_string(),
+                &guild_id.to_string(),
+                trigger
+            ).await
+        {
+            Ok(autores) => autores,
+            Err(_) => {
+                let config = client.get_guild_config(&guild_id).await?;
+                let mut args = FluentArgs::new();
+                args.set("trigger", trigger.to_string());
+                let content = client.get_locale_string(
+                    &config.locale,
+                    "autores-notfound",
+                    Some(&args)
+                );
+                client.ephemeral_interaction_reply(interaction, content).await?;
+                return Ok(());
+            }
+        };
+
+        let message = MessageQueries::fetch_message_response(
+            &client.db,
+            autores.response_id
+        ).await?;
+        let description = message.embed.and_then(|embed| embed.description);
+        let inputs = vec![
+            TextInput {
+                max_length: Some(MAX_CONTENT_LENGTH),
+                required: Some(false),
+                value: message.content.filter(|content| !content.is_empty()),
+                ..text_input("content", "Message", TextInputStyle::Paragraph)
+            },
+            TextInput {
+                max_length: Some(MAX_DESCRIPTION_LENGTH),
+                required: Some(false),
+                value: description.filter(|description| !description.is_empty()),
+                ..text_input("description", "Embed description", TextInputStyle::Paragraph)
+            }
+        ];
+
+        client.show_modal(
+            interaction,
+            ModalEvents::AutoResContent.custom_id(&[&autores.id.to_string()]),
+            &format!("Edit {}", autores.trigger),
+            inputs
+        ).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,174 @@
+//! Syncing of the registered application commands with the bot's commands.
+//!
+//! The registered commands are fetched and compared with the local definitions, then only the
+//! commands that changed are created, updated or deleted. Commands can be synced globally or to
+//! a single guild, for development guilds or to leave out the guild's disabled modules.
+
+use std::{ error::Error, fmt };
+
+use rustycrab_model::response::bots::ResponseCommandSync;
+use twilight_http::{ request::Request, routing::Route };
+use twilight_model::{
+    application::command::{ Command, CommandOption },
+    id::{ Id, marker::{ ApplicationMarker, GuildMarker } },
+};
+
+use crate::twilightrs::discord_client::DiscordClient;
+
+/// Where commands are registered.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CommandScope {
+    Global,
+    Guild(Id<GuildMarker>),
+}
+
+impl fmt::Display for CommandScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommandScope::Global => write!(f, "global"),
+            CommandScope::Guild(guild_id) => write!(f, "{}", guild_id),
+        }
+    }
+}
+
+/// Registers, updates and deletes commands so the registered commands match `commands`.
+///
+/// A command Discord rejects is reported in `failed`, the other commands are still synced.
+pub async fn sync_commands(
+    client: &DiscordClient,
+    scope: CommandScope,
+    commands: &[Command]
+) -> Result<ResponseCommandSync, Box<dyn Error + Send + Sync + 'static>> {
+    let application_id = client.http.current_user_application().await?.model().await?.id;
+    let interaction_client = client.http.interaction(application_id);
+
+    let registered: Vec<Command> = match scope {
+        CommandScope::Global => interaction_client.global_commands().await?.models().await?,
+        CommandScope::Guild(guild_id) => {
+            interaction_client.guild_commands(guild_id).await?.models().await?
+        }
+    };
+
+    let mut report = ResponseCommandSync {
+        scope: scope.to_string(),
+        created: Vec::new(),
+        updated: Vec::new(),
+        deleted: Vec::new(),
+        unchanged: 0,
+        failed: Vec::new(),
+    };
+
+    for command in commands {
+        let existing = registered
+            .iter()
+            .find(|registered| registered.name == command.name && registered.kind == command.kind);
+
+        match existing {
+            Some(existing) if !command_changed(command, existing, scope) => {
+                report.unchanged += 1;
+            }
+            // creating a command with the name of a registered one overwrites it
+            Some(_) => {
+                match create_command(client, application_id, scope, command).await {
+                    Ok(_) => report.updated.push(command.name.clone()),
+                    Err(e) => report.failed.push((command.name.clone(), e.to_string())),
+                }
+            }
+            None => {
+                match create_command(client, application_id, scope, command).await {
+                    Ok(_) => report.created.push(command.name.clone()),
+                    Err(e) => report.failed.push((command.name.clone(), e.to_string())),
+                }
+            }
+        }
+    }
+
+    for registered_command in &registered {
+        let still_exists = commands
+            .iter()
+            .any(|command| {
+                command.name == registered_command.name && command.kind == registered_command.kind
+            });
+        if still_exists {
+            continue;
+        }
+
+        if let Some(command_id) = registered_command.id {
+            let result = match scope {
+                CommandScope::Global => interaction_client.delete_global_command(command_id).await,
+                CommandScope::Guild(guild_id) => {
+                    interaction_client.delete_guild_command(guild_id, command_id).await
+                }
+            };
+            match result {
+                Ok(_) => report.deleted.push(registered_command.name.clone()),
+                Err(e) => report.failed.push((registered_command.name.clone(), e.to_string())),
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+/// Creates a command, or overwrites the registered command with the same name and type.
+async fn create_command(
+    client: &DiscordClient,
+    application_id: Id<ApplicationMarker>,
+    scope: CommandScope,
+    command: &Command
+) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+    let route = match scope {
+        CommandScope::Global =>
+            Route::CreateGlobalCommand {
+                application_id: application_id.get(),
+            },
+        CommandScope::Guild(guild_id) =>
+            Route::CreateGuildCommand {
+                application_id: application_id.get(),
+                guild_id: guild_id.get(),
+            },
+    };
+    // every command type has its own request builder, the command is sent as a whole instead
+    let request = Request::builder(&route).json(command).build()?;
+    client.http.request::<Command>(request).await?;
+
+    Ok(())
+}
+
+/// Compares a local command with its registered version.
+fn command_changed(local: &Command, registered: &Command, scope: CommandScope) -> bool {
+    // guild commands can't be used in DMs, Discord doesn't keep their DM permission
+    let dm_permission_changed =
+        scope == CommandScope::Global &&
+        local.dm_permission.unwrap_or(true) != registered.dm_permission.unwrap_or(true);
+
+    local.description != registered.description ||
+        local.default_member_permissions != registered.default_member_permissions ||
+        local.nsfw.unwrap_or(false) != registered.nsfw.unwrap_or(false) ||
+        dm_permission_changed ||
+        normalize_options(&local.options) != normalize_options(&registered.options)
+}
+
+/// Discord leaves out the empty and default fields of the options it returns.
+fn normalize_options(options: &[CommandOption]) -> Vec<CommandOption> {
+    options
+        .iter()
+        .map(|option| {
+            let mut option = option.clone();
+            if option.required == Some(false) {
+                option.required = None;
+            }
+            if option.autocomplete == Some(false) {
+                option.autocomplete = None;
+            }
+            option.choices = option.choices.filter(|choices| !choices.is_empty());
+            option.channel_types = option.channel_types.filter(|types| !types.is_empty());
+            option.options = option.options
+                .map(|options| normalize_options(&options))
+                .filter(|options| !options.is_empty());
+            option.description_localizations = None;
+            option.name_localizations = None;
+            option
+        })
+        .collect()
+}
@@ -0,0 +1,101 @@
+use std::error::Error;
+
+use twilight_model::{
+    gateway::payload::incoming::InteractionCreate,
+    application::{
+        command::{ CommandOption, CommandOptionType },
+        interaction::application_command::{ CommandData, CommandOptionValue },
+    },
+    channel::message::component::{ TextInput, TextInputStyle },
+};
+
+use async_trait::async_trait;
+use rustycrab_model::modules::GuildModule;
+
+use crate::twilightrs::{
+    commands::slash::slash_command::SlashCommand,
+    discord_client::DiscordClient,
+    events::interaction_handlers::modals::ModalEvents,
+    utils::{ afk::set_afk_from_interaction, modals::text_input },
+};
+
+/// Longest AFK message.
+const MAX_AFK_MESSAGE_LENGTH: u16 = 200;
+
+/// Sets an AFK status, asking for the message in a modal when it's left out.
+///
+/// Replaces the slash command of the `afk` context command, which can't show a modal.
+pub struct AfkSlashCommand {}
+
+#[async_trait]
+impl SlashCommand for AfkSlashCommand {
+    fn name(&self) -> &'static str {
+        "afk"
+    }
+
+    fn description(&self) -> &'static str {
+        "set your AFK status"
+    }
+
+    fn command_options(&self) -> Vec<CommandOption> {
+        vec![CommandOption {
+            autocomplete: None,
+            channel_types: None,
+            choices: None,
+            description: "message shown when you are mentioned".to_string(),
+            description_localizations: None,
+            kind: CommandOptionType::String,
+            max_length: Some(MAX_AFK_MESSAGE_LENGTH),
+            max_value: None,
+            min_length: None,
+            min_value: None,
+            name: "message".to_string(),
+            name_localizations: None,
+            options: None,
+            required: Some(false),
+        }]
+    }
+
+    fn module(&self) -> Option<GuildModule> {
+        Some(GuildModule::Afk)
+    }
+
+    async fn run(
+        &self,
+        client: DiscordClient,
+        interaction: &Box<InteractionCreate>,
+        command_data: &Box<CommandData>
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let guild_id = interaction.guild_id.ok_or("command-guildonly")?;
+        let message = command_data.options.iter().find_map(|option| {
+            match &option.value {
+                CommandOptionValue::String(value) if option.name == "message" => {
+                    Some(value.clone())
+                }
+                _ => None,
+            }
+        });
+
+        match message {
+            Some(message) => {
+                let config = client.get_guild_config(&guild_id).await?;
+                set_afk_from_interaction(&client, &config.locale, interaction, Some(message)).await
+            }
+            None => {
+                let input = TextInput {
+                    max_length: Some(MAX_AFK_MESSAGE_LENGTH),
+                    required: Some(false),
+                    ..text_input("message", "AFK message", TextInputStyle::Short)
+                };
+                client.show_modal(
+                    interaction,
+                    ModalEvents::AfkMessage.custom_id(&[]),
+                    "Going AFK",
+                    vec![input]
+                ).await?;
+
+                Ok(())
+            }
+        }
+    }
+}
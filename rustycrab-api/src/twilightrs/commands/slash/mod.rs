@@ -1,4 +1,7 @@
 pub mod slash_command_dispatcher;
 pub mod slash_command;
+pub mod command_sync;
 
 pub mod tickets;
+pub mod auto_response;
+pub mod general;
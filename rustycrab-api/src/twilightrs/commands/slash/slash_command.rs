@@ -6,9 +6,17 @@ use twilight_model::{
 };
 
 use async_trait::async_trait;
-use twilight_model::{ application::command::{ CommandOption, Command }, guild::Permissions };
+use rustycrab_model::modules::GuildModule;
+use twilight_model::{
+    application::command::{ CommandOption, CommandOptionChoice, Command, CommandType },
+    guild::Permissions,
+    id::Id,
+};
 
-use crate::twilightrs::discord_client::DiscordClient;
+use crate::twilightrs::{
+    discord_client::DiscordClient,
+    commands::autocomplete::FocusedOption,
+};
 
 #[async_trait]
 pub trait SlashCommand: Send + Sync {
@@ -30,6 +38,30 @@ pub trait SlashCommand: Send + Sync {
         false
     }
 
+    /// The guild module the command belongs to, the command can't be used while it's disabled.
+    fn module(&self) -> Option<GuildModule> {
+        None
+    }
+
+    /// Builds the command, to register it together with the other commands.
+    fn to_command(&self) -> Command {
+        Command {
+            application_id: None,
+            default_member_permissions: self.permissions(),
+            dm_permission: None,
+            description: self.description().to_string(),
+            description_localizations: None,
+            guild_id: None,
+            id: None,
+            kind: CommandType::ChatInput,
+            name: self.name().to_string(),
+            name_localizations: None,
+            nsfw: Some(self.nsfw()),
+            options: self.command_options(),
+            version: Id::new(1),
+        }
+    }
+
     async fn register(
         &self,
         client: DiscordClient
@@ -53,6 +85,19 @@ pub trait SlashCommand: Send + Sync {
         Ok(command.await?.model().await?)
     }
 
+    /// Suggestions for the focused option, while it's being typed.
+    ///
+    /// Only called for options with `autocomplete` enabled, at most 25 choices are kept.
+    #[allow(unused_variables)]
+    async fn autocomplete(
+        &self,
+        client: DiscordClient,
+        interaction: &Box<InteractionCreate>,
+        option: &FocusedOption
+    ) -> Result<Vec<CommandOptionChoice>, Box<dyn Error + Send + Sync + 'static>> {
+        Ok(Vec::new())
+    }
+
     async fn exec(
         &self,
         client: DiscordClient,
@@ -1,13 +1,39 @@
-use std::{ error::Error, sync::Arc, collections::HashMap };
+use std::{ error::Error, collections::HashMap, sync::Arc, time::Instant };
 
+use rustycrab_model::response::bots::ResponseCommandSync;
 use twilight_model::{
-    application::interaction::application_command::CommandData,
+    application::{
+        command::Command,
+        interaction::application_command::{ CommandData, CommandOptionValue },
+    },
     gateway::payload::incoming::InteractionCreate,
 };
 
-use crate::twilightrs::discord_client::DiscordClient;
+use crate::{
+    twilightrs::{
+        discord_client::DiscordClient,
+        commands::context::{
+            context_command::GuildConfigModel,
+            context_command_dispatcher::ContextCommandDispatcher,
+        },
+        commands::context_menu::context_menu_dispatcher::ContextMenuDispatcher,
+        utils::{ module_disabled_message, record_command_usage },
+        commands::autocomplete::{ focused_option, MAX_AUTOCOMPLETE_CHOICES },
+    },
+    metrics::metrics,
+    queries::{
+        command_usages_queries::CommandUsageRecord,
+        guild_config_queries::GuildConfigQueries,
+    },
+};
 
-use super::{ slash_command::SlashCommand, tickets::close_ticket::CloseTicketSlashCommand };
+use super::{
+    command_sync::{ sync_commands, CommandScope },
+    slash_command::SlashCommand,
+    tickets::{ close_ticket::CloseTicketSlashCommand, add_team::AddTeamSlashCommand },
+    auto_response::edit_content::EditAutoResContentSlashCommand,
+    general::afk::AfkSlashCommand,
+};
 
 pub struct SlashCommandDispatcher {
     pub commands: HashMap<String, Box<dyn SlashCommand>>,
@@ -16,7 +42,12 @@ pub struct SlashCommandDispatcher {
 impl SlashCommandDispatcher {
     pub fn new() -> Self {
         let mut commands: HashMap<String, Box<dyn SlashCommand>> = HashMap::new();
-        let slash_commands: Vec<Box<dyn SlashCommand>> = vec![Box::new(CloseTicketSlashCommand {})];
+        let slash_commands: Vec<Box<dyn SlashCommand>> = vec![
+            Box::new(CloseTicketSlashCommand {}),
+            Box::new(AddTeamSlashCommand {}),
+            Box::new(EditAutoResContentSlashCommand {}),
+            Box::new(AfkSlashCommand {})
+        ];
         for command in slash_commands {
             commands.entry(command.name().to_string()).or_insert(command);
         }
@@ -25,30 +56,151 @@ impl SlashCommandDispatcher {
         }
     }
 
-    pub async fn register_commands(
+    /// The slash commands, a slash command for every context command without one, and the
+    /// context menu commands.
+    ///
+    /// With `module_flags`, the commands of the modules disabled by the flags are left out.
+    pub fn all_commands(
         &self,
-        client: DiscordClient
-    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
-        for (_, command) in &self.commands {
-            if let Ok(register_command) = command.register(Arc::clone(&client)).await {
-                println!("Registered command {}", register_command.name);
-            } else {
-                eprintln!("Failed to register command {}", command.name());
+        context_commands: &ContextCommandDispatcher,
+        context_menus: &ContextMenuDispatcher,
+        module_flags: Option<i32>
+    ) -> Vec<Command> {
+        let mut commands: Vec<Command> = self.commands
+            .values()
+            .filter(|command| {
+                match (command.module(), module_flags) {
+                    (Some(module), Some(flags)) => module.is_enabled(flags),
+                    _ => true,
+                }
+            })
+            .map(|command| command.to_command())
+            .collect();
+        // a slash command replaces the context command of the same name, like `afk`
+        for command in context_commands.slash_commands(module_flags) {
+            if !commands.iter().any(|registered| registered.name == command.name) {
+                commands.push(command);
             }
         }
+        commands.extend(context_menus.menu_commands(module_flags));
 
-        Ok(())
+        commands
+    }
+
+    /// Syncs the registered commands with the bot's commands, globally or in a single guild.
+    ///
+    /// Guild commands leave out the modules disabled in the guild.
+    pub async fn sync_commands(
+        &self,
+        client: &DiscordClient,
+        context_commands: &ContextCommandDispatcher,
+        context_menus: &ContextMenuDispatcher,
+        scope: CommandScope
+    ) -> Result<ResponseCommandSync, Box<dyn Error + Send + Sync + 'static>> {
+        let module_flags = match scope {
+            CommandScope::Global => None,
+            CommandScope::Guild(guild_id) => {
+                let config = GuildConfigQueries::get_or_create_config(
+                    &client.db,
+                    &client.bot_id.to_string(),
+                    &guild_id.to_string()
+                ).await?;
+                Some(config.module_flags)
+            }
+        };
+
+        let commands = self.all_commands(context_commands, context_menus, module_flags);
+        sync_commands(client, scope, &commands).await
     }
 
     pub async fn dispatch(
         &self,
         client: DiscordClient,
+        config: &GuildConfigModel,
         interaction: &Box<InteractionCreate>,
         command_data: &Box<CommandData>
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         if let Some(command) = self.commands.get(&command_data.name) {
-            let _ = command.exec(client, interaction, command_data).await;
+            if let Some(module) = command.module() {
+                if !module.is_enabled(config.module_flags) {
+                    let content = module_disabled_message(&client, config, module);
+                    client.ephemeral_interaction_reply(interaction, content).await?;
+                    return Ok(());
+                }
+            }
+
+            let bot_label = client.bot_id.to_string();
+            let started_at = Instant::now();
+            let result = command.exec(Arc::clone(&client), interaction, command_data).await;
+            let duration = started_at.elapsed();
+            metrics().record_command(&bot_label, "slash", command.name(), duration, result.is_err());
+            if let (Some(channel), Some(user_id)) = (&interaction.channel, interaction.author_id()) {
+                record_command_usage(&client, config, CommandUsageRecord {
+                    command: command_path(command_data),
+                    kind: "slash",
+                    channel_id: channel.id.to_string(),
+                    user_id: user_id.to_string(),
+                    duration,
+                    success: result.is_ok(),
+                });
+            }
         }
         Ok(())
     }
+
+    /// Answers an autocomplete interaction with the command's suggestions for the focused option.
+    pub async fn autocomplete(
+        &self,
+        client: DiscordClient,
+        config: &GuildConfigModel,
+        interaction: &Box<InteractionCreate>,
+        command_data: &Box<CommandData>
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let command = match self.commands.get(&command_data.name) {
+            Some(command) => command,
+            None => {
+                return Ok(());
+            }
+        };
+
+        let module_enabled = command
+            .module()
+            .map_or(true, |module| module.is_enabled(config.module_flags));
+        let mut choices = match focused_option(&command_data.options) {
+            Some(option) if module_enabled => {
+                let result = command.autocomplete(Arc::clone(&client), interaction, &option).await;
+                result.unwrap_or_else(|e| {
+                    eprintln!("Error autocompleting {}: {e}", command_data.name);
+                    Vec::new()
+                })
+            }
+            _ => Vec::new(),
+        };
+        choices.truncate(MAX_AUTOCOMPLETE_CHOICES);
+        client.autocomplete_interaction_reply(interaction, choices).await?;
+
+        Ok(())
+    }
+}
+
+/// Name of the command followed by its subcommand group and subcommand, like `ticket add team`.
+fn command_path(command_data: &CommandData) -> String {
+    let mut path = command_data.name.clone();
+    let mut options = &command_data.options;
+    // a subcommand is the only option at its level
+    while let Some(option) = options.first() {
+        match &option.value {
+            | CommandOptionValue::SubCommand(sub_options)
+            | CommandOptionValue::SubCommandGroup(sub_options) => {
+                path.push(' ');
+                path.push_str(&option.name);
+                options = sub_options;
+            }
+            _ => {
+                break;
+            }
+        }
+    }
+
+    path
 }
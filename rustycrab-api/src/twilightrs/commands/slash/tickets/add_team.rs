@@ -0,0 +1,154 @@
+use std::error::Error;
+
+use twilight_model::{
+    gateway::payload::incoming::InteractionCreate,
+    application::{
+        command::{ CommandOption, CommandOptionChoice, CommandOptionType },
+        interaction::application_command::{ CommandData, CommandOptionValue },
+    },
+    guild::Permissions,
+};
+
+use async_trait::async_trait;
+use rustycrab_model::{
+    modules::GuildModule,
+    response::ticket::support_team::ResponseTicketSupportTeam,
+};
+
+use crate::{
+    twilightrs::{
+        commands::{
+            slash::slash_command::SlashCommand,
+            autocomplete::{ FocusedOption, string_choice, matches_typed },
+        },
+        discord_client::DiscordClient,
+        bot::tickets::add_support_team_to_channel,
+    },
+    queries::tickets_system::{
+        ticket_queries::TicketQueries,
+        ticket_support_team_queries::TicketSupportTeamQueries,
+    },
+};
+
+pub struct AddTeamSlashCommand {}
+
+#[async_trait]
+impl SlashCommand for AddTeamSlashCommand {
+    fn name(&self) -> &'static str {
+        "addteam"
+    }
+
+    fn description(&self) -> &'static str {
+        "add a support team to the current ticket"
+    }
+
+    fn command_options(&self) -> Vec<CommandOption> {
+        vec![CommandOption {
+            autocomplete: Some(true),
+            channel_types: None,
+            choices: None,
+            description: "name of the support team".to_string(),
+            description_localizations: None,
+            kind: CommandOptionType::String,
+            max_length: None,
+            max_value: None,
+            min_length: None,
+            min_value: None,
+            name: "team".to_string(),
+            name_localizations: None,
+            options: None,
+            required: Some(true),
+        }]
+    }
+
+    fn permissions(&self) -> Option<Permissions> {
+        Some(Permissions::MANAGE_CHANNELS)
+    }
+
+    fn module(&self) -> Option<GuildModule> {
+        Some(GuildModule::Tickets)
+    }
+
+    async fn autocomplete(
+        &self,
+        client: DiscordClient,
+        interaction: &Box<InteractionCreate>,
+        option: &FocusedOption
+    ) -> Result<Vec<CommandOptionChoice>, Box<dyn Error + Send + Sync + 'static>> {
+        let guild_id = interaction.guild_id.ok_or("command-guildonly")?;
+
+        Ok(
+            TicketSupportTeamQueries::find_guild_support_teams_by_discord_ids(
+                &client.db,
+                &client.bot_id.to_string(),
+                &guild_id.to_string()
+            ).await?
+                .iter()
+                .filter(|team| matches_typed(&team.name, &option.value))
+                .map(|team| string_choice(&team.name, &team.name))
+                .collect()
+        )
+    }
+
+    async fn run(
+        &self,
+        client: DiscordClient,
+        interaction: &Box<InteractionCreate>,
+        command_data: &Box<CommandData>
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let (guild_id, channel) = match (&interaction.guild_id, &interaction.channel) {
+            (Some(guild_id), Some(channel)) => (guild_id, channel),
+            _ => {
+                return Ok(());
+            }
+        };
+
+        let team_name = command_data.options
+            .iter()
+            .find_map(|option| {
+                match &option.value {
+                    CommandOptionValue::String(value) if option.name == "team" => Some(value),
+                    _ => None,
+                }
+            })
+            .ok_or("missing team option")?;
+
+        let ticket = TicketQueries::find_by_channel_discord_id(
+            &client.db,
+            channel.id.to_string()
+        ).await;
+        if ticket.is_err() {
+            client.ephemeral_interaction_reply(
+                interaction,
+                "This is not a ticket".to_string()
+            ).await?;
+            return Ok(());
+        }
+
+        let team = match
+            TicketSupportTeamQueries::find_support_team_by_name(
+                &client.db,
+                &client.bot_id.to_string(),
+                &guild_id.to_string(),
+                team_name
+            ).await
+        {
+            Ok(team) => ResponseTicketSupportTeam::from(team),
+            Err(_) => {
+                client.ephemeral_interaction_reply(
+                    interaction,
+                    format!("Support team `{}` not found", team_name)
+                ).await?;
+                return Ok(());
+            }
+        };
+
+        add_support_team_to_channel(&client, channel.id, &team).await?;
+        client.ephemeral_interaction_reply(
+            interaction,
+            format!("Support team `{}` added to this ticket", team.name)
+        ).await?;
+
+        Ok(())
+    }
+}
@@ -8,6 +8,7 @@ use twilight_model::{
 };
 
 use async_trait::async_trait;
+use rustycrab_model::modules::GuildModule;
 
 use crate::{
     twilightrs::{
@@ -30,6 +31,10 @@ impl SlashCommand for CloseTicketSlashCommand {
         "close the current ticket"
     }
 
+    fn module(&self) -> Option<GuildModule> {
+        Some(GuildModule::Tickets)
+    }
+
     async fn run(
         &self,
         client: DiscordClient,
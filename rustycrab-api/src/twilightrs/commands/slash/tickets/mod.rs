@@ -1 +1,2 @@
 pub mod close_ticket;
+pub mod add_team;
@@ -2,17 +2,22 @@ use fluent::FluentArgs;
 
 use rustycrab_model::afk::UserAfkStatus;
 use sea_orm::DatabaseConnection;
-use songbird::Songbird;
+use songbird::{ shards::TwilightMap, Songbird };
 
-use twilight_cache_inmemory::{ InMemoryCache, model::CachedMessage };
+use twilight_cache_inmemory::{ InMemoryCache, ResourceType, model::CachedMessage };
 use twilight_model::{
-    channel::{ Message, message::{ embed::Embed, MessageFlags }, Channel },
+    channel::{
+        Message,
+        message::{ embed::Embed, MessageFlags, Component, component::{ ActionRow, TextInput } },
+        Channel,
+    },
     id::{ Id, marker::{ ChannelMarker, MessageMarker, UserMarker, GuildMarker, RoleMarker } },
     user::{ CurrentUser, User },
     http::interaction::{ InteractionResponse, InteractionResponseType, InteractionResponseData },
     gateway::payload::incoming::InteractionCreate,
     guild::Role,
     voice::VoiceState,
+    application::command::CommandOptionChoice,
 };
 use twilight_http::{ Client as HttpClient, Response, request::channel::message::CreateMessage };
 use twilight_standby::Standby;
@@ -30,6 +35,8 @@ use super::{
     messages::DiscordEmbed,
     commands::context::context_command::GuildConfigModel,
     bot::voice_music::voice_manager::VoiceManager,
+    commands::{ cooldowns::CommandCooldowns, command_replies::CommandReplies },
+    utils::modals::modal_label,
 };
 
 use fluent::FluentResource;
@@ -45,12 +52,33 @@ pub enum MessageContent {
     None,
 }
 
+impl MessageContent {
+    /// Splits the content into a text and embeds, for requests that take them separately.
+    pub fn into_parts(self) -> (Option<String>, Vec<Embed>) {
+        match self {
+            MessageContent::Text(text) => (Some(text), vec![]),
+            MessageContent::EmbedModels(embeds) => (None, convert_embed_models(embeds)),
+            MessageContent::TextAndEmbedModels(text, embeds) => {
+                (Some(text), convert_embed_models(embeds))
+            }
+            MessageContent::DiscordEmbeds(embeds) => (None, convert_discord_embeds(embeds)),
+            MessageContent::TextAndDiscordEmbeds(text, embeds) => {
+                (Some(text), convert_discord_embeds(embeds))
+            }
+            MessageContent::None => (None, vec![]),
+        }
+    }
+}
+
 pub type DiscordClient = Arc<DiscordClientRef>;
 
 /// A reference to the Discord client, encapsulating various functionalities and states.
 /// This structure provides access to database connections, HTTP client, in-memory cache,
 /// and other shared resources necessary for bot operations.
 pub struct DiscordClientRef {
+    /// Discord ID of the bot, known without a request to the API.
+    pub bot_id: Id<UserMarker>,
+
     /// Connection to the database.
     pub db: DatabaseConnection,
 
@@ -72,6 +100,12 @@ pub struct DiscordClientRef {
     /// cache voice staets
     pub voice_states_cached: RwLock<HashMap<Id<GuildMarker>, HashMap<Id<UserMarker>, VoiceState>>>,
 
+    /// Recent command uses, to enforce command cooldowns.
+    pub command_cooldowns: CommandCooldowns,
+
+    /// Replies of recent prefix commands, edited when the command message is edited.
+    pub command_replies: CommandReplies,
+
     /// Manager for voice-related features.
     pub voice_music_manager: Arc<VoiceManager>,
 
@@ -87,6 +121,7 @@ impl DiscordClientRef {
     ///
     /// # Arguments
     ///
+    /// * `bot_id` - Discord ID of the bot.
     /// * `db` - Connection to the database.
     /// * `http` - Arc-wrapped HTTP client for Discord API interactions.
     /// * `cache` - Arc-wrapped in-memory cache of Discord entities.
@@ -96,6 +131,7 @@ impl DiscordClientRef {
     ///
     /// A new instance of `DiscordClientRef`.
     pub fn new(
+        bot_id: Id<UserMarker>,
         db: DatabaseConnection,
         http: Arc<HttpClient>,
         cache: Arc<InMemoryCache>,
@@ -112,6 +148,7 @@ impl DiscordClientRef {
         }
 
         DiscordClientRef {
+            bot_id,
             db,
             http,
             cache,
@@ -122,9 +159,40 @@ impl DiscordClientRef {
             default_bundle: load_localization("en"),
             afk_users: Default::default(),
             voice_states_cached: Default::default(),
+            command_cooldowns: Default::default(),
+            command_replies: Default::default(),
         }
     }
 
+    /// Constructs a client whose HTTP requests go to `api_host` instead of Discord, over plain
+    /// HTTP and without ratelimiting, like a local stand-in of the Discord API.
+    ///
+    /// The client is not connected to the gateway, events are given to its cache and to the
+    /// handlers directly.
+    pub fn with_api_host(
+        bot_id: Id<UserMarker>,
+        db: DatabaseConnection,
+        token: String,
+        api_host: &str
+    ) -> Self {
+        let http = HttpClient::builder()
+            .token(token)
+            .proxy(api_host.to_string(), true)
+            .ratelimiter(None)
+            .build();
+        let cache = InMemoryCache::builder().resource_types(ResourceType::all()).build();
+        let songbird = Songbird::twilight(Arc::new(TwilightMap::new(HashMap::new())), bot_id);
+
+        Self::new(
+            bot_id,
+            db,
+            Arc::new(http),
+            Arc::new(cache),
+            Arc::new(Standby::new()),
+            Arc::new(songbird)
+        )
+    }
+
     /// Retrieves a localization string based on the provided locale and key.
     ///
     /// # Arguments
@@ -420,6 +488,51 @@ impl DiscordClientRef {
         Ok(())
     }
 
+    /// Answers an interaction with a message only the user who used it can see.
+    pub async fn ephemeral_interaction_reply(
+        &self,
+        interaction: &Box<InteractionCreate>,
+        content: String
+    ) -> Result<(), BoxedError> {
+        self.http.interaction(interaction.application_id).create_response(
+            interaction.id,
+            &interaction.token,
+            &(InteractionResponse {
+                kind: InteractionResponseType::ChannelMessageWithSource,
+                data: Some(InteractionResponseData {
+                    content: Some(content),
+                    flags: Some(MessageFlags::EPHEMERAL),
+                    ..Default::default()
+                }),
+            })
+        ).await?;
+
+        Ok(())
+    }
+
+    /// Answers an interaction with embeds, only visible to the user who used it if `ephemeral`.
+    pub async fn embed_interaction_reply(
+        &self,
+        interaction: &Box<InteractionCreate>,
+        embeds: Vec<DiscordEmbed>,
+        ephemeral: bool
+    ) -> Result<(), BoxedError> {
+        self.http.interaction(interaction.application_id).create_response(
+            interaction.id,
+            &interaction.token,
+            &(InteractionResponse {
+                kind: InteractionResponseType::ChannelMessageWithSource,
+                data: Some(InteractionResponseData {
+                    embeds: Some(convert_discord_embeds(embeds)),
+                    flags: if ephemeral { Some(MessageFlags::EPHEMERAL) } else { None },
+                    ..Default::default()
+                }),
+            })
+        ).await?;
+
+        Ok(())
+    }
+
     pub async fn defer_interaction(
         &self,
         interaction: &Box<InteractionCreate>
@@ -436,6 +549,84 @@ impl DiscordClientRef {
         Ok(())
     }
 
+    /// Answers an autocomplete interaction with the suggested choices.
+    pub async fn autocomplete_interaction_reply(
+        &self,
+        interaction: &Box<InteractionCreate>,
+        choices: Vec<CommandOptionChoice>
+    ) -> Result<(), BoxedError> {
+        self.http.interaction(interaction.application_id).create_response(
+            interaction.id,
+            &interaction.token,
+            &(InteractionResponse {
+                kind: InteractionResponseType::ApplicationCommandAutocompleteResult,
+                data: Some(InteractionResponseData {
+                    choices: Some(choices),
+                    ..Default::default()
+                }),
+            })
+        ).await?;
+
+        Ok(())
+    }
+
+    /// Answers a component interaction by editing the message the component is on.
+    pub async fn update_component_message(
+        &self,
+        interaction: &Box<InteractionCreate>,
+        embeds: Vec<Embed>,
+        components: Vec<Component>
+    ) -> Result<(), BoxedError> {
+        self.http.interaction(interaction.application_id).create_response(
+            interaction.id,
+            &interaction.token,
+            &(InteractionResponse {
+                kind: InteractionResponseType::UpdateMessage,
+                data: Some(InteractionResponseData {
+                    embeds: Some(embeds),
+                    components: Some(components),
+                    ..Default::default()
+                }),
+            })
+        ).await?;
+
+        Ok(())
+    }
+
+    /// Answers an interaction with a modal, each text input on its own row.
+    pub async fn show_modal(
+        &self,
+        interaction: &Box<InteractionCreate>,
+        custom_id: String,
+        title: &str,
+        inputs: Vec<TextInput>
+    ) -> Result<(), BoxedError> {
+        let components = inputs
+            .into_iter()
+            .map(|input| {
+                Component::ActionRow(ActionRow {
+                    components: vec![Component::TextInput(input)],
+                })
+            })
+            .collect();
+
+        self.http.interaction(interaction.application_id).create_response(
+            interaction.id,
+            &interaction.token,
+            &(InteractionResponse {
+                kind: InteractionResponseType::Modal,
+                data: Some(InteractionResponseData {
+                    custom_id: Some(custom_id),
+                    title: Some(modal_label(title)),
+                    components: Some(components),
+                    ..Default::default()
+                }),
+            })
+        ).await?;
+
+        Ok(())
+    }
+
     pub async fn find_role(
         &self,
         guild_id: Id<GuildMarker>,
@@ -488,6 +679,39 @@ impl DiscordClientRef {
         Ok(member.roles.contains(&role_id))
     }
 
+    /// Checks whether the bot's highest role is above the target role, which is required
+    /// for the bot to add or remove it.
+    pub async fn can_bot_manage_role(
+        &self,
+        guild_id: Id<GuildMarker>,
+        target_role_id: Id<RoleMarker>
+    ) -> Result<bool, BoxedError> {
+        let bot_id = self.get_bot().await?.id;
+
+        // Fetch the bot's member information
+        let bot_member = self.http.guild_member(guild_id, bot_id).await?.model().await?;
+
+        // Fetch all roles in the guild
+        let roles = self.http.roles(guild_id).await?.model().await?;
+
+        // Determine the bot's highest role position
+        let bot_highest_role_position = bot_member.roles
+            .iter()
+            .filter_map(|role_id| roles.iter().find(|role| &role.id == role_id))
+            .map(|role| role.position)
+            .max()
+            .unwrap_or(0);
+
+        // Fetch the target role information
+        let target_role = roles
+            .into_iter()
+            .find(|role| role.id == target_role_id)
+            .ok_or("Target role not found")?;
+
+        // Check if the bot's highest role is higher than the target role
+        Ok(bot_highest_role_position > target_role.position)
+    }
+
     pub async fn is_user_in_same_channel_as_bot(
         &self,
         guild_id: Id<GuildMarker>,
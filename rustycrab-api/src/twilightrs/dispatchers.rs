@@ -1,11 +1,13 @@
 use super::commands::{
     context::context_command_dispatcher::ContextCommandDispatcher,
     slash::slash_command_dispatcher::SlashCommandDispatcher,
+    context_menu::context_menu_dispatcher::ContextMenuDispatcher,
 };
 
 pub struct ClientDispatchers {
     pub context_commands: ContextCommandDispatcher,
     pub slash_commands: SlashCommandDispatcher,
+    pub context_menus: ContextMenuDispatcher,
 }
 
 impl ClientDispatchers {
@@ -13,6 +15,7 @@ impl ClientDispatchers {
         Self {
             context_commands: ContextCommandDispatcher::new(),
             slash_commands: SlashCommandDispatcher::new(),
+            context_menus: ContextMenuDispatcher::new(),
         }
     }
 }
@@ -0,0 +1,206 @@
+//! Gateway event bus.
+//!
+//! Bot features implement [`EventListener`] and register for the gateway event kinds they care
+//! about. For every event, the bus loads the guild's configuration once, then runs the
+//! interested listeners one after the other, ordered by their priority. A listener can stop the
+//! event from reaching the listeners after it by returning [`EventFlow::Stop`].
+
+use std::{ collections::HashMap, sync::Arc };
+
+use async_trait::async_trait;
+use twilight_gateway::Event;
+use twilight_model::{ gateway::event::EventType, id::{ Id, marker::GuildMarker } };
+
+use crate::{
+    queries::guild_config_queries::GuildConfigQueries,
+    twilightrs::{
+        discord_client::DiscordClient,
+        dispatchers::ClientDispatchers,
+        commands::context::context_command::GuildConfigModel,
+        bot::{
+            action_logs::{ ActionLogsListener, CachedBefore },
+            auto_response::AutoResponseListener,
+            auto_roles::AutoRolesListener,
+            goodbye::GoodbyeListener,
+            new_accounts::NewAccountsListener,
+            welcome::WelcomeListener,
+        },
+        events::{
+            commands::CommandsListener,
+            message_delete::SnipeListener,
+            voice_updates::VoiceListener,
+        },
+        utils::afk::AfkListener,
+    },
+    utilities::app_error::BoxedError,
+};
+
+/// Whether an event should keep going to the next listeners.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EventFlow {
+    Continue,
+    Stop,
+}
+
+/// Everything a listener gets for a single gateway event.
+pub struct EventContext {
+    pub client: DiscordClient,
+    pub event: Event,
+    /// What the cache knew before this event, see [`CachedBefore`]
+    pub before: Option<CachedBefore>,
+    /// The guild's configuration, `None` for events outside of guilds
+    pub config: Option<GuildConfigModel>,
+}
+
+/// A bot feature reacting to gateway events.
+#[async_trait]
+pub trait EventListener: Send + Sync {
+    /// Name used when logging the listener's errors.
+    fn name(&self) -> &'static str;
+
+    /// The event kinds this listener is called for.
+    fn events(&self) -> Vec<EventType>;
+
+    /// Listeners with a lower priority run first.
+    fn priority(&self) -> i32 {
+        0
+    }
+
+    /// Checks whether the listener is enabled in the guild the event comes from.
+    ///
+    /// Only called for guild events, listeners are always called for the other events.
+    fn is_enabled(&self, _config: &GuildConfigModel) -> bool {
+        true
+    }
+
+    async fn handle(&self, ctx: &EventContext) -> Result<EventFlow, BoxedError>;
+}
+
+pub struct EventBus {
+    listeners: HashMap<EventType, Vec<Arc<dyn EventListener>>>,
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        Self { listeners: HashMap::new() }
+    }
+
+    /// Creates a bus with every built-in bot feature registered.
+    pub fn with_default_listeners(dispatchers: Arc<ClientDispatchers>) -> Self {
+        let mut bus = Self::new();
+
+        let listeners: Vec<Arc<dyn EventListener>> = vec![
+            Arc::new(CommandsListener { dispatchers }),
+            Arc::new(ActionLogsListener {}),
+            Arc::new(SnipeListener {}),
+            Arc::new(AfkListener {}),
+            Arc::new(AutoResponseListener {}),
+            Arc::new(NewAccountsListener {}),
+            Arc::new(WelcomeListener {}),
+            Arc::new(GoodbyeListener {}),
+            Arc::new(AutoRolesListener {}),
+            Arc::new(VoiceListener {}),
+        ];
+        for listener in listeners {
+            bus.register(listener);
+        }
+
+        bus
+    }
+
+    /// Registers a listener for its event kinds.
+    ///
+    /// Listeners with the same priority run in the order they were registered.
+    pub fn register(&mut self, listener: Arc<dyn EventListener>) {
+        for kind in listener.events() {
+            let listeners = self.listeners.entry(kind).or_insert_with(Vec::new);
+            let position = listeners
+                .iter()
+                .position(|registered| registered.priority() > listener.priority())
+                .unwrap_or(listeners.len());
+            listeners.insert(position, Arc::clone(&listener));
+        }
+    }
+
+    /// Runs the listeners registered for the event's kind.
+    pub async fn dispatch(
+        &self,
+        client: DiscordClient,
+        event: Event,
+        before: Option<CachedBefore>
+    ) -> Result<(), BoxedError> {
+        let kind = event.kind();
+        let listeners = match self.listeners.get(&kind) {
+            Some(listeners) if !listeners.is_empty() => listeners,
+            _ => {
+                return Ok(());
+            }
+        };
+
+        let config = match event_guild_id(&event) {
+            Some(guild_id) => Some(guild_config(&client, guild_id).await?),
+            None => None,
+        };
+
+        let ctx = EventContext { client, event, before, config };
+
+        for listener in listeners {
+            if let Some(config) = &ctx.config {
+                if !listener.is_enabled(config) {
+                    continue;
+                }
+            }
+
+            match listener.handle(&ctx).await {
+                Ok(EventFlow::Continue) => {}
+                Ok(EventFlow::Stop) => {
+                    break;
+                }
+                Err(e) => {
+                    eprintln!("[{}] Error handling event {:?}: {:?}", listener.name(), kind, e);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+async fn guild_config(
+    client: &DiscordClient,
+    guild_id: Id<GuildMarker>
+) -> Result<GuildConfigModel, BoxedError> {
+    // the current user is cached once the shard is ready, saving a request per event
+    let bot_id = match client.cache.current_user() {
+        Some(user) => user.id,
+        None => client.get_bot().await?.id,
+    };
+
+    Ok(
+        GuildConfigQueries::get_or_create_config(
+            &client.db,
+            &bot_id.to_string(),
+            &guild_id.to_string()
+        ).await?
+    )
+}
+
+/// The guild of the events the bus has listeners for.
+fn event_guild_id(event: &Event) -> Option<Id<GuildMarker>> {
+    match event {
+        Event::MessageCreate(msg) => msg.guild_id,
+        Event::MessageDelete(event) => event.guild_id,
+        Event::MessageUpdate(event) => event.guild_id,
+        Event::InteractionCreate(interaction) => interaction.guild_id,
+        Event::VoiceStateUpdate(update) => update.0.guild_id,
+        Event::MemberAdd(event) => Some(event.guild_id),
+        Event::MemberRemove(event) => Some(event.guild_id),
+        Event::MemberUpdate(event) => Some(event.guild_id),
+        Event::BanAdd(event) => Some(event.guild_id),
+        Event::BanRemove(event) => Some(event.guild_id),
+        Event::ChannelCreate(event) => event.guild_id,
+        Event::ChannelDelete(event) => event.guild_id,
+        Event::ChannelUpdate(event) => event.guild_id,
+        _ => None,
+    }
+}
@@ -0,0 +1,124 @@
+//! Gateway event recordings.
+//!
+//! With `RECORD_GATEWAY_EVENTS` set to a directory, every bot appends the events it receives to
+//! `<directory>/<bot id>.jsonl`, one event per line. The recordings are replayed through the
+//! same handlers in the integration tests, without a connection to Discord.
+
+use std::{ env, fs::{ self, File, OpenOptions }, io::Write, path::PathBuf, sync::Mutex };
+
+use serde::{ Deserialize, Serialize };
+use serde_json::Value;
+use twilight_gateway::Event;
+use twilight_model::{ gateway::event::EventType, id::{ Id, marker::UserMarker } };
+
+/// Implements the conversions between the recorded events and the gateway events, for the
+/// event kinds named the same in `Event` and `EventType`.
+macro_rules! recorded_event_kinds {
+    ($($kind:ident),* $(,)?) => {
+        impl RecordedEvent {
+            /// Records an event, `None` for the kinds that are not recorded, like `Ready`.
+            pub fn from_event(event: &Event) -> Option<Self> {
+                let data = match event {
+                    $(Event::$kind(payload) => serde_json::to_value(payload),)*
+                    _ => {
+                        return None;
+                    }
+                };
+
+                data.ok().map(|data| Self { kind: event.kind(), data })
+            }
+
+            /// The recorded gateway event, `None` for the kinds that are not recorded.
+            pub fn into_event(self) -> Result<Option<Event>, serde_json::Error> {
+                Ok(match self.kind {
+                    $(EventType::$kind => Some(Event::$kind(serde_json::from_value(self.data)?)),)*
+                    _ => None,
+                })
+            }
+        }
+    };
+}
+
+/// A gateway event, as its kind and its payload.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RecordedEvent {
+    #[serde(rename = "t")]
+    pub kind: EventType,
+    #[serde(rename = "d")]
+    pub data: Value,
+}
+
+recorded_event_kinds!(
+    BanAdd,
+    BanRemove,
+    ChannelCreate,
+    ChannelDelete,
+    ChannelUpdate,
+    GuildCreate,
+    InteractionCreate,
+    MemberAdd,
+    MemberRemove,
+    MemberUpdate,
+    MessageCreate,
+    MessageDelete,
+    MessageDeleteBulk,
+    MessageUpdate,
+    ReactionAdd,
+    ReactionRemove,
+    RoleCreate,
+    RoleDelete,
+    RoleUpdate,
+    ThreadCreate,
+    ThreadDelete,
+    ThreadUpdate,
+    VoiceStateUpdate,
+);
+
+/// Appends the events a bot receives to its recording.
+pub struct EventRecorder {
+    file: Mutex<File>,
+}
+
+impl EventRecorder {
+    /// The recorder of a bot, `None` unless `RECORD_GATEWAY_EVENTS` is set.
+    pub fn from_env(bot_id: Id<UserMarker>) -> Option<Self> {
+        let directory = PathBuf::from(env::var("RECORD_GATEWAY_EVENTS").ok()?);
+        let file = fs::create_dir_all(&directory).and_then(|_| {
+            OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(directory.join(format!("{}.jsonl", bot_id)))
+        });
+
+        match file {
+            Ok(file) => Some(Self { file: Mutex::new(file) }),
+            Err(e) => {
+                eprintln!("Failed to open the gateway event recording of {}: {:?}", bot_id, e);
+                None
+            }
+        }
+    }
+
+    pub fn record(&self, event: &Event) {
+        let recorded = RecordedEvent::from_event(event).map(|event| serde_json::to_string(&event));
+        let line = match recorded {
+            Some(Ok(line)) => line,
+            _ => {
+                return;
+            }
+        };
+
+        if let Err(e) = writeln!(self.file.lock().unwrap(), "{}", line) {
+            eprintln!("Failed to record a gateway event: {:?}", e);
+        }
+    }
+}
+
+/// Reads a recording, skipping its empty lines.
+pub fn read_recording(content: &str) -> Result<Vec<RecordedEvent>, serde_json::Error> {
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(serde_json::from_str)
+        .collect()
+}
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use twilight_gateway::Event;
+use twilight_model::gateway::event::EventType;
+
+use crate::{
+    twilightrs::{
+        dispatchers::ClientDispatchers,
+        event_bus::{ EventContext, EventFlow, EventListener },
+    },
+    utilities::app_error::BoxedError,
+};
+
+use super::{
+    message_create::handle_message_create,
+    message_update::handle_message_update,
+    interaction_handlers::handle_interaction_create,
+};
+
+/// Runs prefix commands, slash commands and buttons.
+///
+/// Prefix commands also run again when their message is edited shortly after being sent.
+///
+/// Slash commands are not registered on `Ready`, they are synced on request, see
+/// [`BotSupervisor::sync_commands`](crate::bot_supervisor::BotSupervisor::sync_commands).
+pub struct CommandsListener {
+    pub dispatchers: Arc<ClientDispatchers>,
+}
+
+#[async_trait]
+impl EventListener for CommandsListener {
+    fn name(&self) -> &'static str {
+        "commands"
+    }
+
+    fn events(&self) -> Vec<EventType> {
+        vec![
+            EventType::Ready,
+            EventType::MessageCreate,
+            EventType::MessageUpdate,
+            EventType::InteractionCreate
+        ]
+    }
+
+    async fn handle(&self, ctx: &EventContext) -> Result<EventFlow, BoxedError> {
+        let client = Arc::clone(&ctx.client);
+
+        match &ctx.event {
+            Event::Ready(ready) => {
+                println!("[{}#{:04}] Shard is ready", ready.user.name, ready.user.discriminator);
+
+                Ok(EventFlow::Continue)
+            }
+            Event::MessageCreate(msg) => {
+                match &ctx.config {
+                    Some(config) => handle_message_create(client, msg, config, &self.dispatchers).await,
+                    None => Ok(EventFlow::Continue),
+                }
+            }
+            Event::MessageUpdate(update) => {
+                match &ctx.config {
+                    Some(config) => {
+                        handle_message_update(
+                            client,
+                            update,
+                            &ctx.before,
+                            config,
+                            &self.dispatchers
+                        ).await
+                    }
+                    None => Ok(EventFlow::Continue),
+                }
+            }
+            Event::InteractionCreate(interaction) => {
+                if let Some(config) = &ctx.config {
+                    handle_interaction_create(client, config, interaction, &self.dispatchers).await?;
+                }
+
+                Ok(EventFlow::Continue)
+            }
+            _ => Ok(EventFlow::Continue),
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use std::error::Error;
+
+use fluent_bundle::FluentArgs;
+use twilight_model::{
+    channel::message::Embed,
+    gateway::payload::incoming::InteractionCreate,
+    id::{ Id, marker::UserMarker },
+};
+
+use crate::twilightrs::{
+    discord_client::DiscordClient,
+    commands::context::{
+        context_command::GuildConfigModel,
+        general::help::{ help_page, HelpView },
+    },
+};
+
+/// Turns the pages of the `help` command.
+///
+/// The buttons' args are the user who asked for help, the page to show and the `HelpView`.
+pub async fn help_page_handler(
+    client: DiscordClient,
+    config: &GuildConfigModel,
+    interaction: &Box<InteractionCreate>,
+    args: &[&str]
+) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+    let (user_id, page, view) = match args {
+        [user_id, page, view @ ..] => (
+            user_id.parse::<Id<UserMarker>>()?,
+            page.parse::<usize>()?,
+            HelpView::from_args(view),
+        ),
+        _ => {
+            return Ok(());
+        }
+    };
+    let view = match view {
+        Some(view) => view,
+        None => {
+            return Ok(());
+        }
+    };
+
+    if interaction.author_id() != Some(user_id) {
+        let mut args = FluentArgs::new();
+        args.set("user", format!("<@{}>", user_id));
+        let content = client.get_locale_string(
+            &config.locale,
+            "select-menu-not-yours",
+            Some(&args)
+        );
+        client.ephemeral_interaction_reply(interaction, content).await?;
+        return Ok(());
+    }
+
+    let (embed, components) = help_page(&client, config, user_id, &view, page).await;
+    client.update_component_message(interaction, vec![Embed::from(embed)], components).await?;
+
+    Ok(())
+}
@@ -1,23 +1,31 @@
 use std::{ error::Error, sync::Arc, str::FromStr };
 use enum_primitive_derive::Primitive;
 use num_traits::{ ToPrimitive, FromPrimitive };
+use rustycrab_model::modules::GuildModule;
 use twilight_model::{
     application::interaction::message_component::MessageComponentInteractionData,
     gateway::payload::incoming::InteractionCreate,
 };
 
-use crate::twilightrs::{ dispatchers::ClientDispatchers, discord_client::DiscordClient };
+use crate::twilightrs::{
+    dispatchers::ClientDispatchers,
+    discord_client::DiscordClient,
+    commands::context::context_command::GuildConfigModel,
+    utils::module_disabled_message,
+};
 
-use self::{ tickets::tickets_handler, afk::add_afk_notification };
+use self::{ tickets::tickets_handler, afk::add_afk_notification, help::help_page_handler };
 
-mod tickets;
+pub mod tickets;
 mod afk;
+mod help;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Primitive)]
 pub enum ButtonEvents {
     Tickets = 1,
     Afk = 2,
     MusicPlayer = 3,
+    HelpPage = 4,
 }
 
 impl FromStr for ButtonEvents {
@@ -32,14 +40,31 @@ impl FromStr for ButtonEvents {
 }
 
 impl ButtonEvents {
-    #[allow(dead_code)]
     pub fn to_i32_string(&self) -> String {
         self.to_i32().unwrap().to_string()
     }
+
+    /// Builds the custom id of a button for this event, like `SelectMenuEvents::custom_id`.
+    pub fn custom_id(&self, args: &[&str]) -> String {
+        let mut parts = vec![self.to_i32_string()];
+        parts.extend(args.iter().map(|arg| arg.to_string()));
+        parts.join(":")
+    }
+
+    /// The guild module the buttons belong to, `None` for buttons that are always available.
+    pub fn module(&self) -> Option<GuildModule> {
+        match self {
+            ButtonEvents::Tickets => Some(GuildModule::Tickets),
+            ButtonEvents::Afk => Some(GuildModule::Afk),
+            ButtonEvents::MusicPlayer => Some(GuildModule::Music),
+            ButtonEvents::HelpPage => None,
+        }
+    }
 }
 
 pub async fn button_handlers(
     client: DiscordClient,
+    config: &GuildConfigModel,
     interaction: &Box<InteractionCreate>,
     dispatchers: &Arc<ClientDispatchers>,
     button_data: &MessageComponentInteractionData
@@ -47,6 +72,14 @@ pub async fn button_handlers(
     let button_parts: Vec<String> = button_data.custom_id.split(":").map(String::from).collect();
     if let Some(button_event) = button_parts.first() {
         if let Ok(button_event) = ButtonEvents::from_str(button_event.as_str()) {
+            if let Some(module) = button_event.module() {
+                if !module.is_enabled(config.module_flags) {
+                    let content = module_disabled_message(&client, config, module);
+                    client.ephemeral_interaction_reply(interaction, content).await?;
+                    return Ok(());
+                }
+            }
+
             match button_event {
                 ButtonEvents::Tickets => {
                     tickets_handler(client, interaction, dispatchers, button_data).await?;
@@ -57,6 +90,13 @@ pub async fn button_handlers(
                 ButtonEvents::MusicPlayer => {
                     // music_player_handler(client, interaction, button_data).await?;
                 }
+                ButtonEvents::HelpPage => {
+                    let args: Vec<&str> = button_parts[1..]
+                        .iter()
+                        .map(|part| part.as_str())
+                        .collect();
+                    help_page_handler(client, config, interaction, &args).await?;
+                }
                 // _ => {}
             }
         }
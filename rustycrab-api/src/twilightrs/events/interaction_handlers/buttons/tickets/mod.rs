@@ -11,6 +11,7 @@ use crate::{
         discord_client::DiscordClient,
         dispatchers::ClientDispatchers,
         bot::tickets::close_ticket_handler,
+        events::interaction_handlers::modals::tickets::show_ticket_questions,
     },
     queries::tickets_system::ticket_queries::TicketQueries,
     default_queries::DefaultSeaQueries,
@@ -18,7 +19,7 @@ use crate::{
 
 use self::open_ticket::open_ticket_handler;
 
-mod open_ticket;
+pub mod open_ticket;
 
 pub async fn tickets_handler(
     client: DiscordClient,
@@ -35,9 +36,22 @@ pub async fn tickets_handler(
         if let Some(action) = button_parts.get(1) {
             match action.as_str() {
                 "1" => {
+                    // panels with questions ask them in a modal before opening the ticket
+                    if let Some(id) = button_parts.get(2) {
+                        if show_ticket_questions(&client, interaction, id, &[]).await? {
+                            return Ok(());
+                        }
+                    }
+
                     client.defer_ephemeral_interaction(interaction).await?;
                     if let Some(id) = button_parts.get(2) {
-                        open_ticket_handler(client, interaction, guild_id, id.to_string()).await?;
+                        open_ticket_handler(
+                            client,
+                            interaction,
+                            guild_id,
+                            id.to_string(),
+                            Vec::new()
+                        ).await?;
                     } else {
                         client.http
                             .interaction(interaction.application_id)
@@ -18,7 +18,11 @@ use twilight_model::{
 };
 
 use crate::{
-    twilightrs::{ discord_client::DiscordClient, messages::DiscordEmbed },
+    twilightrs::{
+        discord_client::DiscordClient,
+        messages::{ DiscordEmbed, DiscordEmbedField },
+        bot::tickets::add_support_team_to_channel,
+    },
     queries::{
         tickets_system::{
             ticket_setting_queries::TicketSettingQueries,
@@ -31,13 +35,17 @@ use crate::{
     unique_bot_guild_entity_queries::UniqueBotGuildEntityQueries,
     database::tickets::Model as TicketModel,
     utilities::utils::color_to_button_style,
+    metrics::metrics,
+    cdn_avatar,
 };
 
+/// Opens a ticket from a panel, `answers` being the panel's questions and their answers.
 pub async fn open_ticket_handler(
     client: DiscordClient,
     interaction: &Box<InteractionCreate>,
     guild_id: Id<GuildMarker>,
-    panel_id: String
+    panel_id: String,
+    answers: Vec<(String, String)>
 ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
     if let Some(user) = interaction.author() {
         let panel_id = i32::from_str_radix(&panel_id, 10)?;
@@ -104,6 +112,9 @@ pub async fn open_ticket_handler(
                 ticket,
                 channel_id
             ).await?;
+            if !answers.is_empty() {
+                send_ticket_answers(&client, channel_id, user, answers).await?;
+            }
         } else {
             client.http
                 .interaction(interaction.application_id)
@@ -212,38 +223,7 @@ async fn create_channel_ticket(
     ).await;
 
     if let Some(team) = &panel_details.support_team {
-        let support_team_permission_overwrites =
-            Permissions::VIEW_CHANNEL |
-            Permissions::SEND_MESSAGES |
-            Permissions::READ_MESSAGE_HISTORY |
-            Permissions::MANAGE_MESSAGES |
-            Permissions::MANAGE_ROLES;
-        for role_id in &team.roles {
-            if !role_id.is_empty() {
-                let _ = client.http.update_channel_permission(
-                    ticket_channel_id,
-                    &(PermissionOverwrite {
-                        kind: PermissionOverwriteType::Role,
-                        id: Id::new(u64::from_str_radix(role_id, 10)?.into()),
-                        allow: Some(support_team_permission_overwrites),
-                        deny: None,
-                    })
-                ).await;
-            }
-        }
-        for user_id in &team.users {
-            if !user_id.is_empty() {
-                let _ = client.http.update_channel_permission(
-                    ticket_channel_id,
-                    &(PermissionOverwrite {
-                        kind: PermissionOverwriteType::Member,
-                        id: Id::new(u64::from_str_radix(user_id, 10)?.into()),
-                        allow: Some(support_team_permission_overwrites),
-                        deny: None,
-                    })
-                ).await;
-            }
-        }
+        add_support_team_to_channel(&client, ticket_channel_id, team).await?;
     }
 
     Ok(Some(ticket_channel_id))
@@ -359,6 +339,34 @@ async fn welcome_ticket(
         status: Some("Opened".to_string()),
         ..Default::default()
     }).await;
+    metrics().record_ticket_opened(&client.bot_id.to_string());
+
+    Ok(())
+}
+
+/// Posts the answers to the panel's questions in the ticket.
+async fn send_ticket_answers(
+    client: &DiscordClient,
+    channel_id: Id<ChannelMarker>,
+    user: &User,
+    answers: Vec<(String, String)>
+) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+    let embed = DiscordEmbed {
+        author_name: Some(user.name.clone()),
+        author_icon_url: user.avatar.map(|hash| cdn_avatar!(user.id, hash)),
+        fields: Some(
+            answers
+                .into_iter()
+                .map(|(question, answer)| DiscordEmbedField {
+                    name: question,
+                    value: answer,
+                    inline: false,
+                })
+                .collect()
+        ),
+        ..Default::default()
+    };
+    client.http.create_message(channel_id).embeds(&vec![Embed::from(embed)])?.await?;
 
     Ok(())
 }
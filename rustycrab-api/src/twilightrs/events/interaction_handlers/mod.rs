@@ -1,36 +1,108 @@
 pub mod buttons;
+pub mod modals;
+pub mod select_menus;
 
 use std::{ error::Error, sync::Arc };
 
 use twilight_model::{
     gateway::payload::incoming::InteractionCreate,
-    application::interaction::{ InteractionType, InteractionData },
+    application::{ command::CommandType, interaction::{ InteractionType, InteractionData } },
+    channel::message::component::ComponentType,
 };
 
-use crate::twilightrs::{ discord_client::DiscordClient, dispatchers::ClientDispatchers };
+use crate::twilightrs::{
+    discord_client::DiscordClient,
+    dispatchers::ClientDispatchers,
+    commands::context::context_command::GuildConfigModel,
+};
 
-use self::buttons::button_handlers;
+use self::{
+    buttons::button_handlers,
+    modals::modal_handlers,
+    select_menus::select_menu_handlers,
+};
 
 pub async fn handle_interaction_create(
     client: DiscordClient,
+    config: &GuildConfigModel,
     interaction: &Box<InteractionCreate>,
     dispatchers: &Arc<ClientDispatchers>
 ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
     if let Some(_) = interaction.guild_id {
         match interaction.kind {
             InteractionType::MessageComponent => {
-                if let Some(InteractionData::MessageComponent(button_data)) = &interaction.data {
-                    button_handlers(client, interaction, dispatchers, button_data).await?;
+                if let Some(InteractionData::MessageComponent(data)) = &interaction.data {
+                    match data.component_type {
+                        ComponentType::Button => {
+                            button_handlers(client, config, interaction, dispatchers, data).await?;
+                        }
+                        ComponentType::TextSelectMenu |
+                        ComponentType::UserSelectMenu |
+                        ComponentType::RoleSelectMenu |
+                        ComponentType::MentionableSelectMenu |
+                        ComponentType::ChannelSelectMenu => {
+                            select_menu_handlers(
+                                client,
+                                config,
+                                interaction,
+                                dispatchers,
+                                data
+                            ).await?;
+                        }
+                        _ => {}
+                    }
                 }
             }
             InteractionType::ApplicationCommand => {
                 if let Some(InteractionData::ApplicationCommand(command_data)) = &interaction.data {
-                    // command_data.name
-                    let _ = dispatchers.slash_commands.dispatch(
-                        client,
-                        interaction,
-                        command_data
-                    ).await;
+                    if command_data.kind != CommandType::ChatInput {
+                        // user and message context menu commands
+                        let _ = dispatchers.context_menus.dispatch(
+                            client,
+                            config,
+                            interaction,
+                            command_data
+                        ).await;
+                    } else if dispatchers.slash_commands.commands.contains_key(&command_data.name) {
+                        let _ = dispatchers.slash_commands.dispatch(
+                            client,
+                            config,
+                            interaction,
+                            command_data
+                        ).await;
+                    } else {
+                        // every context command is also registered as a slash command
+                        let _ = dispatchers.context_commands.dispatch_interaction(
+                            client,
+                            config,
+                            interaction,
+                            command_data
+                        ).await;
+                    }
+                }
+            }
+            InteractionType::ModalSubmit => {
+                if let Some(InteractionData::ModalSubmit(modal_data)) = &interaction.data {
+                    modal_handlers(client, config, interaction, modal_data).await?;
+                }
+            }
+            InteractionType::ApplicationCommandAutocomplete => {
+                if let Some(InteractionData::ApplicationCommand(command_data)) = &interaction.data {
+                    if dispatchers.slash_commands.commands.contains_key(&command_data.name) {
+                        dispatchers.slash_commands.autocomplete(
+                            client,
+                            config,
+                            interaction,
+                            command_data
+                        ).await?;
+                    } else {
+                        dispatchers.context_commands.dispatch_autocomplete(
+                            client,
+                            config,
+                            interaction,
+                            command_data
+                        ).await?;
+                    }
                 }
             }
             _ => {}
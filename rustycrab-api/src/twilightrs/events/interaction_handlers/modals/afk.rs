@@ -0,0 +1,35 @@
+use std::error::Error;
+
+use twilight_model::gateway::payload::incoming::InteractionCreate;
+
+use crate::{
+    twilightrs::{
+        discord_client::DiscordClient,
+        commands::context::context_command::GuildConfigModel,
+        utils::{ afk::set_afk_from_interaction, modals::{ FromModal, ModalValues } },
+    },
+    utilities::app_error::BoxedError,
+};
+
+struct AfkMessageForm {
+    message: Option<String>,
+}
+
+impl FromModal for AfkMessageForm {
+    fn from_modal(values: &ModalValues) -> Result<Self, BoxedError> {
+        Ok(Self {
+            message: values.value("message"),
+        })
+    }
+}
+
+/// Marks the member AFK with the message typed in the `afk` modal.
+pub async fn afk_message_handler(
+    client: DiscordClient,
+    config: &GuildConfigModel,
+    interaction: &Box<InteractionCreate>,
+    values: &ModalValues
+) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+    let form = AfkMessageForm::from_modal(values)?;
+    set_afk_from_interaction(&client, &config.locale, interaction, form.message).await
+}
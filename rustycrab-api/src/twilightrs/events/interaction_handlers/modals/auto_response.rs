@@ -0,0 +1,95 @@
+use std::error::Error;
+
+use fluent_bundle::FluentArgs;
+use rustycrab_model::response::{
+    auto_response::RequestUpdateAutoResponse,
+    discord_message::{ RequestCreateUpdateMessage, RequestCreateUpdateEmbed },
+};
+use twilight_model::gateway::payload::incoming::InteractionCreate;
+
+use crate::{
+    twilightrs::{
+        discord_client::DiscordClient,
+        commands::context::context_command::GuildConfigModel,
+        utils::modals::{ FromModal, ModalValues },
+    },
+    queries::{ auto_responses_queries::AutoResponsesQueries, message_queries::MessageQueries },
+    default_queries::DefaultSeaQueries,
+    utilities::app_error::BoxedError,
+};
+
+/// The texts of an auto-response, an empty input clears the text.
+struct AutoResContentForm {
+    content: Option<String>,
+    description: Option<String>,
+}
+
+impl FromModal for AutoResContentForm {
+    fn from_modal(values: &ModalValues) -> Result<Self, BoxedError> {
+        Ok(Self {
+            content: values.value("content"),
+            description: values.value("description"),
+        })
+    }
+}
+
+/// Saves the texts submitted in the `autoresedit` modal.
+pub async fn autores_content_handler(
+    client: DiscordClient,
+    config: &GuildConfigModel,
+    interaction: &Box<InteractionCreate>,
+    args: &[&str],
+    values: &ModalValues
+) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+    let autores_id = args.first().ok_or("missing auto-response id")?.parse::<i32>()?;
+    let autores = AutoResponsesQueries::find_by_id(&client.db, autores_id).await?;
+    // the id comes from the modal, make sure it's an auto-response of this guild
+    if autores.guild_id != config.guild_id {
+        return Ok(());
+    }
+
+    let form = AutoResContentForm::from_modal(values)?;
+    let mut args = FluentArgs::new();
+    args.set("trigger", autores.trigger.clone());
+
+    // a message can't be sent without content or embed
+    if form.content.is_none() && form.description.is_none() {
+        let content = client.get_locale_string(
+            &config.locale,
+            "autores-update-failed",
+            Some(&args)
+        );
+        client.ephemeral_interaction_reply(interaction, content).await?;
+        return Ok(());
+    }
+
+    let message = MessageQueries::fetch_message_response(&client.db, autores.response_id).await?;
+    // only update the embed of messages that have one, or when there's a description to show
+    let embed = if message.embed.is_some() || form.description.is_some() {
+        Some(RequestCreateUpdateEmbed {
+            description: Some(form.description.unwrap_or_default()),
+            ..Default::default()
+        })
+    } else {
+        None
+    };
+
+    let result = AutoResponsesQueries::update_by_id(
+        &client.db,
+        autores.id,
+        RequestUpdateAutoResponse {
+            response_data: Some(RequestCreateUpdateMessage {
+                content: Some(form.content.unwrap_or_default()),
+                embed,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    ).await;
+
+    let key = if result.is_ok() { "autores-updated" } else { "autores-update-failed" };
+    let content = client.get_locale_string(&config.locale, key, Some(&args));
+    client.ephemeral_interaction_reply(interaction, content).await?;
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use std::{ error::Error, str::FromStr };
+use enum_primitive_derive::Primitive;
+use num_traits::{ ToPrimitive, FromPrimitive };
+use rustycrab_model::modules::GuildModule;
+use twilight_model::{
+    application::interaction::modal::ModalInteractionData,
+    gateway::payload::incoming::InteractionCreate,
+};
+
+use crate::twilightrs::{
+    discord_client::DiscordClient,
+    commands::context::context_command::GuildConfigModel,
+    utils::{ module_disabled_message, modals::ModalValues },
+};
+
+use self::{
+    tickets::ticket_questions_handler,
+    auto_response::autores_content_handler,
+    afk::afk_message_handler,
+};
+
+pub mod tickets;
+mod auto_response;
+mod afk;
+
+/// What a submitted modal is for, the first part of its custom id.
+///
+/// Custom ids look like `<event>:<arg>:<arg>`, like the ids of `ButtonEvents`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Primitive)]
+pub enum ModalEvents {
+    TicketQuestions = 1,
+    AutoResContent = 2,
+    AfkMessage = 3,
+}
+
+impl FromStr for ModalEvents {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse::<i32>() {
+            Ok(num) => ModalEvents::from_i32(num).ok_or(()),
+            Err(_) => Err(()),
+        }
+    }
+}
+
+impl ModalEvents {
+    /// Builds the custom id of a modal for this event.
+    pub fn custom_id(&self, args: &[&str]) -> String {
+        let mut parts = vec![self.to_i32().unwrap().to_string()];
+        parts.extend(args.iter().map(|arg| arg.to_string()));
+        parts.join(":")
+    }
+
+    /// The guild module the modals belong to.
+    pub fn module(&self) -> GuildModule {
+        match self {
+            ModalEvents::TicketQuestions => GuildModule::Tickets,
+            ModalEvents::AutoResContent => GuildModule::AutoResponses,
+            ModalEvents::AfkMessage => GuildModule::Afk,
+        }
+    }
+}
+
+pub async fn modal_handlers(
+    client: DiscordClient,
+    config: &GuildConfigModel,
+    interaction: &Box<InteractionCreate>,
+    modal_data: &ModalInteractionData
+) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+    let modal_parts: Vec<&str> = modal_data.custom_id.split(":").collect();
+    let modal_event = match modal_parts.first().and_then(|part| ModalEvents::from_str(part).ok()) {
+        Some(modal_event) => modal_event,
+        None => {
+            return Ok(());
+        }
+    };
+
+    let module = modal_event.module();
+    if !module.is_enabled(config.module_flags) {
+        let content = module_disabled_message(&client, config, module);
+        client.ephemeral_interaction_reply(interaction, content).await?;
+        return Ok(());
+    }
+
+    let values = ModalValues::from_data(modal_data);
+    let args = &modal_parts[1..];
+    match modal_event {
+        ModalEvents::TicketQuestions => {
+            ticket_questions_handler(client, interaction, args, &values).await?;
+        }
+        ModalEvents::AutoResContent => {
+            autores_content_handler(client, config, interaction, args, &values).await?;
+        }
+        ModalEvents::AfkMessage => {
+            afk_message_handler(client, config, interaction, &values).await?;
+        }
+    }
+
+    Ok(())
+}
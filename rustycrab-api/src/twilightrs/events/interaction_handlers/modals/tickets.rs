@@ -0,0 +1,146 @@
+use std::error::Error;
+
+use twilight_model::{
+    gateway::payload::incoming::InteractionCreate,
+    channel::message::component::{ TextInput, TextInputStyle },
+    id::{ Id, marker::{ ChannelMarker, GuildMarker, MessageMarker } },
+};
+
+use crate::{
+    twilightrs::{
+        discord_client::DiscordClient,
+        events::interaction_handlers::buttons::tickets::open_ticket::open_ticket_handler,
+        utils::modals::{ FromModal, ModalValues, text_input },
+    },
+    queries::tickets_system::ticket_panels_queries::{ TicketPanelsQueries, MAX_PANEL_QUESTIONS },
+    default_queries::DefaultSeaQueries,
+    utilities::app_error::BoxedError,
+};
+
+use super::ModalEvents;
+
+/// Longest answer, answers are shown as embed fields.
+const MAX_ANSWER_LENGTH: u16 = 1024;
+
+/// Answers to a panel's questions, in the order of the questions.
+struct TicketAnswers {
+    answers: Vec<String>,
+}
+
+impl FromModal for TicketAnswers {
+    fn from_modal(values: &ModalValues) -> Result<Self, BoxedError> {
+        Ok(Self {
+            answers: (0..MAX_PANEL_QUESTIONS)
+                .map(|index| values.value(&question_input_id(index)).unwrap_or_default())
+                .collect(),
+        })
+    }
+}
+
+fn question_input_id(index: usize) -> String {
+    format!("question-{}", index)
+}
+
+/// The link to the message a ticket is about, from the `<channel id>:<message id>` arguments of
+/// a custom id.
+pub fn linked_message_answer(
+    guild_id: Id<GuildMarker>,
+    linked_message: &[&str]
+) -> Option<(String, String)> {
+    match linked_message {
+        [channel_id, message_id] => {
+            let channel_id = channel_id.parse::<Id<ChannelMarker>>().ok()?;
+            let message_id = message_id.parse::<Id<MessageMarker>>().ok()?;
+            Some((
+                "Message".to_string(),
+                format!("https://discord.com/channels/{}/{}/{}", guild_id, channel_id, message_id),
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// Opens a ticket with the panel, asking the panel's questions first.
+///
+/// `linked_message` are the `<channel id>:<message id>` of the message the ticket is about, if any.
+pub async fn open_ticket_for_panel(
+    client: DiscordClient,
+    interaction: &Box<InteractionCreate>,
+    guild_id: Id<GuildMarker>,
+    panel_id: String,
+    linked_message: &[&str]
+) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+    if show_ticket_questions(&client, interaction, &panel_id, linked_message).await? {
+        return Ok(());
+    }
+
+    client.defer_ephemeral_interaction(interaction).await?;
+    let answers = linked_message_answer(guild_id, linked_message).into_iter().collect();
+    open_ticket_handler(client, interaction, guild_id, panel_id, answers).await
+}
+
+/// Shows the questions of a panel in a modal, returns `false` if the panel has no questions.
+pub async fn show_ticket_questions(
+    client: &DiscordClient,
+    interaction: &Box<InteractionCreate>,
+    panel_id: &str,
+    linked_message: &[&str]
+) -> Result<bool, Box<dyn Error + Send + Sync + 'static>> {
+    let questions = match panel_id.parse::<i32>() {
+        Ok(id) => {
+            match TicketPanelsQueries::find_by_id(&client.db, id).await {
+                Ok(panel) => TicketPanelsQueries::questions(&panel),
+                Err(_) => Vec::new(),
+            }
+        }
+        Err(_) => Vec::new(),
+    };
+    if questions.is_empty() {
+        return Ok(false);
+    }
+
+    let inputs = questions
+        .iter()
+        .enumerate()
+        .map(|(index, question)| TextInput {
+            max_length: Some(MAX_ANSWER_LENGTH),
+            ..text_input(&question_input_id(index), question, TextInputStyle::Paragraph)
+        })
+        .collect();
+    let mut args = vec![panel_id];
+    args.extend_from_slice(linked_message);
+    client.show_modal(
+        interaction,
+        ModalEvents::TicketQuestions.custom_id(&args),
+        "Open a ticket",
+        inputs
+    ).await?;
+
+    Ok(true)
+}
+
+/// Opens the ticket once the panel's questions are answered.
+pub async fn ticket_questions_handler(
+    client: DiscordClient,
+    interaction: &Box<InteractionCreate>,
+    args: &[&str],
+    values: &ModalValues
+) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+    let (guild_id, panel_id) = match (interaction.guild_id, args.first()) {
+        (Some(guild_id), Some(panel_id)) => (guild_id, panel_id.to_string()),
+        _ => {
+            return Ok(());
+        }
+    };
+    client.defer_ephemeral_interaction(interaction).await?;
+
+    let panel = TicketPanelsQueries::find_by_id(&client.db, panel_id.parse::<i32>()?).await?;
+    let mut answers: Vec<(String, String)> = TicketPanelsQueries::questions(&panel)
+        .into_iter()
+        .zip(TicketAnswers::from_modal(values)?.answers)
+        .filter(|(_, answer)| !answer.is_empty())
+        .collect();
+    answers.extend(linked_message_answer(guild_id, &args[1..]));
+
+    open_ticket_handler(client, interaction, guild_id, panel_id, answers).await
+}
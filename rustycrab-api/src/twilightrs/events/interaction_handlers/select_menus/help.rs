@@ -0,0 +1,51 @@
+use std::error::Error;
+
+use fluent_bundle::FluentArgs;
+use twilight_model::{
+    channel::message::Embed,
+    gateway::payload::incoming::InteractionCreate,
+    id::{ Id, marker::UserMarker },
+};
+
+use crate::twilightrs::{
+    discord_client::DiscordClient,
+    commands::context::{
+        context_command::GuildConfigModel,
+        general::help::{ help_page, HelpView },
+    },
+    utils::select_menus::SelectedValues,
+};
+
+/// Shows the commands of the category picked in the menu of the `help` command.
+pub async fn help_category_handler(
+    client: DiscordClient,
+    config: &GuildConfigModel,
+    interaction: &Box<InteractionCreate>,
+    args: &[&str],
+    values: &SelectedValues
+) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+    let (user_id, category) = match (args.first(), values.first()) {
+        (Some(user_id), Some(category)) => (user_id.parse::<Id<UserMarker>>()?, category),
+        _ => {
+            return Ok(());
+        }
+    };
+
+    if interaction.author_id() != Some(user_id) {
+        let mut args = FluentArgs::new();
+        args.set("user", format!("<@{}>", user_id));
+        let content = client.get_locale_string(
+            &config.locale,
+            "select-menu-not-yours",
+            Some(&args)
+        );
+        client.ephemeral_interaction_reply(interaction, content).await?;
+        return Ok(());
+    }
+
+    let view = HelpView::Category(category.to_string());
+    let (embed, components) = help_page(&client, config, user_id, &view, 0).await;
+    client.update_component_message(interaction, vec![Embed::from(embed)], components).await?;
+
+    Ok(())
+}
@@ -0,0 +1,118 @@
+use std::{ error::Error, sync::Arc, str::FromStr };
+use enum_primitive_derive::Primitive;
+use num_traits::{ ToPrimitive, FromPrimitive };
+use rustycrab_model::modules::GuildModule;
+use twilight_model::{
+    application::interaction::message_component::MessageComponentInteractionData,
+    gateway::payload::incoming::InteractionCreate,
+};
+
+use crate::twilightrs::{
+    dispatchers::ClientDispatchers,
+    discord_client::DiscordClient,
+    commands::context::context_command::GuildConfigModel,
+    utils::{ module_disabled_message, select_menus::SelectedValues },
+};
+
+use self::{
+    tickets::{ ticket_panel_select_handler, ticket_about_message_handler },
+    roles::role_menu_handler,
+    help::help_category_handler,
+    music::music_search_handler,
+};
+
+mod tickets;
+mod roles;
+mod help;
+mod music;
+
+/// What a select menu is for, the first part of its custom id.
+///
+/// Custom ids look like `<event>:<arg>:<arg>`, like the ids of `ButtonEvents`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Primitive)]
+pub enum SelectMenuEvents {
+    TicketPanels = 1,
+    RoleMenu = 2,
+    HelpCategory = 3,
+    MusicSearch = 4,
+    TicketAboutMessage = 5,
+}
+
+impl FromStr for SelectMenuEvents {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse::<i32>() {
+            Ok(num) => SelectMenuEvents::from_i32(num).ok_or(()),
+            Err(_) => Err(()),
+        }
+    }
+}
+
+impl SelectMenuEvents {
+    /// Builds the custom id of a select menu for this event.
+    pub fn custom_id(&self, args: &[&str]) -> String {
+        let mut parts = vec![self.to_i32().unwrap().to_string()];
+        parts.extend(args.iter().map(|arg| arg.to_string()));
+        parts.join(":")
+    }
+
+    /// The guild module the select menus belong to, `None` for menus that are always available.
+    pub fn module(&self) -> Option<GuildModule> {
+        match self {
+            SelectMenuEvents::TicketPanels => Some(GuildModule::Tickets),
+            SelectMenuEvents::RoleMenu => Some(GuildModule::Moderation),
+            SelectMenuEvents::HelpCategory => None,
+            SelectMenuEvents::MusicSearch => Some(GuildModule::Music),
+            SelectMenuEvents::TicketAboutMessage => Some(GuildModule::Tickets),
+        }
+    }
+}
+
+pub async fn select_menu_handlers(
+    client: DiscordClient,
+    config: &GuildConfigModel,
+    interaction: &Box<InteractionCreate>,
+    dispatchers: &Arc<ClientDispatchers>,
+    component_data: &MessageComponentInteractionData
+) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+    let menu_parts: Vec<&str> = component_data.custom_id.split(":").collect();
+    let menu_event = match
+        menu_parts.first().and_then(|part| SelectMenuEvents::from_str(part).ok())
+    {
+        Some(menu_event) => menu_event,
+        None => {
+            return Ok(());
+        }
+    };
+
+    if let Some(module) = menu_event.module() {
+        if !module.is_enabled(config.module_flags) {
+            let content = module_disabled_message(&client, config, module);
+            client.ephemeral_interaction_reply(interaction, content).await?;
+            return Ok(());
+        }
+    }
+
+    let values = SelectedValues::from_data(component_data);
+    let args = &menu_parts[1..];
+    match menu_event {
+        SelectMenuEvents::TicketPanels => {
+            ticket_panel_select_handler(client, interaction, &values).await?;
+        }
+        SelectMenuEvents::RoleMenu => {
+            role_menu_handler(client, config, interaction, component_data, &values).await?;
+        }
+        SelectMenuEvents::HelpCategory => {
+            help_category_handler(client, config, interaction, args, &values).await?;
+        }
+        SelectMenuEvents::MusicSearch => {
+            music_search_handler(client, config, interaction, dispatchers, args, &values).await?;
+        }
+        SelectMenuEvents::TicketAboutMessage => {
+            ticket_about_message_handler(client, interaction, args, &values).await?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use std::{ error::Error, sync::Arc };
+
+use fluent_bundle::FluentArgs;
+use twilight_model::{
+    gateway::payload::incoming::InteractionCreate,
+    id::{ Id, marker::UserMarker },
+};
+
+use crate::twilightrs::{
+    discord_client::DiscordClient,
+    dispatchers::ClientDispatchers,
+    commands::{ context::context_command::GuildConfigModel, invocation::CommandInvocation },
+    utils::select_menus::SelectedValues,
+};
+
+/// Plays the track picked in the results of the `search` command.
+pub async fn music_search_handler(
+    client: DiscordClient,
+    config: &GuildConfigModel,
+    interaction: &Box<InteractionCreate>,
+    dispatchers: &Arc<ClientDispatchers>,
+    args: &[&str],
+    values: &SelectedValues
+) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+    let (user_id, url) = match (args.first(), values.first()) {
+        (Some(user_id), Some(url)) => (user_id.parse::<Id<UserMarker>>()?, url),
+        _ => {
+            return Ok(());
+        }
+    };
+
+    if interaction.author_id() != Some(user_id) {
+        let mut args = FluentArgs::new();
+        args.set("user", format!("<@{}>", user_id));
+        let content = client.get_locale_string(
+            &config.locale,
+            "select-menu-not-yours",
+            Some(&args)
+        );
+        client.ephemeral_interaction_reply(interaction, content).await?;
+        return Ok(());
+    }
+
+    let invocation = CommandInvocation::from_interaction(interaction).ok_or(
+        "interaction has no channel or author"
+    )?;
+
+    // the track is queued by the `play` command, which answers the deferred interaction
+    client.defer_interaction(interaction).await?;
+    let context_commands = &dispatchers.context_commands;
+    context_commands.dispatch_command(client, config, &invocation, "play", &[url]).await;
+
+    Ok(())
+}
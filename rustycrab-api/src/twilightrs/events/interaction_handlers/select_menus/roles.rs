@@ -0,0 +1,97 @@
+use std::error::Error;
+
+use fluent_bundle::FluentArgs;
+use twilight_model::{
+    application::interaction::message_component::MessageComponentInteractionData,
+    channel::message::Component,
+    gateway::payload::incoming::InteractionCreate,
+    id::{ Id, marker::RoleMarker },
+};
+
+use crate::twilightrs::{
+    discord_client::DiscordClient,
+    commands::context::context_command::GuildConfigModel,
+    utils::select_menus::SelectedValues,
+};
+
+/// Gives the member the roles picked in a role menu, and removes the other roles of the menu.
+pub async fn role_menu_handler(
+    client: DiscordClient,
+    config: &GuildConfigModel,
+    interaction: &Box<InteractionCreate>,
+    component_data: &MessageComponentInteractionData,
+    values: &SelectedValues
+) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+    let (guild_id, user_id) = match (interaction.guild_id, interaction.author_id()) {
+        (Some(guild_id), Some(user_id)) => (guild_id, user_id),
+        _ => {
+            return Ok(());
+        }
+    };
+
+    // the roles of the menu are its options, picked values can only be some of them
+    let menu_roles = interaction.message
+        .as_ref()
+        .map(|message| menu_role_ids(&message.components, &component_data.custom_id))
+        .unwrap_or_default();
+    let member_roles = interaction.member
+        .as_ref()
+        .map_or(vec![], |member| member.roles.clone());
+    let picked_roles = values.ids::<RoleMarker>();
+
+    let mut added = Vec::new();
+    let mut removed = Vec::new();
+    let mut failed = false;
+    for role_id in menu_roles {
+        let picked = picked_roles.contains(&role_id);
+        let has_role = member_roles.contains(&role_id);
+        if picked && !has_role {
+            match client.http.add_guild_member_role(guild_id, user_id, role_id).await {
+                Ok(_) => added.push(format!("<@&{}>", role_id)),
+                Err(_) => {
+                    failed = true;
+                }
+            }
+        } else if !picked && has_role {
+            match client.http.remove_guild_member_role(guild_id, user_id, role_id).await {
+                Ok(_) => removed.push(format!("<@&{}>", role_id)),
+                Err(_) => {
+                    failed = true;
+                }
+            }
+        }
+    }
+
+    let mut args = FluentArgs::new();
+    args.set("added", if added.is_empty() { "-".to_string() } else { added.join(", ") });
+    args.set("removed", if removed.is_empty() { "-".to_string() } else { removed.join(", ") });
+    let key = if failed { "rolemenu-update-failed" } else { "rolemenu-updated" };
+    let content = client.get_locale_string(&config.locale, key, Some(&args));
+    client.ephemeral_interaction_reply(interaction, content).await?;
+
+    Ok(())
+}
+
+/// IDs of the roles offered by the select menu with the custom id.
+fn menu_role_ids(components: &[Component], custom_id: &str) -> Vec<Id<RoleMarker>> {
+    components
+        .iter()
+        .filter_map(|component| {
+            match component {
+                Component::ActionRow(row) => Some(row.components.iter()),
+                _ => None,
+            }
+        })
+        .flatten()
+        .filter_map(|component| {
+            match component {
+                Component::SelectMenu(menu) if menu.custom_id == custom_id => {
+                    menu.options.as_ref()
+                }
+                _ => None,
+            }
+        })
+        .flatten()
+        .filter_map(|option| option.value.parse::<Id<RoleMarker>>().ok())
+        .collect()
+}
@@ -0,0 +1,42 @@
+use std::error::Error;
+
+use twilight_model::gateway::payload::incoming::InteractionCreate;
+
+use crate::twilightrs::{
+    discord_client::DiscordClient,
+    events::interaction_handlers::modals::tickets::open_ticket_for_panel,
+    utils::select_menus::SelectedValues,
+};
+
+/// Opens a ticket for the panel picked in the dropdown of a multipanel.
+pub async fn ticket_panel_select_handler(
+    client: DiscordClient,
+    interaction: &Box<InteractionCreate>,
+    values: &SelectedValues
+) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+    let (guild_id, panel_id) = match (interaction.guild_id, values.first()) {
+        (Some(guild_id), Some(panel_id)) => (guild_id, panel_id.to_string()),
+        _ => {
+            return Ok(());
+        }
+    };
+
+    open_ticket_for_panel(client, interaction, guild_id, panel_id, &[]).await
+}
+
+/// Opens a ticket about a message with the picked panel, the message is linked in the ticket.
+pub async fn ticket_about_message_handler(
+    client: DiscordClient,
+    interaction: &Box<InteractionCreate>,
+    args: &[&str],
+    values: &SelectedValues
+) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+    let (guild_id, panel_id) = match (interaction.guild_id, values.first()) {
+        (Some(guild_id), Some(panel_id)) => (guild_id, panel_id.to_string()),
+        _ => {
+            return Ok(());
+        }
+    };
+
+    open_ticket_for_panel(client, interaction, guild_id, panel_id, args).await
+}
@@ -1,41 +1,40 @@
 use rustycrab_model::color::ColorResolvables;
 // src/twilightrs/events/message_create.rs
-use twilight_model::gateway::payload::incoming::MessageCreate;
+use twilight_model::{
+    gateway::payload::incoming::MessageCreate,
+    id::{ Id, marker::UserMarker },
+};
 use std::{ error::Error, sync::Arc };
 
 use crate::{
-    queries::guild_config_queries::GuildConfigQueries,
     twilightrs::{
-        commands,
+        commands::{ self, context::context_command::GuildConfigModel },
         discord_client::DiscordClient,
         dispatchers::ClientDispatchers,
-        utils::afk::check_afk,
-        bot::auto_response::check_autores,
+        event_bus::EventFlow,
         messages::{ DiscordEmbed, DiscordEmbedField },
+        utils::arguments::split_args,
     },
+    queries::guild_config_queries::GuildConfigQueries,
     cdn_avatar,
 };
 
 pub async fn handle_message_create(
     client: DiscordClient,
     msg: &MessageCreate,
+    config: &GuildConfigModel,
     dispatchers: &Arc<ClientDispatchers>
-) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+) -> Result<EventFlow, Box<dyn Error + Send + Sync + 'static>> {
     // Implement your logic for handling the message create event
     // For example, send a response message
     // check for commands
-    if let Some(guild_id) = msg.guild_id {
+    if let Some(_) = msg.guild_id {
         let bot = client.get_bot().await?;
+        // nothing else should react to the bot's own messages
         if bot.id == msg.author.id {
-            return Ok(());
+            return Ok(EventFlow::Stop);
         }
 
-        let config = GuildConfigQueries::get_or_create_config(
-            &client.db,
-            &bot.id.to_string(),
-            &guild_id.get().to_string()
-        ).await?;
-
         let content = msg.content.trim().to_string();
 
         if content == format!("<@{}>", bot.id) && msg.guild_id.is_some() {
@@ -48,7 +47,11 @@ pub async fn handle_message_create(
                         fields: Some(
                             vec![DiscordEmbedField {
                                 name: "Prefix".to_string(),
-                                value: format!("{}", config.prefix),
+                                value: GuildConfigQueries::prefixes(config)
+                                    .iter()
+                                    .map(|prefix| format!("`{}`", prefix))
+                                    .collect::<Vec<String>>()
+                                    .join(", "),
                                 inline: true,
                             }]
                         ),
@@ -57,42 +60,59 @@ pub async fn handle_message_create(
                     }]
                 )
             ).await;
-            return Ok(());
+            return Ok(EventFlow::Stop);
         }
 
-        let command_prefix = if content.starts_with(&config.prefix) {
-            Some(config.prefix.clone())
-        } else if content.starts_with(&format!("<@{}>", bot.id)) {
-            Some(format!("<@{}>", bot.id))
-        } else {
-            None
-        };
-
-        if let Some(prefix) = command_prefix {
-            if let Some(stripped) = content.strip_prefix(&prefix) {
-                let parts: Vec<&str> = stripped
-                    .trim_start_matches(' ')
-                    .split_whitespace()
-                    .collect();
-                if let Some((&cmd_name, cmd_args)) = parts.split_first() {
-                    let _ = commands::context_commands_handler(
-                        Arc::clone(&client),
-                        &config,
-                        &dispatchers,
-                        msg,
-                        cmd_name,
-                        cmd_args
-                    ).await;
-                }
+        if let Some(args_content) = command_content(&content, config, bot.id) {
+            let parts = split_args(args_content);
+            let parts: Vec<&str> = parts
+                .iter()
+                .map(|part| part.as_str())
+                .collect();
+            if let Some((&cmd_name, cmd_args)) = parts.split_first() {
+                let _ = commands::context_commands_handler(
+                    Arc::clone(&client),
+                    config,
+                    &dispatchers,
+                    msg,
+                    args_content,
+                    cmd_name,
+                    cmd_args
+                ).await;
             }
         }
+    }
 
-        // check afk
-        let _ = check_afk(Arc::clone(&client), &config, msg, guild_id).await;
+    Ok(EventFlow::Continue)
+}
 
-        // check for auto-responses
-        let _ = check_autores(Arc::clone(&client), msg, &config).await;
+/// What follows the prefix of a prefix command, its name and arguments to split with
+/// `split_args`. `None` if the message is not a command.
+///
+/// Commands start with one of the guild's prefixes, whatever their case, or with a mention of
+/// the bot unless the guild turned the mention prefix off.
+pub fn command_content<'a>(
+    content: &'a str,
+    config: &GuildConfigModel,
+    bot_id: Id<UserMarker>
+) -> Option<&'a str> {
+    let mut prefixes = GuildConfigQueries::prefixes(config);
+    if config.mention_prefix != 0 {
+        prefixes.push(format!("<@{}>", bot_id));
+        prefixes.push(format!("<@!{}>", bot_id));
     }
+    // the longest prefix wins, so `!!` isn't read as `!` followed by a command named `!`
+    prefixes.sort_by_key(|prefix| std::cmp::Reverse(prefix.len()));
 
-    Ok(())
+    prefixes.iter().find_map(|prefix| strip_prefix_ignore_case(content, prefix))
+}
+
+/// `content` without `prefix`, comparing them regardless of case.
+fn strip_prefix_ignore_case<'a>(content: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = content.get(..prefix.len())?;
+    if head.to_lowercase() == prefix.to_lowercase() {
+        Some(&content[prefix.len()..])
+    } else {
+        None
+    }
 }
@@ -1,30 +1,68 @@
 use std::error::Error;
 
-use twilight_model::gateway::payload::incoming::MessageDelete;
+use async_trait::async_trait;
+use rustycrab_model::modules::GuildModule;
+use twilight_gateway::Event;
+use twilight_model::gateway::{ event::EventType, payload::incoming::MessageDelete };
 
-use crate::twilightrs::discord_client::DiscordClient;
+use crate::{
+    twilightrs::{
+        discord_client::DiscordClient,
+        commands::context::context_command::GuildConfigModel,
+        event_bus::{ EventContext, EventFlow, EventListener },
+    },
+    utilities::app_error::BoxedError,
+};
 
-pub async fn handle_message_delete(
-    client: DiscordClient,
+/// Keeps the last deleted messages of each channel for the `snipe` command.
+pub struct SnipeListener {}
+
+#[async_trait]
+impl EventListener for SnipeListener {
+    fn name(&self) -> &'static str {
+        "snipe"
+    }
+
+    fn events(&self) -> Vec<EventType> {
+        vec![EventType::MessageDelete]
+    }
+
+    fn is_enabled(&self, config: &GuildConfigModel) -> bool {
+        GuildModule::Snipe.is_enabled(config.module_flags)
+    }
+
+    async fn handle(&self, ctx: &EventContext) -> Result<EventFlow, BoxedError> {
+        if let Event::MessageDelete(event) = &ctx.event {
+            handle_message_delete(&ctx.client, event)?;
+        }
+
+        Ok(EventFlow::Continue)
+    }
+}
+
+pub fn handle_message_delete(
+    client: &DiscordClient,
     event: &MessageDelete
 ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
-    let mut messages = client.deleted_messages.write().unwrap(); // Acquire a write lock
+    {
+        let mut messages = client.deleted_messages.write().unwrap(); // Acquire a write lock
 
-    // Check if the deleted message is in the cache
-    if let Some(cached_message) = client.cache.message(event.id) {
-        // Store the deleted message in the map
-        let channel_messages = messages.entry(event.channel_id).or_insert_with(Vec::new);
+        // Check if the deleted message is in the cache
+        if let Some(cached_message) = client.cache.message(event.id) {
+            // Store the deleted message in the map
+            let channel_messages = messages.entry(event.channel_id).or_insert_with(Vec::new);
 
-        // Ensure the list doesn't exceed 10 messages
-        if channel_messages.len() >= 10 {
-            channel_messages.remove(0); // Remove the oldest message
-        }
+            // Ensure the list doesn't exceed 10 messages
+            if channel_messages.len() >= 10 {
+                channel_messages.remove(0); // Remove the oldest message
+            }
 
-        // Add the new deleted message
-        channel_messages.push(cached_message.value().clone());
-        // println!("saved deleted cached message {}", cached_message.value().content());
-    } else {
-        // println!("can't find cached message with id {}", event.id.to_string());
+            // Add the new deleted message
+            channel_messages.push(cached_message.value().clone());
+            // println!("saved deleted cached message {}", cached_message.value().content());
+        } else {
+            // println!("can't find cached message with id {}", event.id.to_string());
+        }
     }
 
     Ok(())
@@ -0,0 +1,113 @@
+// src/twilightrs/events/message_update.rs
+use twilight_model::{
+    gateway::payload::incoming::{ MessageCreate, MessageUpdate },
+    id::{ Id, marker::MessageMarker },
+};
+use std::{ error::Error, sync::Arc, time::{ Duration, SystemTime, UNIX_EPOCH } };
+
+use crate::twilightrs::{
+    bot::action_logs::CachedBefore,
+    commands::{
+        context::context_command::GuildConfigModel,
+        command_replies::EDIT_RERUN_WINDOW,
+        invocation::CommandInvocation,
+    },
+    discord_client::DiscordClient,
+    dispatchers::ClientDispatchers,
+    event_bus::EventFlow,
+    utils::arguments::split_args,
+};
+
+use super::message_create::command_content;
+
+/// Milliseconds between the Unix epoch and the first second of 2015, the Discord epoch.
+const DISCORD_EPOCH_MILLIS: u64 = 1_420_070_400_000;
+
+/// Runs a prefix command again when its message is edited shortly after being sent, so a typo
+/// can be fixed without sending the command again.
+pub async fn handle_message_update(
+    client: DiscordClient,
+    event: &MessageUpdate,
+    before: &Option<CachedBefore>,
+    config: &GuildConfigModel,
+    dispatchers: &Arc<ClientDispatchers>
+) -> Result<EventFlow, Box<dyn Error + Send + Sync + 'static>> {
+    let (guild_id, content) = match (event.guild_id, &event.content) {
+        (Some(guild_id), Some(content)) => (guild_id, content.trim()),
+        _ => {
+            return Ok(EventFlow::Continue);
+        }
+    };
+    if event.author.as_ref().map_or(false, |author| author.bot) {
+        return Ok(EventFlow::Continue);
+    }
+
+    // embeds being resolved also trigger updates, only actual edits run the command again
+    match before {
+        Some(CachedBefore::Message(message)) if message.content().trim() == content => {
+            return Ok(EventFlow::Continue);
+        }
+        None if event.edited_timestamp.is_none() => {
+            return Ok(EventFlow::Continue);
+        }
+        _ => {}
+    }
+
+    let sent_for = SystemTime::now().duration_since(sent_at(event.id)).unwrap_or_default();
+    if sent_for > EDIT_RERUN_WINDOW {
+        return Ok(EventFlow::Continue);
+    }
+
+    let bot = client.get_bot().await?;
+    let args_content = match command_content(content, config, bot.id) {
+        Some(args_content) => args_content,
+        None => {
+            return Ok(EventFlow::Continue);
+        }
+    };
+    let parts = split_args(args_content);
+    let parts: Vec<&str> = parts
+        .iter()
+        .map(|part| part.as_str())
+        .collect();
+    let (&cmd_name, cmd_args) = match parts.split_first() {
+        Some(split) => split,
+        None => {
+            return Ok(EventFlow::Continue);
+        }
+    };
+    // a run that didn't execute anything, like a typo in the command's arguments, can be fixed
+    let previous_run_failed = client.command_replies.not_executed(event.id);
+    if !previous_run_failed && !dispatchers.context_commands.reruns_on_edit(cmd_name) {
+        return Ok(EventFlow::Continue);
+    }
+
+    // updates only carry what changed, the command needs the whole message
+    let mut message = client.http.message(event.channel_id, event.id).await?.model().await?;
+    if message.author.bot {
+        return Ok(EventFlow::Continue);
+    }
+    message.guild_id = Some(guild_id);
+
+    let mut invocation = CommandInvocation::from_message(&MessageCreate(message))
+        .with_args_content(args_content)
+        .with_previous_reply(client.command_replies.get(event.id));
+    if let Some(member) = client.cache.member(guild_id, invocation.author.id) {
+        invocation.member_roles = member.roles().to_vec();
+    }
+
+    dispatchers.context_commands.dispatch_command(
+        Arc::clone(&client),
+        config,
+        &invocation,
+        cmd_name,
+        cmd_args
+    ).await;
+
+    Ok(EventFlow::Continue)
+}
+
+/// When a message was sent, from the timestamp in its snowflake ID.
+fn sent_at(message_id: Id<MessageMarker>) -> SystemTime {
+    UNIX_EPOCH + Duration::from_millis((message_id.get() >> 22) + DISCORD_EPOCH_MILLIS)
+}
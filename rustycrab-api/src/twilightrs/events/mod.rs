@@ -2,23 +2,24 @@
 use std::{ error::Error, sync::Arc };
 use twilight_gateway::{ Event, Shard, stream::ShardEventStream };
 
-use crate::spawn;
+use crate::{ spawn, metrics::metrics };
 use futures::StreamExt;
 
-use self::{
-    message_create::handle_message_create,
-    message_delete::handle_message_delete,
-    interaction_handlers::handle_interaction_create,
-    voice_updates::handle_voice_state_update,
+use super::{
+    discord_client::DiscordClient,
+    dispatchers::ClientDispatchers,
+    event_bus::EventBus,
+    event_recorder::EventRecorder,
+    bot::action_logs::CachedBefore,
 };
 
-use super::{ discord_client::DiscordClient, dispatchers::ClientDispatchers };
+pub mod commands;
+pub mod message_create;
+pub mod message_update;
+pub mod message_delete;
+pub mod interaction_handlers;
 
-mod message_create;
-mod message_delete;
-mod interaction_handlers;
-
-mod voice_updates;
+pub mod voice_updates;
 pub async fn handle_bot_events(
     mut shards: Vec<Shard>,
     client: DiscordClient
@@ -28,6 +29,10 @@ pub async fn handle_bot_events(
     // Arc is a common pattern in Rust for sharing data across asynchronous tasks when cloning is not feasible or too expensive.
     // We don't want to create dispatcher every single time a new event is received, so this approach might be a good one
     let dispatchers = Arc::new(ClientDispatchers::new());
+    // bot features listening to the gateway events, created once for the same reason
+    let event_bus = Arc::new(EventBus::with_default_listeners(dispatchers));
+    let bot_label = client.bot_id.to_string();
+    let recorder = EventRecorder::from_env(client.bot_id);
     let mut stream: ShardEventStream<'_> = ShardEventStream::new(shards.iter_mut());
     loop {
         let event = match stream.next().await {
@@ -46,58 +51,42 @@ pub async fn handle_bot_events(
             }
         };
 
-        if let Event::MessageDelete(_) = &event {
-        } else {
-            // Update the cache.
-            client.cache.update(&event);
+        metrics().record_gateway_event(&bot_label, &format!("{:?}", event.kind()));
+        if let Some(recorder) = &recorder {
+            recorder.record(&event);
         }
-        client.standby.process(&event);
-        client.voice_music_manager.songbird.process(&event).await;
 
-        spawn(handle_event(Arc::clone(&client), event.clone(), dispatchers.clone()));
+        let before = prepare_event(&client, &event).await;
+
+        spawn(handle_event(Arc::clone(&client), event, Arc::clone(&event_bus), before));
     }
 
     Ok(())
 }
 
-async fn handle_event(
-    client: DiscordClient,
-    event: Event,
-    dispatchers: Arc<ClientDispatchers>
-) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
-    // event logger
-
-    let handle_event_result = match event.clone() {
-        Event::MessageCreate(message_create) => {
-            handle_message_create(client, &message_create, &dispatchers).await
-        }
-        Event::Ready(ready) => {
-            println!("[{}#{:04}] Shard is ready", ready.user.name, ready.user.discriminator);
-            println!(
-                "[{}#{:04}] Registering slash commands",
-                ready.user.name,
-                ready.user.discriminator
-            );
-            let _ = dispatchers.slash_commands.register_commands(Arc::clone(&client)).await;
-
-            Ok(())
-        }
-        Event::MessageDelete(message_delete) => {
-            handle_message_delete(Arc::clone(&client), &message_delete).await
-        }
-        Event::InteractionCreate(interaction) => {
-            handle_interaction_create(Arc::clone(&client), &interaction, &dispatchers).await
-        }
-        Event::VoiceStateUpdate(update) => { handle_voice_state_update(client, &update).await }
-        _ => { Ok(()) }
-    };
+/// Updates the client's cache and waiters with an event, before its handlers run.
+///
+/// Returns what the cache knew before the event, for the action logs.
+pub async fn prepare_event(client: &DiscordClient, event: &Event) -> Option<CachedBefore> {
+    let before = CachedBefore::from_cache(&client.cache, event);
 
-    // handler event error
-    if let Err(e) = handle_event_result {
-        {
-            eprintln!("Error handling event {event:?}: {e:?}");
-        }
+    // deleted messages stay in the cache for the snipe command
+    if let Event::MessageDelete(_) = event {
+    } else {
+        client.cache.update(event);
     }
+    client.standby.process(event);
+    client.voice_music_manager.songbird.process(event).await;
 
-    Ok(())
+    before
+}
+
+/// Runs the listeners of an event, once [`prepare_event`] updated the client with it.
+pub async fn handle_event(
+    client: DiscordClient,
+    event: Event,
+    event_bus: Arc<EventBus>,
+    before: Option<CachedBefore>
+) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+    event_bus.dispatch(client, event, before).await
 }
@@ -1,20 +1,45 @@
-use std::error::Error;
+use std::{ error::Error, sync::Arc };
 
+use async_trait::async_trait;
 use rustycrab_model::{ color::ColorResolvables, error::BoxedError, music::PlayerLoopState };
 
+use twilight_gateway::Event;
 use twilight_model::{
-    gateway::payload::incoming::VoiceStateUpdate,
+    gateway::{ event::EventType, payload::incoming::VoiceStateUpdate },
     id::{ marker::GuildMarker, Id },
 };
 
 use crate::twilightrs::{
     discord_client::{ DiscordClient, MessageContent },
+    event_bus::{ EventContext, EventFlow, EventListener },
     messages::DiscordEmbed,
 };
 
 use self::update_type::{ get_update_type, VoiceUpdateType };
 
-mod update_type;
+pub mod update_type;
+
+/// Pauses and resumes the music player as members leave and join the bot's voice channel.
+pub struct VoiceListener {}
+
+#[async_trait]
+impl EventListener for VoiceListener {
+    fn name(&self) -> &'static str {
+        "voice"
+    }
+
+    fn events(&self) -> Vec<EventType> {
+        vec![EventType::VoiceStateUpdate]
+    }
+
+    async fn handle(&self, ctx: &EventContext) -> Result<EventFlow, BoxedError> {
+        if let Event::VoiceStateUpdate(update) = &ctx.event {
+            handle_voice_state_update(Arc::clone(&ctx.client), update).await?;
+        }
+
+        Ok(EventFlow::Continue)
+    }
+}
 
 pub async fn handle_voice_state_update(
     client: DiscordClient,
@@ -5,4 +5,6 @@ pub mod utils;
 pub mod commands;
 
 pub mod dispatchers;
+pub mod event_bus;
+pub mod event_recorder;
 pub mod bot;
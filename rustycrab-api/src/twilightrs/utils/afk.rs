@@ -1,8 +1,12 @@
-use std::error::Error;
+use std::{ error::Error, sync::Arc };
 
+use async_trait::async_trait;
+use rustycrab_model::{ afk::UserAfkStatus, modules::GuildModule };
 use fluent_bundle::FluentArgs;
+use twilight_gateway::Event;
 use twilight_model::{
-    gateway::payload::incoming::MessageCreate,
+    gateway::{ event::EventType, payload::incoming::{ MessageCreate, InteractionCreate } },
+    http::interaction::{ InteractionResponse, InteractionResponseType, InteractionResponseData },
     id::{ Id, marker::{ GuildMarker, UserMarker } },
     channel::message::{ component::{ Button, ActionRow }, Component, Embed },
 };
@@ -11,11 +15,81 @@ use crate::{
     twilightrs::{
         discord_client::DiscordClient,
         commands::context::context_command::GuildConfigModel,
+        event_bus::{ EventContext, EventFlow, EventListener },
         messages::DiscordEmbed,
     },
-    utilities::utils::color_to_button_style,
+    utilities::{
+        app_error::BoxedError,
+        utils::{ color_to_button_style, current_unix_timestamp },
+    },
 };
 
+/// Marks a member AFK with an optional message, and prefixes their nickname with `[AFK]`.
+pub async fn set_afk(
+    client: &DiscordClient,
+    guild_id: Id<GuildMarker>,
+    user_id: Id<UserMarker>,
+    message: Option<String>
+) -> Result<(), BoxedError> {
+    let since = current_unix_timestamp()?;
+
+    let guild_member = client.http.guild_member(guild_id, user_id).await?.model().await?;
+    // Update or set the AFK status
+    {
+        let mut afk_users = client.afk_users.write().unwrap();
+        let user_afk_status = afk_users
+            .entry(guild_id)
+            .or_default()
+            .entry(user_id)
+            .or_insert(UserAfkStatus::new(None, since));
+
+        user_afk_status.message = message;
+        user_afk_status.since = since;
+    } // The lock is dropped here
+
+    let current_name = if let Some(nickname) = guild_member.nick {
+        nickname
+    } else {
+        guild_member.user.name
+    };
+
+    if !current_name.trim().starts_with("[AFK]") {
+        let _ = client.http
+            .update_guild_member(guild_id, user_id)
+            .nick(Some(&format!("[AFK] {}", current_name)))?.await;
+    }
+
+    Ok(())
+}
+
+/// Marks the member of an interaction AFK and answers the interaction.
+pub async fn set_afk_from_interaction(
+    client: &DiscordClient,
+    locale: &str,
+    interaction: &Box<InteractionCreate>,
+    message: Option<String>
+) -> Result<(), BoxedError> {
+    let guild_id = interaction.guild_id.ok_or("command-guildonly")?;
+    let user_id = interaction.author_id().ok_or("interaction has no author")?;
+    set_afk(client, guild_id, user_id, message).await?;
+
+    let mut args = FluentArgs::new();
+    args.set("user", format!("<@{}>", user_id));
+    client.http.interaction(interaction.application_id).create_response(
+        interaction.id,
+        &interaction.token,
+        &(InteractionResponse {
+            kind: InteractionResponseType::ChannelMessageWithSource,
+            data: Some(InteractionResponseData {
+                content: Some(client.get_locale_string(locale, "command-afk-success", Some(&args))),
+                ..Default::default()
+            }),
+        })
+    ).await?;
+
+    Ok(())
+}
+
 pub async fn check_afk(
     client: DiscordClient,
     config: &GuildConfigModel,
@@ -157,12 +231,18 @@ pub async fn check_afk(
 
                     if let Ok(message) = message {
                         if let Ok(message) = message.model().await {
-                            // Schedule a task to update the message after 5 minutes
-                            let channel_id = msg.channel_id;
-                            tokio::time::sleep(tokio::time::Duration::from_secs(30)).await; // Wait for 5 minutes
-                            let _ = client.http
-                                .update_message(channel_id, message.id)
-                                .components(Some(&vec![]))?.await;
+                            // remove the button after a while, without holding up the event
+                            let client_cloned = client.clone();
+                            tokio::spawn(async move {
+                                tokio::time::sleep(tokio::time::Duration::from_secs(30)).await;
+                                if
+                                    let Ok(update) = client_cloned.http
+                                        .update_message(message.channel_id, message.id)
+                                        .components(Some(&[]))
+                                {
+                                    let _ = update.await;
+                                }
+                            });
                         }
                     }
                 }
@@ -172,3 +252,35 @@ pub async fn check_afk(
 
     Ok(())
 }
+
+/// Clears the AFK status of members talking again and notifies about mentioned AFK members.
+pub struct AfkListener {}
+
+#[async_trait]
+impl EventListener for AfkListener {
+    fn name(&self) -> &'static str {
+        "afk"
+    }
+
+    fn events(&self) -> Vec<EventType> {
+        vec![EventType::MessageCreate]
+    }
+
+    fn priority(&self) -> i32 {
+        10
+    }
+
+    fn is_enabled(&self, config: &GuildConfigModel) -> bool {
+        GuildModule::Afk.is_enabled(config.module_flags)
+    }
+
+    async fn handle(&self, ctx: &EventContext) -> Result<EventFlow, BoxedError> {
+        if let (Event::MessageCreate(msg), Some(config)) = (&ctx.event, &ctx.config) {
+            if let Some(guild_id) = msg.guild_id {
+                let _ = check_afk(Arc::clone(&ctx.client), config, msg, guild_id).await;
+            }
+        }
+
+        Ok(EventFlow::Continue)
+    }
+}
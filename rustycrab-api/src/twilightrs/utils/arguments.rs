@@ -0,0 +1,279 @@
+use std::time::Duration;
+
+/// Splits a command's content into arguments, as typed.
+///
+/// Arguments are separated by whitespace, except inside double quotes: `"multi word"` is a
+/// single argument. The quotes are kept, `unquote` removes them from the arguments read as a
+/// single value.
+pub fn split_args(content: &str) -> Vec<String> {
+    arg_spans(content)
+        .into_iter()
+        .map(|(start, end)| content[start..end].to_string())
+        .collect()
+}
+
+/// Byte ranges of the arguments of a command's content, see `split_args`.
+pub fn arg_spans(content: &str) -> Vec<(usize, usize)> {
+    let mut spans: Vec<(usize, usize)> = Vec::new();
+    let mut start: Option<usize> = None;
+    let mut in_quotes = false;
+
+    let mut chars = content.char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+        if c.is_whitespace() && !in_quotes {
+            if let Some(arg_start) = start.take() {
+                spans.push((arg_start, index));
+            }
+            continue;
+        }
+
+        start.get_or_insert(index);
+        match c {
+            '\\' if matches!(chars.peek(), Some((_, '"' | '“' | '”'))) => {
+                chars.next();
+            }
+            // mobile keyboards type curly quotes
+            '"' | '“' | '”' => {
+                in_quotes = !in_quotes;
+            }
+            _ => {}
+        }
+    }
+    if let Some(arg_start) = start {
+        spans.push((arg_start, content.len()));
+    }
+
+    spans
+}
+
+/// An argument without its quotes, a quote escaped with a backslash is kept.
+pub fn unquote(arg: &str) -> String {
+    let mut unquoted = String::new();
+    let mut chars = arg.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if matches!(chars.peek(), Some('"' | '“' | '”')) => {
+                unquoted.extend(chars.next());
+            }
+            '"' | '“' | '”' => {}
+            c => unquoted.push(c),
+        }
+    }
+
+    unquoted
+}
+
+/// Parses a duration like `1h30m`, `2d` or `90s`, a number without unit is read as minutes.
+pub fn parse_duration(arg: &str) -> Option<Duration> {
+    let arg = arg.trim().to_lowercase();
+    if arg.is_empty() {
+        return None;
+    }
+    if let Ok(minutes) = arg.parse::<u64>() {
+        return Some(Duration::from_secs(minutes.checked_mul(60)?));
+    }
+
+    let mut seconds: u64 = 0;
+    let mut number = String::new();
+    for c in arg.chars() {
+        if c.is_ascii_digit() {
+            number.push(c);
+            continue;
+        }
+
+        let unit: u64 = match c {
+            's' => 1,
+            'm' => 60,
+            'h' => 60 * 60,
+            'd' => 24 * 60 * 60,
+            'w' => 7 * 24 * 60 * 60,
+            _ => {
+                return None;
+            }
+        };
+        let value = number.parse::<u64>().ok()?;
+        seconds = seconds.checked_add(value.checked_mul(unit)?)?;
+        number.clear();
+    }
+
+    // a trailing number without unit, like `1h30`
+    if !number.is_empty() {
+        return None;
+    }
+
+    Some(Duration::from_secs(seconds))
+}
+
+pub fn parse_bool(arg: &str) -> Option<bool> {
+    match arg.to_lowercase().as_str() {
+        "true" | "yes" | "y" | "on" | "enable" | "enabled" | "1" => Some(true),
+        "false" | "no" | "n" | "off" | "disable" | "disabled" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+/// Checks that the argument is an emoji, either a custom `<:name:id>` emoji or an unicode one.
+pub fn parse_emoji(arg: &str) -> Option<String> {
+    let is_custom =
+        (arg.starts_with("<:") || arg.starts_with("<a:")) &&
+        arg.ends_with('>') &&
+        arg
+            .trim_end_matches('>')
+            .rsplit(':')
+            .next()
+            .map_or(false, |id| id.parse::<u64>().is_ok());
+    let is_unicode = is_emoji_sequence(arg);
+
+    if is_custom || is_unicode {
+        Some(arg.to_string())
+    } else {
+        None
+    }
+}
+
+/// Code points with the Extended_Pictographic or Emoji_Presentation property.
+const PICTOGRAPHIC_RANGES: &[(u32, u32)] = &[
+    (0x00a9, 0x00a9),
+    (0x00ae, 0x00ae),
+    (0x203c, 0x203c),
+    (0x2049, 0x2049),
+    (0x2122, 0x2122),
+    (0x2139, 0x2139),
+    (0x2194, 0x2199),
+    (0x21a9, 0x21aa),
+    (0x231a, 0x231b),
+    (0x2328, 0x2328),
+    (0x23cf, 0x23cf),
+    (0x23e9, 0x23f3),
+    (0x23f8, 0x23fa),
+    (0x24c2, 0x24c2),
+    (0x25aa, 0x25ab),
+    (0x25b6, 0x25b6),
+    (0x25c0, 0x25c0),
+    (0x25fb, 0x25fe),
+    (0x2600, 0x27bf),
+    (0x2934, 0x2935),
+    (0x2b05, 0x2b07),
+    (0x2b1b, 0x2b1c),
+    (0x2b50, 0x2b50),
+    (0x2b55, 0x2b55),
+    (0x3030, 0x3030),
+    (0x303d, 0x303d),
+    (0x3297, 0x3297),
+    (0x3299, 0x3299),
+    (0x1f000, 0x1f0ff),
+    (0x1f10d, 0x1f10f),
+    (0x1f12f, 0x1f12f),
+    (0x1f16c, 0x1f171),
+    (0x1f17e, 0x1f17f),
+    (0x1f18e, 0x1f18e),
+    (0x1f191, 0x1f19a),
+    (0x1f1ad, 0x1f1e5),
+    (0x1f201, 0x1f20f),
+    (0x1f21a, 0x1f21a),
+    (0x1f22f, 0x1f22f),
+    (0x1f232, 0x1f23a),
+    (0x1f23c, 0x1f23f),
+    (0x1f249, 0x1f3fa),
+    (0x1f400, 0x1f53d),
+    (0x1f546, 0x1f64f),
+    (0x1f680, 0x1f6ff),
+    (0x1f774, 0x1f77f),
+    (0x1f7d5, 0x1f7ff),
+    (0x1f80c, 0x1f80f),
+    (0x1f848, 0x1f84f),
+    (0x1f85a, 0x1f85f),
+    (0x1f888, 0x1f88f),
+    (0x1f8ae, 0x1f8ff),
+    (0x1f90c, 0x1f93a),
+    (0x1f93c, 0x1f945),
+    (0x1f947, 0x1faff),
+    (0x1fc00, 0x1fffd),
+];
+
+const ZWJ: char = '\u{200d}';
+const VARIATION_SELECTOR: char = '\u{fe0f}';
+const TEXT_SELECTOR: char = '\u{fe0e}';
+const KEYCAP: char = '\u{20e3}';
+const TAG_END: char = '\u{e007f}';
+
+fn is_pictographic(c: char) -> bool {
+    let c = c as u32;
+    PICTOGRAPHIC_RANGES.iter().any(|&(start, end)| start <= c && c <= end)
+}
+
+fn is_regional_indicator(c: char) -> bool {
+    ('\u{1f1e6}'..='\u{1f1ff}').contains(&c)
+}
+
+/// Checks that the text is exactly one emoji: a pictograph with its variation selector, skin tone
+/// and tags, a keycap like 1️⃣, a flag, or such emojis joined by zero width joiners.
+fn is_emoji_sequence(text: &str) -> bool {
+    let mut chars = text.chars().peekable();
+    loop {
+        match chars.next() {
+            // keycaps: a digit, `#` or `*`, then the keycap combining mark
+            Some(c) if c.is_ascii_digit() || c == '#' || c == '*' => {
+                chars.next_if_eq(&VARIATION_SELECTOR);
+                if chars.next() != Some(KEYCAP) {
+                    return false;
+                }
+            }
+            // flags are pairs of regional indicators
+            Some(c) if is_regional_indicator(c) => {
+                if !chars.next().map_or(false, is_regional_indicator) {
+                    return false;
+                }
+            }
+            Some(c) if is_pictographic(c) => {
+                if chars.next_if_eq(&VARIATION_SELECTOR).is_none() {
+                    chars.next_if_eq(&TEXT_SELECTOR);
+                }
+                chars.next_if(|&c| ('\u{1f3fb}'..='\u{1f3ff}').contains(&c));
+                // subdivision flags like England's end with tag characters
+                let mut tagged = false;
+                while chars.next_if(|&c| ('\u{e0020}'..='\u{e007e}').contains(&c)).is_some() {
+                    tagged = true;
+                }
+                if tagged && chars.next() != Some(TAG_END) {
+                    return false;
+                }
+            }
+            _ => {
+                return false;
+            }
+        }
+
+        match chars.next() {
+            None => {
+                return true;
+            }
+            Some(ZWJ) => {}
+            Some(_) => {
+                return false;
+            }
+        }
+    }
+}
+
+/// Finds the choice matching the argument, ignoring case.
+pub fn parse_choice(arg: &str, choices: &[&'static str]) -> Option<String> {
+    choices
+        .iter()
+        .find(|choice| choice.eq_ignore_ascii_case(arg))
+        .map(|choice| choice.to_string())
+}
+
+/// Formats a duration the way `parse_duration` reads it, like `1d 2h 30m`.
+pub fn format_duration_units(duration: &Duration) -> String {
+    let mut seconds = duration.as_secs();
+    let mut parts: Vec<String> = Vec::new();
+    for (unit, unit_seconds) in [("d", 24 * 60 * 60), ("h", 60 * 60), ("m", 60), ("s", 1)] {
+        if seconds >= unit_seconds {
+            parts.push(format!("{}{}", seconds / unit_seconds, unit));
+            seconds %= unit_seconds;
+        }
+    }
+
+    if parts.is_empty() { "0s".to_string() } else { parts.join(" ") }
+}
@@ -1,11 +1,15 @@
 use twilight_http::Client as HttpClient;
 use twilight_model::{
     user::User,
-    id::{ Id, marker::{ UserMarker, ChannelMarker } },
+    id::{ Id, marker::{ UserMarker, ChannelMarker, GuildMarker } },
     channel::Channel,
+    guild::{ Member, Role },
 };
 
-use crate::utilities::app_error::AppError;
+use crate::{
+    twilightrs::{ discord_client::DiscordClient, utils::arguments::unquote },
+    utilities::app_error::AppError,
+};
 
 fn extract_id(arg: &str) -> Option<u64> {
     // Check if the argument is in one of the mention formats: <@>, <@!>, <@&>, or <#>
@@ -188,3 +192,88 @@ pub async fn greedy_channels<'a>(
     let remaining_args = &args[last_index + 1..];
     (channels, remaining_args)
 }
+
+/// Parses a single role mention, ID or name.
+pub async fn greedy_role<'a>(
+    client: &'a DiscordClient,
+    guild_id: Id<GuildMarker>,
+    args: &'a [&'a str]
+) -> (Option<Role>, &'a [&'a str]) {
+    if let Some(&first_arg) = args.first() {
+        // names with spaces are quoted
+        if let Ok(role) = client.find_role(guild_id, &unquote(first_arg)).await {
+            return (Some(role), &args[1..]);
+        }
+    }
+    (None, args)
+}
+
+/// Parses a single guild member from a mention, an ID, or a username, display name or nickname.
+///
+/// Names are looked up in the cached members first, then searched through the API.
+pub async fn greedy_member<'a>(
+    client: &'a DiscordClient,
+    guild_id: Id<GuildMarker>,
+    args: &'a [&'a str]
+) -> (Option<Member>, &'a [&'a str]) {
+    let first_arg = match args.first() {
+        Some(&first_arg) => first_arg,
+        None => {
+            return (None, args);
+        }
+    };
+
+    // names with spaces are quoted
+    let name = unquote(first_arg);
+    let user_id = match extract_id(first_arg) {
+        Some(id) if id != 0 => Some(Id::<UserMarker>::new(id)),
+        _ => find_cached_member(client, guild_id, &name),
+    };
+
+    if let Some(user_id) = user_id {
+        if let Ok(response) = client.http.guild_member(guild_id, user_id).await {
+            if let Ok(member) = response.model().await {
+                return (Some(member), &args[1..]);
+            }
+        }
+        return (None, args);
+    }
+
+    if let Ok(request) = client.http.search_guild_members(guild_id, &name).limit(1) {
+        if let Ok(response) = request.await {
+            if let Ok(members) = response.models().await {
+                if let Some(member) = members.into_iter().next() {
+                    return (Some(member), &args[1..]);
+                }
+            }
+        }
+    }
+
+    (None, args)
+}
+
+fn find_cached_member(
+    client: &DiscordClient,
+    guild_id: Id<GuildMarker>,
+    name: &str
+) -> Option<Id<UserMarker>> {
+    let member_ids = client.cache.guild_members(guild_id)?;
+
+    member_ids
+        .iter()
+        .find(|&&user_id| {
+            let nick_matches = client.cache
+                .member(guild_id, user_id)
+                .and_then(|member| member.nick().map(|nick| nick.eq_ignore_ascii_case(name)))
+                .unwrap_or(false);
+
+            nick_matches ||
+                client.cache.user(user_id).map_or(false, |user| {
+                    user.name.eq_ignore_ascii_case(name) ||
+                        user.global_name
+                            .as_deref()
+                            .map_or(false, |global_name| global_name.eq_ignore_ascii_case(name))
+                })
+        })
+        .copied()
+}
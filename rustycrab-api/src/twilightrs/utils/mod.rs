@@ -1,36 +1,64 @@
 use std::error::Error;
 
 use fluent_bundle::FluentArgs;
-use rustycrab_model::color::ColorResolvables;
-use twilight_model::{
-    gateway::payload::incoming::MessageCreate,
-    channel::message::{ component::Button, Component },
-};
+use rustycrab_model::{ color::ColorResolvables, modules::GuildModule };
+use twilight_model::channel::message::{ component::Button, Component };
 
-use crate::cdn_avatar;
+use crate::{
+    cdn_avatar,
+    queries::command_usages_queries::{ CommandUsageRecord, CommandUsagesQueries },
+};
 
 use super::{
     discord_client::{ DiscordClient, MessageContent },
-    commands::context::context_command::GuildConfigModel,
+    commands::{ context::context_command::GuildConfigModel, invocation::CommandInvocation },
     messages::DiscordEmbed,
 };
 
 pub mod greedy;
+pub mod arguments;
 pub mod discord_embed_builder;
 pub mod afk;
+pub mod modals;
+pub mod select_menus;
+
+/// Records a command invocation in the background, so the database write doesn't slow the bot.
+pub fn record_command_usage(
+    client: &DiscordClient,
+    config: &GuildConfigModel,
+    usage: CommandUsageRecord
+) {
+    let db = client.db.clone();
+    let config = config.clone();
+    tokio::spawn(async move {
+        if let Err(e) = CommandUsagesQueries::record(&db, &config, usage).await {
+            eprintln!("Failed to record command usage: {:?}", e);
+        }
+    });
+}
+
+/// The localized message telling users that a module is disabled in the guild.
+pub fn module_disabled_message(
+    client: &DiscordClient,
+    config: &GuildConfigModel,
+    module: GuildModule
+) -> String {
+    let mut args = FluentArgs::new();
+    args.set("module", module.name());
+    client.get_locale_string(&config.locale, "module-disabled", Some(&args))
+}
 
 pub async fn reply_command(
     client: &DiscordClient,
     config: &GuildConfigModel,
-    msg: &MessageCreate,
+    msg: &CommandInvocation,
     key: &str,
     args: Option<FluentArgs<'_>>,
     color: ColorResolvables
 ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
     let content = client.get_locale_string(&config.locale, key, args.as_ref());
-    client.reply_message(
-        msg.channel_id,
-        msg.id,
+    msg.reply(
+        client,
         MessageContent::DiscordEmbeds(
             vec![DiscordEmbed {
                 description: Some(content),
@@ -54,14 +82,14 @@ pub async fn reply_command(
 pub async fn send_command_response(
     client: &DiscordClient,
     config: &GuildConfigModel,
-    msg: &MessageCreate,
+    msg: &CommandInvocation,
     key: &str,
     args: Option<FluentArgs<'_>>,
     color: ColorResolvables
 ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
     let content = client.get_locale_string(&config.locale, key, args.as_ref());
-    client.send_message(
-        msg.channel_id,
+    msg.send(
+        client,
         MessageContent::DiscordEmbeds(
             vec![DiscordEmbed {
                 description: Some(content),
@@ -0,0 +1,80 @@
+use std::collections::HashMap;
+
+use twilight_model::{
+    application::interaction::modal::ModalInteractionData,
+    channel::message::component::{ TextInput, TextInputStyle },
+};
+
+use crate::utilities::app_error::BoxedError;
+
+/// Most text inputs a modal can hold.
+pub const MAX_MODAL_INPUTS: usize = 5;
+
+/// Longest modal title or text input label.
+const MAX_LABEL_LENGTH: usize = 45;
+
+/// A required text input, the other fields can be set with struct update syntax:
+/// `TextInput { value: Some(current), ..text_input("content", "Content", style) }`.
+pub fn text_input(custom_id: &str, label: &str, style: TextInputStyle) -> TextInput {
+    TextInput {
+        custom_id: custom_id.to_string(),
+        label: modal_label(label),
+        max_length: None,
+        min_length: None,
+        placeholder: None,
+        required: Some(true),
+        style,
+        value: None,
+    }
+}
+
+/// Shortens a title or label to what Discord accepts.
+pub fn modal_label(label: &str) -> String {
+    if label.chars().count() <= MAX_LABEL_LENGTH {
+        return label.to_string();
+    }
+
+    let mut truncated: String = label
+        .chars()
+        .take(MAX_LABEL_LENGTH - 1)
+        .collect();
+    truncated.push('…');
+    truncated
+}
+
+/// Values of the text inputs of a submitted modal, by their custom id.
+pub struct ModalValues {
+    values: HashMap<String, String>,
+}
+
+impl ModalValues {
+    pub fn from_data(modal_data: &ModalInteractionData) -> Self {
+        let values = modal_data.components
+            .iter()
+            .flat_map(|row| row.components.iter())
+            .filter_map(|input| {
+                input.value.as_ref().map(|value| (input.custom_id.clone(), value.clone()))
+            })
+            .collect();
+
+        Self { values }
+    }
+
+    /// The value of an input, `None` when it was left empty.
+    pub fn value(&self, custom_id: &str) -> Option<String> {
+        self.values
+            .get(custom_id)
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    }
+
+    /// The value of an input that must be filled.
+    pub fn required(&self, custom_id: &str) -> Result<String, BoxedError> {
+        self.value(custom_id).ok_or_else(|| format!("Missing modal value `{}`", custom_id).into())
+    }
+}
+
+/// A form parsed from the text inputs of a submitted modal.
+pub trait FromModal: Sized {
+    fn from_modal(values: &ModalValues) -> Result<Self, BoxedError>;
+}
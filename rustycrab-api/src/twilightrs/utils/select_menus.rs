@@ -0,0 +1,97 @@
+use std::str::FromStr;
+
+use twilight_model::{
+    application::interaction::message_component::MessageComponentInteractionData,
+    channel::message::{
+        Component,
+        component::{ ActionRow, SelectMenu, SelectMenuOption, SelectMenuType },
+    },
+    id::Id,
+};
+
+/// Most options a text select menu can hold.
+pub const MAX_SELECT_OPTIONS: usize = 25;
+
+/// Longest option label, description or value.
+const MAX_OPTION_LENGTH: usize = 100;
+
+/// A select menu picking a single value, the other fields can be set with struct update syntax:
+/// `SelectMenu { max_values: Some(3), ..select_menu(custom_id, SelectMenuType::Role, "Roles") }`.
+///
+/// Text select menus need their `options`, the other kinds list the users, roles or channels
+/// of the guild.
+pub fn select_menu(custom_id: String, kind: SelectMenuType, placeholder: &str) -> SelectMenu {
+    SelectMenu {
+        channel_types: None,
+        custom_id,
+        disabled: false,
+        kind,
+        max_values: Some(1),
+        min_values: Some(1),
+        options: None,
+        placeholder: Some(truncate_option_text(placeholder)),
+    }
+}
+
+/// An option of a text select menu.
+pub fn select_option(label: &str, value: &str) -> SelectMenuOption {
+    SelectMenuOption {
+        default: false,
+        description: None,
+        emoji: None,
+        label: truncate_option_text(label),
+        value: truncate_option_text(value),
+    }
+}
+
+/// Wraps a select menu in the action row it needs to be sent.
+pub fn select_menu_row(menu: SelectMenu) -> Component {
+    Component::ActionRow(ActionRow {
+        components: vec![Component::SelectMenu(menu)],
+    })
+}
+
+/// Shortens a label, description or value to what Discord accepts.
+pub fn truncate_option_text(text: &str) -> String {
+    if text.chars().count() <= MAX_OPTION_LENGTH {
+        return text.to_string();
+    }
+
+    let mut truncated: String = text
+        .chars()
+        .take(MAX_OPTION_LENGTH - 1)
+        .collect();
+    truncated.push('…');
+    truncated
+}
+
+/// Values picked in a select menu.
+///
+/// Text select menus give the values of the picked options, user, role and channel select menus
+/// give the IDs of the picked users, roles or channels.
+pub struct SelectedValues {
+    values: Vec<String>,
+}
+
+impl SelectedValues {
+    pub fn from_data(component_data: &MessageComponentInteractionData) -> Self {
+        Self { values: component_data.values.clone() }
+    }
+
+    pub fn values(&self) -> &[String] {
+        &self.values
+    }
+
+    /// The first picked value, for menus picking a single one.
+    pub fn first(&self) -> Option<&str> {
+        self.values.first().map(String::as_str)
+    }
+
+    /// The picked users, roles or channels: `selected.ids::<RoleMarker>()`.
+    pub fn ids<T>(&self) -> Vec<Id<T>> {
+        self.values
+            .iter()
+            .filter_map(|value| Id::<T>::from_str(value).ok())
+            .collect()
+    }
+}
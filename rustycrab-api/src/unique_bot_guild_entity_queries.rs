@@ -85,6 +85,17 @@ pub trait UniqueBotGuildEntityQueries: DefaultSeaQueries {
         bot_discord_id: &str,
         guild_discord_id: &str
     ) -> Result<<Self::Entity as EntityTrait>::Model, AppError> {
+        Self::find_optional_by_discord_ids(db, bot_discord_id, guild_discord_id).await?.ok_or_else(||
+            AppError::not_found("Record not found")
+        )
+    }
+
+    /// Like `find_by_discord_ids`, with `None` when the guild has no entity for the bot.
+    async fn find_optional_by_discord_ids(
+        db: &DatabaseConnection,
+        bot_discord_id: &str,
+        guild_discord_id: &str
+    ) -> Result<Option<<Self::Entity as EntityTrait>::Model>, AppError> {
         Self::Entity::find()
             .join(sea_orm::JoinType::LeftJoin, Self::bot_relation())
             .join(sea_orm::JoinType::LeftJoin, Self::guild_relation())
@@ -94,8 +105,7 @@ pub trait UniqueBotGuildEntityQueries: DefaultSeaQueries {
                     .add(crate::database::guild_info::Column::GuildId.eq(guild_discord_id))
             )
             .one(db).await
-            .map_err(AppError::from)?
-            .ok_or_else(|| AppError::not_found("Record not found"))
+            .map_err(AppError::from)
     }
 
     /// Updates an entity based on both the bot's and guild's Discord IDs with the provided data.
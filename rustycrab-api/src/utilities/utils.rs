@@ -52,7 +52,21 @@ pub fn color_to_button_style(color: &str) -> ButtonStyle {
     }
 }
 
-use chrono::{ DateTime, Utc };
+use chrono::{ DateTime, Duration, Utc };
+
+/// Gets when a Discord account was created, from the timestamp stored in its snowflake.
+pub fn account_created_at(user_id: Id<UserMarker>) -> DateTime<Utc> {
+    let timestamp_ms = ((user_id.get() >> 22) as i64) + 1_420_070_400_000;
+
+    DateTime::<Utc>
+        ::from_timestamp(timestamp_ms / 1000, ((timestamp_ms % 1000) * 1_000_000) as u32)
+        .unwrap_or_else(|| Utc::now())
+}
+
+/// Gets how old a Discord account is.
+pub fn account_age(user_id: Id<UserMarker>) -> Duration {
+    Utc::now().signed_duration_since(account_created_at(user_id))
+}
 
 pub fn process_placeholders_sync(
     text: String,
@@ -70,6 +84,10 @@ pub fn process_placeholders_sync(
             );
         }
         replaced_text = replaced_text.replace("{server-id}", &guild.id.to_string());
+
+        if let Some(count) = guild.approximate_member_count.or(guild.member_count) {
+            replaced_text = replaced_text.replace("{member-count}", &count.to_string());
+        }
     }
 
     if let Some(user) = user {
@@ -82,11 +100,7 @@ pub fn process_placeholders_sync(
         replaced_text = replaced_text.replace("{username}", &user.name);
 
         // replace {account-age}
-        let creation_date = DateTime::<Utc>
-            ::from_timestamp(((user.id.get() >> 22) as i64) + 1_420_070_400_000, 0 as u32)
-            .unwrap_or_else(|| Utc::now());
-        let account_age = Utc::now().signed_duration_since(creation_date);
-        let account_age_str = format!("{} days", account_age.num_days());
+        let account_age_str = format!("{} days", account_age(user.id).num_days());
         replaced_text = replaced_text.replace("{account-age}", &account_age_str);
     }
 
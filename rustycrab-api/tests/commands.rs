@@ -0,0 +1,355 @@
+mod common;
+
+use common::bot::{ TestBot, BOT_ID, OWNER_ID };
+use rustycrab_api::{
+    database::prelude::Messages,
+    default_queries::DefaultSeaQueries,
+    queries::{
+        command_rules_queries::CommandRulesQueries,
+        guild_config_queries::GuildConfigQueries,
+        message_queries::MessageQueries,
+    },
+};
+use rustycrab_model::response::{
+    command_rule::RequestCreateCommandRule,
+    discord_message::{ RequestCreateUpdateEmbed, RequestCreateUpdateMessage },
+};
+use sea_orm::EntityTrait;
+use twilight_model::guild::Permissions;
+
+#[tokio::test]
+async fn math_replies_with_the_result() {
+    let bot = TestBot::new().await;
+
+    let replies = bot.send_message(&bot.owner, "!math 6*7").await;
+
+    assert_eq!(replies.len(), 1);
+    assert_eq!(replies[0].content, "42");
+    assert!(replies[0].reply_to.is_some());
+}
+
+#[tokio::test]
+async fn unknown_commands_suggest_close_ones() {
+    let bot = TestBot::new().await;
+
+    let replies = bot.send_message(&bot.owner, "!mth 6*7").await;
+
+    assert_eq!(replies.len(), 1);
+    assert!(replies[0].contains("`!math`"));
+}
+
+#[tokio::test]
+async fn purge_needs_the_manage_messages_permission() {
+    let bot = TestBot::new().await;
+    let member = bot.add_member("member", &[]);
+
+    let replies = bot.send_message(&member, "!purge 5").await;
+
+    assert_eq!(replies.len(), 1);
+    assert!(replies[0].contains("You don't have the permissions required"));
+    assert!(bot.discord.state().deleted_messages.is_empty());
+}
+
+#[tokio::test]
+async fn purge_deletes_the_last_messages() {
+    let bot = TestBot::new().await;
+    let member = bot.add_member("member", &[]);
+    let channel_id = bot.discord.channel_id();
+    let kept = bot.discord.add_message(channel_id, member.id, "kept");
+    for content in ["first", "second"] {
+        bot.discord.add_message(channel_id, member.id, content);
+    }
+
+    bot.send_message(&bot.owner, "!purge 3").await;
+
+    let state = bot.discord.state();
+    assert_eq!(state.deleted_messages.len(), 3);
+    assert_eq!(
+        state
+            .channel_messages(channel_id)
+            .iter()
+            .map(|message| message.id)
+            .collect::<Vec<u64>>(),
+        vec![kept.id]
+    );
+}
+
+#[tokio::test]
+async fn purge_user_flags_take_names() {
+    let bot = TestBot::new().await;
+    let alice = bot.add_member("alice", &[]);
+    let member = bot.add_member("member", &[]);
+    let channel_id = bot.discord.channel_id();
+    let kept = bot.discord.add_message(channel_id, member.id, "kept");
+    bot.discord.add_message(channel_id, alice.id, "deleted");
+
+    bot.send_message(&bot.owner, "!purge 2 --user=alice").await;
+
+    let state = bot.discord.state();
+    assert_eq!(state.deleted_messages.len(), 1);
+    assert!(
+        state
+            .channel_messages(channel_id)
+            .iter()
+            .any(|message| message.id == kept.id)
+    );
+}
+
+#[tokio::test]
+async fn purge_user_flags_must_name_a_member() {
+    let bot = TestBot::new().await;
+
+    let replies = bot.send_message(&bot.owner, "!purge 2 --user=nobody").await;
+
+    assert!(replies[0].contains("Invalid argument"));
+    assert!(bot.discord.state().deleted_messages.is_empty());
+}
+
+#[tokio::test]
+async fn invalid_arguments_dont_use_up_cooldowns() {
+    let bot = TestBot::new().await;
+
+    let replies = bot.send_message(&bot.owner, "!purge many").await;
+    assert!(replies[0].contains("Invalid argument"));
+    bot.send_message(&bot.owner, "!purge 1").await;
+
+    assert_eq!(bot.discord.state().deleted_messages.len(), 1);
+}
+
+#[tokio::test]
+async fn category_rules_restrict_commands_without_a_module() {
+    let bot = TestBot::new().await;
+    let member = bot.add_member("member", &[]);
+    CommandRulesQueries::create_entity(&bot.client.db, RequestCreateCommandRule {
+        bot_discord_id: BOT_ID.to_string(),
+        guild_discord_id: bot.discord.guild_id().to_string(),
+        target: "category".to_string(),
+        name: "General".to_string(),
+        subject: "user".to_string(),
+        subject_id: member.id.to_string(),
+        allow: Some(0),
+    }).await.unwrap();
+
+    let replies = bot.send_message(&member, "!math 6*7").await;
+
+    assert_eq!(replies.len(), 1);
+    assert!(replies[0].contains("You are not allowed to use this command"));
+    assert_eq!(bot.send_message(&bot.owner, "!math 6*7").await[0].content, "42");
+}
+
+#[tokio::test]
+async fn command_rules_are_saved_with_the_full_command_name() {
+    let bot = TestBot::new().await;
+    let rule = |name: &str| RequestCreateCommandRule {
+        bot_discord_id: BOT_ID.to_string(),
+        guild_discord_id: bot.discord.guild_id().to_string(),
+        target: "command".to_string(),
+        name: name.to_string(),
+        subject: "user".to_string(),
+        subject_id: OWNER_ID.to_string(),
+        allow: Some(0),
+    };
+
+    let saved = CommandRulesQueries::create_entity(&bot.client.db, rule("AR del")).await.unwrap();
+
+    assert_eq!(saved.name, "autores delete");
+    assert!(CommandRulesQueries::create_entity(&bot.client.db, rule("nope")).await.is_err());
+    assert!(CommandRulesQueries::create_entity(&bot.client.db, rule("ar nope")).await.is_err());
+}
+
+#[tokio::test]
+async fn role_menus_only_offer_roles_below_the_author() {
+    let bot = TestBot::new().await;
+    let colors = bot.add_role("colors", 0);
+    let mods = bot.add_role("mods", Permissions::MANAGE_ROLES.bits());
+    let vip = bot.add_role("vip", 0);
+    let admins = bot.add_role("admins", Permissions::ADMINISTRATOR.bits());
+    let top = bot.add_role("top", 0);
+    bot.discord.state().members.get_mut(&BOT_ID).unwrap().roles.push(top.id);
+    bot.sync_cache();
+    let moderator = bot.add_member("moderator", &[&mods]);
+
+    let replies = bot.send_message(&moderator, "!rolemenu colors mods vip admins").await;
+
+    assert!(replies[0].contains(&format!("<@&{}>", colors.id)));
+    for role in [&mods, &vip, &admins] {
+        assert!(!replies[0].contains(&format!("<@&{}>", role.id)));
+    }
+
+    let replies = bot.send_message(&bot.owner, "!rolemenu vip admins").await;
+
+    assert!(replies[0].contains(&format!("<@&{}>", vip.id)));
+    assert!(!replies[0].contains(&format!("<@&{}>", admins.id)));
+}
+
+#[tokio::test]
+async fn edited_commands_edit_their_previous_reply() {
+    let bot = TestBot::new().await;
+    let channel_id = bot.discord.channel_id();
+    let suggestion = bot.send_message(&bot.owner, "!mth 6*7").await;
+    let command_id = bot.discord
+        .state()
+        .channel_messages(channel_id)
+        .iter()
+        .find(|message| message.content == "!mth 6*7")
+        .map(|message| message.id)
+        .unwrap();
+
+    let replies = bot.edit_message(command_id, "!math 6*7").await;
+
+    assert_eq!(replies.len(), 1);
+    assert_eq!(replies[0].id, suggestion[0].id);
+    assert_eq!(replies[0].content, "42");
+}
+
+#[tokio::test]
+async fn edited_commands_with_side_effects_do_not_run_again() {
+    let bot = TestBot::new().await;
+    let channel_id = bot.discord.channel_id();
+    bot.send_message(&bot.owner, "!prefix add rc!").await;
+    let command_id = bot.discord
+        .state()
+        .channel_messages(channel_id)
+        .iter()
+        .find(|message| message.content == "!prefix add rc!")
+        .map(|message| message.id)
+        .unwrap();
+
+    let replies = bot.edit_message(command_id, "!prefix add rr!").await;
+
+    assert!(replies.is_empty());
+    let config = GuildConfigQueries::find_by_id(&bot.client.db, bot.config.id).await.unwrap();
+    assert_eq!(GuildConfigQueries::extra_prefixes(&config), vec!["rc!".to_string()]);
+}
+
+#[tokio::test]
+async fn edited_typos_run_commands_with_side_effects() {
+    let mut bot = TestBot::new().await;
+
+    // missing argument
+    bot.send_message(&bot.owner, "!prefix add").await;
+    bot.edit_message(sent_message_id(&bot, "!prefix add"), "!prefix add rc!").await;
+    bot.config = GuildConfigQueries::find_by_id(&bot.client.db, bot.config.id).await.unwrap();
+    // unknown command
+    bot.send_message(&bot.owner, "!prefx add rr!").await;
+    bot.edit_message(sent_message_id(&bot, "!prefx add rr!"), "!prefix add rr!").await;
+
+    let config = GuildConfigQueries::find_by_id(&bot.client.db, bot.config.id).await.unwrap();
+    assert_eq!(
+        GuildConfigQueries::extra_prefixes(&config),
+        vec!["rc!".to_string(), "rr!".to_string()]
+    );
+}
+
+/// ID of the message with this content in the guild's channel.
+fn sent_message_id(bot: &TestBot, content: &str) -> u64 {
+    let channel_id = bot.discord.channel_id();
+    bot.discord
+        .state()
+        .channel_messages(channel_id)
+        .iter()
+        .find(|message| message.content == content)
+        .map(|message| message.id)
+        .unwrap()
+}
+
+#[tokio::test]
+async fn added_prefixes_are_case_insensitive() {
+    let mut bot = TestBot::new().await;
+
+    let replies = bot.send_message(&bot.owner, "!prefix add rc!").await;
+    assert!(replies[0].contains("added"));
+    bot.config = GuildConfigQueries::find_by_id(&bot.client.db, bot.config.id).await.unwrap();
+
+    let replies = bot.send_message(&bot.owner, "RC!math 6*7").await;
+
+    assert_eq!(replies.len(), 1);
+    assert_eq!(replies[0].content, "42");
+}
+
+#[tokio::test]
+async fn mention_prefix_can_be_turned_off() {
+    let mut bot = TestBot::new().await;
+    let mention = format!("<@{}> math 6*7", BOT_ID);
+    assert_eq!(bot.send_message(&bot.owner, &mention).await[0].content, "42");
+
+    bot.send_message(&bot.owner, "!prefix mention off").await;
+    bot.config = GuildConfigQueries::find_by_id(&bot.client.db, bot.config.id).await.unwrap();
+
+    assert!(bot.send_message(&bot.owner, &mention).await.is_empty());
+}
+
+#[tokio::test]
+async fn help_searches_what_is_not_a_command() {
+    let bot = TestBot::new().await;
+
+    let replies = bot.send_message(&bot.owner, "!help mention").await;
+
+    assert_eq!(replies.len(), 1);
+    assert!(replies[0].contains("`!changeprefix mention <on/off>`"));
+}
+
+#[tokio::test]
+async fn help_names_the_permissions_required() {
+    let bot = TestBot::new().await;
+
+    let replies = bot.send_message(&bot.owner, "!help purge").await;
+
+    assert!(replies[0].contains("manage_messages"));
+}
+
+#[tokio::test]
+async fn long_help_searches_get_page_buttons() {
+    let bot = TestBot::new().await;
+
+    let replies = bot.send_message(&bot.owner, "!help e").await;
+
+    assert_eq!(replies.len(), 1);
+    assert!(replies[0].contains("Page 1/"));
+    assert_eq!(
+        replies[0].custom_ids(),
+        vec![
+            format!("3:{}", OWNER_ID),
+            format!("4:{}:0:s:e", OWNER_ID),
+            format!("4:{}:1:s:e", OWNER_ID)
+        ]
+    );
+}
+
+#[tokio::test]
+async fn text_arguments_are_kept_as_typed() {
+    let bot = TestBot::new().await;
+
+    let replies = bot.send_message(
+        &bot.owner,
+        "!autores add \"hi\" | Say   \"hello\"\nto  everyone"
+    ).await;
+
+    assert!(replies[0].contains("Auto-response created"));
+    let messages = Messages::find().all(&bot.client.db).await.unwrap();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].content.as_deref(), Some("Say   \"hello\"\nto  everyone"));
+}
+
+#[tokio::test]
+async fn embeds_added_to_a_response_are_linked_to_it() {
+    let bot = TestBot::new().await;
+    bot.send_message(&bot.owner, "!autores add hi | hello").await;
+    let message_id = Messages::find().all(&bot.client.db).await.unwrap()[0].id;
+
+    let update = RequestCreateUpdateMessage {
+        embed: Some(RequestCreateUpdateEmbed {
+            description: Some("hello there".to_string()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let message = MessageQueries::update_by_id(&bot.client.db, message_id, update).await.unwrap();
+
+    let response = MessageQueries::fetch_message_response(&bot.client.db, message.id).await;
+    assert!(message.embed_id.is_some());
+    assert_eq!(
+        response.unwrap().embed.and_then(|embed| embed.description),
+        Some("hello there".to_string())
+    );
+}
@@ -0,0 +1,257 @@
+//! A bot running against the Discord stand-in and an in-memory database.
+
+use std::sync::Arc;
+
+use rustycrab_api::{
+    database::{ bot_guild_configurations, bots, guild_info, prelude::* },
+    twilightrs::{
+        bot::action_logs::CachedBefore,
+        commands::context::context_command::GuildConfigModel,
+        discord_client::{ DiscordClient, DiscordClientRef },
+        dispatchers::ClientDispatchers,
+        event_bus::EventBus,
+        events::{
+            message_create::handle_message_create,
+            message_update::handle_message_update,
+        },
+    },
+};
+use sea_orm::{
+    ActiveModelTrait,
+    ConnectOptions,
+    ConnectionTrait,
+    Database,
+    DatabaseConnection,
+    EntityTrait,
+    Schema,
+    Set,
+};
+use serde_json::Value;
+use twilight_gateway::Event;
+use twilight_model::{
+    gateway::payload::incoming::{ GuildCreate, MessageCreate, MessageUpdate },
+    id::Id,
+};
+
+use super::discord::{ DiscordStandIn, StandInMessage, StandInRole, StandInUser };
+
+pub const BOT_ID: u64 = 1_000;
+pub const OWNER_ID: u64 = 1_001;
+
+/// A bot, the guild it's in and its owner.
+///
+/// Messages are given to the same handlers the gateway events go to, what the bot sent in
+/// response is read from the stand-in.
+pub struct TestBot {
+    pub discord: DiscordStandIn,
+    pub client: DiscordClient,
+    pub dispatchers: Arc<ClientDispatchers>,
+    /// Listeners of the gateway events, for the replayed recordings
+    pub event_bus: Arc<EventBus>,
+    pub config: GuildConfigModel,
+    /// Owner of the guild, allowed to use every command
+    pub owner: StandInUser,
+}
+
+impl TestBot {
+    pub async fn new() -> Self {
+        let bot = StandInUser { id: BOT_ID, name: "Rusty Crab".to_string(), bot: true };
+        let owner = StandInUser { id: OWNER_ID, name: "owner".to_string(), bot: false };
+        let discord = DiscordStandIn::start(bot, owner.clone()).await;
+
+        let db = test_database().await;
+        let config = seed_guild(&db, discord.guild_id()).await;
+        let client = Arc::new(
+            DiscordClientRef::with_api_host(
+                Id::new(BOT_ID),
+                db,
+                "test-token".to_string(),
+                &discord.host()
+            )
+        );
+
+        let dispatchers = Arc::new(ClientDispatchers::new());
+        let test_bot = Self {
+            discord,
+            client,
+            event_bus: Arc::new(EventBus::with_default_listeners(Arc::clone(&dispatchers))),
+            dispatchers,
+            config,
+            owner,
+        };
+        test_bot.sync_cache();
+
+        test_bot
+    }
+
+    /// Gives the guild, as the stand-in knows it, to the cache like the gateway does.
+    pub fn sync_cache(&self) {
+        let guild_json = self.discord.state().guild_json();
+        let guild: GuildCreate = from_json(guild_json);
+        self.client.cache.update(&Event::GuildCreate(Box::new(guild)));
+    }
+
+    /// Adds a member to the guild, with the given roles.
+    pub fn add_member(&self, name: &str, roles: &[&StandInRole]) -> StandInUser {
+        let user = self.discord.add_user(name, false);
+        self.discord.add_member(
+            &user,
+            roles
+                .iter()
+                .map(|role| role.id)
+                .collect()
+        );
+        self.sync_cache();
+
+        user
+    }
+
+    pub fn add_role(&self, name: &str, permissions: u64) -> StandInRole {
+        let role = self.discord.add_role(name, permissions);
+        self.sync_cache();
+
+        role
+    }
+
+    /// Sends a message as `author` in the guild's channel and handles its `MessageCreate`,
+    /// returns the messages the bot sent or edited meanwhile.
+    pub async fn send_message(&self, author: &StandInUser, content: &str) -> Vec<StandInMessage> {
+        let revision = self.discord.state().revision;
+        let message = self.discord.add_message(self.discord.channel_id(), author.id, content);
+        let message_create: MessageCreate = from_json(self.gateway_message(&message));
+        self.client.cache.update(&Event::MessageCreate(Box::new(message_create.clone())));
+
+        handle_message_create(
+            Arc::clone(&self.client),
+            &message_create,
+            &self.config,
+            &self.dispatchers
+        ).await.expect("handling MessageCreate failed");
+
+        self.bot_messages_since(revision)
+    }
+
+    /// Edits a message like its author would and handles its `MessageUpdate`, returns the
+    /// messages the bot sent or edited meanwhile.
+    pub async fn edit_message(&self, message_id: u64, content: &str) -> Vec<StandInMessage> {
+        let revision = self.discord.state().revision;
+        let message = self.discord.edit_message(message_id, content);
+        let message_update: MessageUpdate = from_json(self.gateway_message(&message));
+        let event = Event::MessageUpdate(Box::new(message_update.clone()));
+        let before = CachedBefore::from_cache(&self.client.cache, &event);
+        self.client.cache.update(&event);
+
+        handle_message_update(
+            Arc::clone(&self.client),
+            &message_update,
+            &before,
+            &self.config,
+            &self.dispatchers
+        ).await.expect("handling MessageUpdate failed");
+
+        self.bot_messages_since(revision)
+    }
+
+    /// The message as the gateway sends it, with the member of its author.
+    fn gateway_message(&self, message: &StandInMessage) -> Value {
+        let state = self.discord.state();
+        let mut value = state.message_json(message);
+        if let Some(member) = state.members.get(&message.author_id) {
+            value["member"] = state.member_json(member);
+        }
+
+        value
+    }
+
+    pub fn bot_messages_since(&self, revision: u64) -> Vec<StandInMessage> {
+        self.discord.state().bot_messages_since(revision).into_iter().cloned().collect()
+    }
+}
+
+/// An in-memory SQLite database with the tables of every entity.
+pub async fn test_database() -> DatabaseConnection {
+    // every connection to `sqlite::memory:` opens its own database, only one is kept
+    let mut options = ConnectOptions::new("sqlite::memory:".to_string());
+    options.max_connections(1).min_connections(1).sqlx_logging(false);
+    let db = Database::connect(options).await.expect("connecting to SQLite failed");
+
+    let backend = db.get_database_backend();
+    let schema = Schema::new(backend);
+    let statements = [
+        schema.create_table_from_entity(AutoResponses),
+        schema.create_table_from_entity(AutoRoles),
+        schema.create_table_from_entity(BotGuildConfigurations),
+        schema.create_table_from_entity(BotGuildGoodbyes),
+        schema.create_table_from_entity(BotGuildWelcomes),
+        schema.create_table_from_entity(BotStaffs),
+        schema.create_table_from_entity(BotUsers),
+        schema.create_table_from_entity(Bots),
+        schema.create_table_from_entity(Buttons),
+        schema.create_table_from_entity(CommandRules),
+        schema.create_table_from_entity(CommandUsages),
+        schema.create_table_from_entity(EmbedFields),
+        schema.create_table_from_entity(EmbedInfo),
+        schema.create_table_from_entity(GuildActionLogs),
+        schema.create_table_from_entity(GuildInfo),
+        schema.create_table_from_entity(Items),
+        schema.create_table_from_entity(LogIgnoreChannels),
+        schema.create_table_from_entity(LogIgnoreRoles),
+        schema.create_table_from_entity(LogSettings),
+        schema.create_table_from_entity(Marriages),
+        schema.create_table_from_entity(Messages),
+        schema.create_table_from_entity(Session),
+        schema.create_table_from_entity(TicketMultiPanels),
+        schema.create_table_from_entity(TicketMultiPanelsPanelsTicketPanels),
+        schema.create_table_from_entity(TicketPanels),
+        schema.create_table_from_entity(TicketSettings),
+        schema.create_table_from_entity(TicketSupportTeams),
+        schema.create_table_from_entity(Tickets),
+        schema.create_table_from_entity(Users)
+    ];
+    for statement in statements {
+        db.execute(backend.build(&statement)).await.expect("creating a table failed");
+    }
+
+    db
+}
+
+/// Adds the bot, the guild and the guild's configuration, with every module enabled.
+async fn seed_guild(db: &DatabaseConnection, guild_id: u64) -> GuildConfigModel {
+    let bot = (bots::ActiveModel {
+        bot_id: Set(BOT_ID.to_string()),
+        token: Set("test-token".to_string()),
+        theme_hex_color: Set("#2b2d31".to_string()),
+        discord_secret: Set(String::new()),
+        discord_callback_url: Set(String::new()),
+        premium_flags: Set(0),
+        ..Default::default()
+    }).insert(db).await.expect("adding the bot failed");
+    let guild = (guild_info::ActiveModel {
+        guild_id: Set(guild_id.to_string()),
+        ..Default::default()
+    }).insert(db).await.expect("adding the guild failed");
+
+    (bot_guild_configurations::ActiveModel {
+        prefix: Set("!".to_string()),
+        locale: Set("en".to_string()),
+        module_flags: Set(0),
+        premium_flags: Set(0),
+        command_cooldowns: Set(None),
+        command_suggestions: Set(1),
+        extra_prefixes: Set(None),
+        mention_prefix: Set(1),
+        bot_id: Set(bot.id),
+        guild_id: Set(guild.id),
+        ..Default::default()
+    }).insert(db).await.expect("adding the guild configuration failed")
+}
+
+/// Deserializes a gateway payload, panicking on the stand-in's mistakes.
+pub fn from_json<T: serde::de::DeserializeOwned>(value: Value) -> T {
+    serde_json::from_value(value).expect("the stand-in built an invalid payload")
+}
+
+/// Number of rows in the table of an entity.
+pub async fn count<E: EntityTrait>(db: &DatabaseConnection) -> usize {
+    E::find().all(db).await.expect("querying the table failed").len()
+}
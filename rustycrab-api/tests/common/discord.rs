@@ -0,0 +1,963 @@
+//! A local stand-in of the Discord REST API.
+//!
+//! Implements the endpoints the bot uses for messages, members, roles, channels, threads and
+//! interactions on top of a state the tests can inspect and seed.
+
+use std::{
+    collections::{ BTreeMap, HashMap, HashSet },
+    net::SocketAddr,
+    sync::{ Arc, Mutex, MutexGuard },
+    time::{ SystemTime, UNIX_EPOCH },
+};
+
+use axum::{
+    body::Bytes,
+    extract::State,
+    http::{ Method, StatusCode, Uri },
+    response::{ IntoResponse, Response },
+    Json,
+    Router,
+};
+use chrono::{ TimeZone, Utc };
+use serde_json::{ json, Value };
+
+/// Milliseconds between the Unix epoch and the first second of 2015, the Discord epoch.
+const DISCORD_EPOCH_MILLIS: u64 = 1_420_070_400_000;
+
+/// IDs of the guild and its channels, fixed so recorded gateway events can refer to them.
+pub const GUILD_ID: u64 = 2_000;
+pub const CHANNEL_ID: u64 = 2_001;
+pub const VOICE_CHANNEL_ID: u64 = 2_002;
+pub const AFK_CHANNEL_ID: u64 = 2_003;
+
+/// Permissions of the @everyone role: view channels, send messages and read message history.
+pub const EVERYONE_PERMISSIONS: u64 = 1 << 10 | 1 << 11 | 1 << 16;
+
+#[derive(Clone, Debug)]
+pub struct StandInUser {
+    pub id: u64,
+    pub name: String,
+    pub bot: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct StandInRole {
+    pub id: u64,
+    pub name: String,
+    pub permissions: u64,
+    pub position: i64,
+}
+
+#[derive(Clone, Debug)]
+pub struct StandInChannel {
+    pub id: u64,
+    pub guild_id: u64,
+    pub name: String,
+    /// Discord channel type, `0` for text channels, `2` for voice channels and `11` for public
+    /// threads
+    pub kind: u8,
+    pub parent_id: Option<u64>,
+    /// Members of a thread
+    pub thread_members: HashSet<u64>,
+}
+
+#[derive(Clone, Debug)]
+pub struct StandInMember {
+    pub user_id: u64,
+    pub nick: Option<String>,
+    pub roles: Vec<u64>,
+    pub communication_disabled_until: Option<String>,
+}
+
+/// A message sent to a channel, or the response or a followup of an interaction.
+#[derive(Clone, Debug)]
+pub struct StandInMessage {
+    pub id: u64,
+    pub channel_id: u64,
+    pub author_id: u64,
+    pub content: String,
+    pub embeds: Vec<Value>,
+    pub components: Vec<Value>,
+    /// The message this one replies to
+    pub reply_to: Option<u64>,
+    /// Token of the interaction this message answers
+    pub interaction_token: Option<String>,
+    pub flags: u64,
+    pub edited: bool,
+    /// Revision of the state when the message was last sent or edited
+    pub revision: u64,
+}
+
+impl StandInMessage {
+    /// Descriptions of the message's embeds.
+    pub fn embed_descriptions(&self) -> Vec<&str> {
+        self.embeds
+            .iter()
+            .filter_map(|embed| embed["description"].as_str())
+            .collect()
+    }
+
+    /// Titles of the message's embeds.
+    pub fn embed_titles(&self) -> Vec<&str> {
+        self.embeds
+            .iter()
+            .filter_map(|embed| embed["title"].as_str())
+            .collect()
+    }
+
+    /// Custom IDs of the message's components, in their action rows.
+    pub fn custom_ids(&self) -> Vec<&str> {
+        self.components
+            .iter()
+            .flat_map(|row| row["components"].as_array().into_iter().flatten())
+            .filter_map(|component| component["custom_id"].as_str())
+            .collect()
+    }
+
+    /// Whether the text or one of the embeds of the message contains `text`, ignoring the
+    /// isolation marks Fluent puts around the arguments of localized strings.
+    pub fn contains(&self, text: &str) -> bool {
+        let embeds = self.embeds
+            .iter()
+            .map(|embed| embed.to_string())
+            .collect::<Vec<String>>()
+            .join("\n");
+        format!("{}\n{}", self.content, embeds)
+            .replace(['\u{2068}', '\u{2069}'], "")
+            .contains(text)
+    }
+}
+
+/// An interaction callback, like a deferred response or a modal.
+#[derive(Clone, Debug)]
+pub struct InteractionCallback {
+    pub interaction_id: u64,
+    pub token: String,
+    /// Discord interaction callback type, `5` for a deferred response and `9` for a modal
+    pub kind: u64,
+    pub data: Value,
+}
+
+/// A request the stand-in received.
+#[derive(Clone, Debug)]
+pub struct RecordedRequest {
+    pub method: Method,
+    pub path: String,
+    pub body: Value,
+}
+
+/// Everything the stand-in knows about, tests inspect it after running the bot's handlers.
+#[derive(Default)]
+pub struct StandInState {
+    pub bot: Option<StandInUser>,
+    pub users: HashMap<u64, StandInUser>,
+    pub guild_id: u64,
+    pub guild_name: String,
+    pub owner_id: u64,
+    pub roles: BTreeMap<u64, StandInRole>,
+    pub channels: BTreeMap<u64, StandInChannel>,
+    /// Direct message channels of the bot, by recipient
+    pub dm_channels: BTreeMap<u64, u64>,
+    pub members: BTreeMap<u64, StandInMember>,
+    pub messages: BTreeMap<u64, StandInMessage>,
+    pub deleted_messages: Vec<u64>,
+    pub bans: HashSet<u64>,
+    pub kicked: Vec<u64>,
+    pub interaction_callbacks: Vec<InteractionCallback>,
+    /// Channels of the interactions, by token, their responses are sent there
+    pub interaction_channels: HashMap<String, u64>,
+    pub requests: Vec<RecordedRequest>,
+    /// Bumped every time a message is sent or edited
+    pub revision: u64,
+    sequence: u64,
+}
+
+impl StandInState {
+    /// A new snowflake, its timestamp is the current time like the IDs Discord gives.
+    pub fn next_id(&mut self) -> u64 {
+        self.sequence += 1;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64;
+        ((now - DISCORD_EPOCH_MILLIS) << 22) | (self.sequence & 0x3f_ffff)
+    }
+
+    /// Messages of a channel that were not deleted, oldest first.
+    pub fn channel_messages(&self, channel_id: u64) -> Vec<&StandInMessage> {
+        self.messages
+            .values()
+            .filter(|message| message.channel_id == channel_id)
+            .collect()
+    }
+
+    /// Messages the bot sent to a user in direct messages, oldest first.
+    pub fn dm_messages(&self, user_id: u64) -> Vec<&StandInMessage> {
+        match self.dm_channels.get(&user_id) {
+            Some(channel_id) => self.channel_messages(*channel_id),
+            None => Vec::new(),
+        }
+    }
+
+    /// Messages of the bot, including its interaction responses, oldest first.
+    pub fn bot_messages(&self) -> Vec<&StandInMessage> {
+        let bot_id = self.bot.as_ref().map_or(0, |bot| bot.id);
+        self.messages
+            .values()
+            .filter(|message| message.author_id == bot_id)
+            .collect()
+    }
+
+    /// Messages the bot sent or edited after the given revision of the state, oldest first.
+    pub fn bot_messages_since(&self, revision: u64) -> Vec<&StandInMessage> {
+        let mut messages: Vec<&StandInMessage> = self
+            .bot_messages()
+            .into_iter()
+            .filter(|message| message.revision > revision)
+            .collect();
+        messages.sort_by_key(|message| message.revision);
+
+        messages
+    }
+
+    pub fn user_json(&self, user_id: u64) -> Value {
+        let user = self.users.get(&user_id).cloned().unwrap_or(StandInUser {
+            id: user_id,
+            name: format!("user{}", user_id),
+            bot: false,
+        });
+        json!({
+            "id": user.id.to_string(),
+            "username": user.name,
+            "global_name": null,
+            "discriminator": "0",
+            "avatar": null,
+            "bot": user.bot,
+            "public_flags": 0,
+        })
+    }
+
+    pub fn current_user_json(&self) -> Value {
+        let bot = self.bot.clone().expect("the stand-in has no bot user");
+        json!({
+            "id": bot.id.to_string(),
+            "username": bot.name,
+            "discriminator": "0",
+            "avatar": null,
+            "bot": true,
+            "mfa_enabled": false,
+            "verified": true,
+            "locale": "en-US",
+            "flags": 0,
+            "public_flags": 0,
+        })
+    }
+
+    pub fn role_json(&self, role: &StandInRole) -> Value {
+        json!({
+            "id": role.id.to_string(),
+            "name": role.name,
+            "color": 0,
+            "hoist": false,
+            "icon": null,
+            "unicode_emoji": null,
+            "managed": false,
+            "mentionable": false,
+            "permissions": role.permissions.to_string(),
+            "position": role.position,
+            "flags": 0,
+        })
+    }
+
+    pub fn channel_json(&self, channel: &StandInChannel) -> Value {
+        let mut value = json!({
+            "id": channel.id.to_string(),
+            "guild_id": channel.guild_id.to_string(),
+            "type": channel.kind,
+            "name": channel.name,
+            "position": 0,
+            "permission_overwrites": [],
+            "nsfw": false,
+            "topic": null,
+            "last_message_id": null,
+            "parent_id": channel.parent_id.map(|id| id.to_string()),
+            "rate_limit_per_user": 0,
+        });
+        if channel.kind == 11 || channel.kind == 12 {
+            value["owner_id"] = json!(self.bot.as_ref().map(|bot| bot.id.to_string()));
+            value["member_count"] = json!(channel.thread_members.len());
+            value["message_count"] = json!(0);
+            value["thread_metadata"] = json!({
+                "archived": false,
+                "auto_archive_duration": 1440,
+                "archive_timestamp": timestamp(channel.id),
+                "locked": false,
+            });
+        }
+
+        value
+    }
+
+    pub fn dm_channel_json(&self, channel_id: u64, user_id: u64) -> Value {
+        json!({
+            "id": channel_id.to_string(),
+            "type": 1,
+            "last_message_id": null,
+            "recipients": [self.user_json(user_id)],
+        })
+    }
+
+    pub fn member_json(&self, member: &StandInMember) -> Value {
+        json!({
+            "user": self.user_json(member.user_id),
+            "nick": member.nick,
+            "avatar": null,
+            "roles": member.roles.iter().map(|id| id.to_string()).collect::<Vec<String>>(),
+            "joined_at": timestamp(member.user_id),
+            "premium_since": null,
+            "deaf": false,
+            "mute": false,
+            "flags": 0,
+            "pending": false,
+            "communication_disabled_until": member.communication_disabled_until,
+        })
+    }
+
+    pub fn message_json(&self, message: &StandInMessage) -> Value {
+        let guild_id = self.channels
+            .get(&message.channel_id)
+            .map(|channel| channel.guild_id.to_string());
+        json!({
+            "id": message.id.to_string(),
+            "channel_id": message.channel_id.to_string(),
+            "guild_id": guild_id,
+            "author": self.user_json(message.author_id),
+            "content": message.content,
+            "timestamp": timestamp(message.id),
+            "edited_timestamp": if message.edited { Some(now_timestamp()) } else { None },
+            "tts": false,
+            "mention_everyone": false,
+            "mentions": [],
+            "mention_roles": [],
+            "mention_channels": [],
+            "attachments": [],
+            "embeds": message.embeds,
+            "components": message.components,
+            "reactions": [],
+            "sticker_items": [],
+            "pinned": false,
+            "type": if message.reply_to.is_some() { 19 } else { 0 },
+            "flags": message.flags,
+            "message_reference": message.reply_to.map(|id| json!({
+                "message_id": id.to_string(),
+                "channel_id": message.channel_id.to_string(),
+            })),
+        })
+    }
+
+    /// The guild, with its roles, channels and members, as sent in `GUILD_CREATE`.
+    pub fn guild_json(&self) -> Value {
+        json!({
+            "id": self.guild_id.to_string(),
+            "name": self.guild_name,
+            "icon": null,
+            "splash": null,
+            "discovery_splash": null,
+            "banner": null,
+            "description": null,
+            "owner_id": self.owner_id.to_string(),
+            "afk_channel_id": null,
+            "afk_timeout": 300,
+            "application_id": null,
+            "verification_level": 0,
+            "default_message_notifications": 0,
+            "explicit_content_filter": 0,
+            "mfa_level": 0,
+            "nsfw_level": 0,
+            "premium_tier": 0,
+            "premium_subscription_count": 0,
+            "premium_progress_bar_enabled": false,
+            "preferred_locale": "en-US",
+            "features": [],
+            "emojis": [],
+            "stickers": [],
+            "system_channel_id": null,
+            "system_channel_flags": 0,
+            "rules_channel_id": null,
+            "public_updates_channel_id": null,
+            "safety_alerts_channel_id": null,
+            "vanity_url_code": null,
+            "max_members": 500000,
+            "max_presences": null,
+            "max_video_channel_users": 25,
+            "widget_enabled": false,
+            "widget_channel_id": null,
+            "joined_at": timestamp(self.guild_id),
+            "large": false,
+            "unavailable": false,
+            "member_count": self.members.len(),
+            "roles": self.roles
+                .values()
+                .map(|role| self.role_json(role))
+                .collect::<Vec<Value>>(),
+            "channels": self.channels
+                .values()
+                .map(|channel| self.channel_json(channel))
+                .collect::<Vec<Value>>(),
+            "members": self.members
+                .values()
+                .map(|member| self.member_json(member))
+                .collect::<Vec<Value>>(),
+            "threads": [],
+            "presences": [],
+            "voice_states": [],
+            "stage_instances": [],
+            "guild_scheduled_events": [],
+        })
+    }
+
+    pub fn create_message(
+        &mut self,
+        channel_id: u64,
+        author_id: u64,
+        body: &Value,
+        interaction_token: Option<String>
+    ) -> StandInMessage {
+        let message = StandInMessage {
+            id: self.next_id(),
+            channel_id,
+            author_id,
+            content: body["content"].as_str().unwrap_or_default().to_string(),
+            embeds: array(&body["embeds"]),
+            components: array(&body["components"]),
+            reply_to: body["message_reference"]["message_id"].as_str().and_then(id),
+            interaction_token,
+            flags: body["flags"].as_u64().unwrap_or_default(),
+            edited: false,
+            revision: self.revision + 1,
+        };
+        self.revision += 1;
+        self.messages.insert(message.id, message.clone());
+
+        message
+    }
+
+    pub fn update_message(&mut self, message_id: u64, body: &Value) -> Option<StandInMessage> {
+        self.revision += 1;
+        let revision = self.revision;
+        let message = self.messages.get_mut(&message_id)?;
+        message.revision = revision;
+        // fields left out of the body are kept, `null` ones are cleared
+        if let Some(content) = body.get("content") {
+            message.content = content.as_str().unwrap_or_default().to_string();
+        }
+        if let Some(embeds) = body.get("embeds") {
+            message.embeds = array(embeds);
+        }
+        if let Some(components) = body.get("components") {
+            message.components = array(components);
+        }
+        message.edited = true;
+
+        Some(message.clone())
+    }
+
+    fn delete_message(&mut self, message_id: u64) -> bool {
+        let deleted = self.messages.remove(&message_id).is_some();
+        if deleted {
+            self.deleted_messages.push(message_id);
+        }
+
+        deleted
+    }
+
+    /// The original response of an interaction, or a followup.
+    fn interaction_message(&self, token: &str, message_id: Option<u64>) -> Option<u64> {
+        self.messages
+            .values()
+            .filter(|message| message.interaction_token.as_deref() == Some(token))
+            .find(|message| message_id.map_or(true, |id| message.id == id))
+            .map(|message| message.id)
+    }
+
+    /// Channel of an interaction, the first channel of the guild if it was not registered.
+    fn interaction_channel(&self, token: &str) -> u64 {
+        self.interaction_channels
+            .get(token)
+            .or_else(|| self.channels.keys().next())
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+/// A stand-in of the Discord REST API, listening on a local port until it's dropped.
+pub struct DiscordStandIn {
+    pub addr: SocketAddr,
+    state: Arc<Mutex<StandInState>>,
+    server: tokio::task::JoinHandle<()>,
+}
+
+impl DiscordStandIn {
+    /// Starts a stand-in with a guild owned by `owner`, with a text channel, two voice channels
+    /// and an @everyone role.
+    pub async fn start(bot: StandInUser, owner: StandInUser) -> Self {
+        let mut state = StandInState::default();
+        state.guild_id = GUILD_ID;
+        state.guild_name = "Test guild".to_string();
+        state.owner_id = owner.id;
+        state.roles.insert(state.guild_id, StandInRole {
+            id: state.guild_id,
+            name: "@everyone".to_string(),
+            permissions: EVERYONE_PERMISSIONS,
+            position: 0,
+        });
+        let channels = [
+            (CHANNEL_ID, "general", 0),
+            (VOICE_CHANNEL_ID, "General", 2),
+            (AFK_CHANNEL_ID, "AFK", 2),
+        ];
+        for (id, name, kind) in channels {
+            state.channels.insert(id, StandInChannel {
+                id,
+                guild_id: GUILD_ID,
+                name: name.to_string(),
+                kind,
+                parent_id: None,
+                thread_members: HashSet::new(),
+            });
+        }
+        for user in [&bot, &owner] {
+            state.members.insert(user.id, StandInMember {
+                user_id: user.id,
+                nick: None,
+                roles: vec![],
+                communication_disabled_until: None,
+            });
+        }
+        state.users.insert(owner.id, owner);
+        state.users.insert(bot.id, bot.clone());
+        state.bot = Some(bot);
+
+        let state = Arc::new(Mutex::new(state));
+        let app = Router::new().fallback(handle_request).with_state(Arc::clone(&state));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        Self { addr, state, server }
+    }
+
+    /// Host the bot's HTTP client is pointed at.
+    pub fn host(&self) -> String {
+        self.addr.to_string()
+    }
+
+    pub fn state(&self) -> MutexGuard<'_, StandInState> {
+        self.state.lock().unwrap()
+    }
+
+    pub fn guild_id(&self) -> u64 {
+        self.state().guild_id
+    }
+
+    /// The first text channel of the guild.
+    pub fn channel_id(&self) -> u64 {
+        *self.state().channels.keys().next().unwrap()
+    }
+
+    pub fn add_user(&self, name: &str, bot: bool) -> StandInUser {
+        let mut state = self.state();
+        let user = StandInUser { id: state.next_id(), name: name.to_string(), bot };
+        state.users.insert(user.id, user.clone());
+
+        user
+    }
+
+    pub fn add_member(&self, user: &StandInUser, roles: Vec<u64>) -> StandInMember {
+        let member = StandInMember {
+            user_id: user.id,
+            nick: None,
+            roles,
+            communication_disabled_until: None,
+        };
+        let mut state = self.state();
+        state.users.insert(user.id, user.clone());
+        state.members.insert(user.id, member.clone());
+
+        member
+    }
+
+    pub fn add_role(&self, name: &str, permissions: u64) -> StandInRole {
+        let mut state = self.state();
+        let role = StandInRole {
+            id: state.next_id(),
+            name: name.to_string(),
+            permissions,
+            position: state.roles.len() as i64,
+        };
+        state.roles.insert(role.id, role.clone());
+
+        role
+    }
+
+    /// Stores a message sent by a user, as if it went through Discord.
+    pub fn add_message(&self, channel_id: u64, author_id: u64, content: &str) -> StandInMessage {
+        self.state().create_message(channel_id, author_id, &json!({ "content": content }), None)
+    }
+
+    /// Messages of the bot, oldest first.
+    pub fn bot_messages(&self) -> Vec<StandInMessage> {
+        self.state().bot_messages().into_iter().cloned().collect()
+    }
+
+    /// Changes the content of a stored message, as if its author edited it.
+    pub fn edit_message(&self, message_id: u64, content: &str) -> StandInMessage {
+        self.state()
+            .update_message(message_id, &json!({ "content": content }))
+            .expect("the edited message does not exist")
+    }
+}
+
+impl Drop for DiscordStandIn {
+    fn drop(&mut self) {
+        self.server.abort();
+    }
+}
+
+async fn handle_request(
+    State(state): State<Arc<Mutex<StandInState>>>,
+    method: Method,
+    uri: Uri,
+    body: Bytes
+) -> Response {
+    let body: Value = serde_json::from_slice(&body).unwrap_or(Value::Null);
+    let path = uri.path().trim_start_matches("/api/v10/").to_string();
+    let segments: Vec<&str> = path.split('/').collect();
+    let mut state = state.lock().unwrap();
+    state.requests.push(RecordedRequest {
+        method: method.clone(),
+        path: path.clone(),
+        body: body.clone(),
+    });
+    let bot_id = state.bot.as_ref().map_or(0, |bot| bot.id);
+
+    match (method.as_str(), segments.as_slice()) {
+        ("GET", ["users", "@me"]) => ok(state.current_user_json()),
+        ("GET", ["users", user_id]) => ok(state.user_json(id(user_id).unwrap_or_default())),
+        ("POST", ["users", "@me", "channels"]) => {
+            let user_id = body["recipient_id"].as_str().and_then(id).unwrap_or_default();
+            let channel_id = match state.dm_channels.get(&user_id) {
+                Some(channel_id) => *channel_id,
+                None => {
+                    let channel_id = state.next_id();
+                    state.dm_channels.insert(user_id, channel_id);
+                    channel_id
+                }
+            };
+            ok(state.dm_channel_json(channel_id, user_id))
+        }
+
+        // messages
+        ("GET", ["channels", channel_id, "messages"]) => {
+            let limit = query_value(&uri, "limit").unwrap_or(50) as usize;
+            let channel_id = id(channel_id).unwrap_or_default();
+            // newest first, like Discord
+            let messages: Vec<Value> = state
+                .channel_messages(channel_id)
+                .into_iter()
+                .rev()
+                .take(limit)
+                .map(|message| state.message_json(message))
+                .collect();
+            ok(Value::Array(messages))
+        }
+        ("POST", ["channels", channel_id, "messages"]) => {
+            let message = state.create_message(
+                id(channel_id).unwrap_or_default(),
+                bot_id,
+                &body,
+                None
+            );
+            ok(state.message_json(&message))
+        }
+        ("POST", ["channels", _, "messages", "bulk-delete"]) => {
+            for message_id in array(&body["messages"]) {
+                if let Some(message_id) = message_id.as_str().and_then(id) {
+                    state.delete_message(message_id);
+                }
+            }
+            no_content()
+        }
+        ("GET", ["channels", _, "messages", message_id]) => {
+            match state.messages.get(&id(message_id).unwrap_or_default()) {
+                Some(message) => ok(state.message_json(message)),
+                None => not_found(10008, "Unknown Message"),
+            }
+        }
+        ("PATCH", ["channels", _, "messages", message_id]) => {
+            match state.update_message(id(message_id).unwrap_or_default(), &body) {
+                Some(message) => ok(state.message_json(&message)),
+                None => not_found(10008, "Unknown Message"),
+            }
+        }
+        ("DELETE", ["channels", _, "messages", message_id]) => {
+            if state.delete_message(id(message_id).unwrap_or_default()) {
+                no_content()
+            } else {
+                not_found(10008, "Unknown Message")
+            }
+        }
+        ("POST", ["channels", _, "typing"]) => no_content(),
+        ("PUT" | "DELETE", ["channels", _, "messages", _, "reactions", ..]) => no_content(),
+
+        // channels and threads
+        ("GET", ["channels", channel_id]) => {
+            match state.channels.get(&id(channel_id).unwrap_or_default()) {
+                Some(channel) => ok(state.channel_json(channel)),
+                None => not_found(10003, "Unknown Channel"),
+            }
+        }
+        ("PATCH", ["channels", channel_id]) => {
+            let channel = state.channels.get_mut(&id(channel_id).unwrap_or_default());
+            match channel {
+                Some(channel) => {
+                    if let Some(name) = body["name"].as_str() {
+                        channel.name = name.to_string();
+                    }
+                    let channel = channel.clone();
+                    ok(state.channel_json(&channel))
+                }
+                None => not_found(10003, "Unknown Channel"),
+            }
+        }
+        ("DELETE", ["channels", channel_id]) => {
+            match state.channels.remove(&id(channel_id).unwrap_or_default()) {
+                Some(channel) => ok(state.channel_json(&channel)),
+                None => not_found(10003, "Unknown Channel"),
+            }
+        }
+        ("PUT" | "DELETE", ["channels", _, "permissions", _]) => no_content(),
+        | ("POST", ["channels", parent_id, "threads"])
+        | ("POST", ["channels", parent_id, "messages", _, "threads"]) => {
+            let parent_id = id(parent_id).unwrap_or_default();
+            let thread = StandInChannel {
+                id: state.next_id(),
+                guild_id: state.guild_id,
+                name: body["name"].as_str().unwrap_or("thread").to_string(),
+                kind: body["type"].as_u64().unwrap_or(11) as u8,
+                parent_id: Some(parent_id),
+                thread_members: HashSet::from([bot_id]),
+            };
+            state.channels.insert(thread.id, thread.clone());
+            ok(state.channel_json(&thread))
+        }
+        ("PUT" | "DELETE", ["channels", thread_id, "thread-members", user_id]) => {
+            let user_id = if *user_id == "@me" { bot_id } else { id(user_id).unwrap_or_default() };
+            match state.channels.get_mut(&id(thread_id).unwrap_or_default()) {
+                Some(thread) => {
+                    if method == Method::PUT {
+                        thread.thread_members.insert(user_id);
+                    } else {
+                        thread.thread_members.remove(&user_id);
+                    }
+                    no_content()
+                }
+                None => not_found(10003, "Unknown Channel"),
+            }
+        }
+
+        // guild, roles and members
+        ("GET", ["guilds", _]) => ok(state.guild_json()),
+        ("GET", ["guilds", _, "roles"]) => {
+            ok(
+                Value::Array(
+                    state.roles
+                        .values()
+                        .map(|role| state.role_json(role))
+                        .collect()
+                )
+            )
+        }
+        ("GET", ["guilds", _, "channels"]) => {
+            ok(
+                Value::Array(
+                    state.channels
+                        .values()
+                        .map(|channel| state.channel_json(channel))
+                        .collect()
+                )
+            )
+        }
+        ("POST", ["guilds", _, "channels"]) => {
+            let channel = StandInChannel {
+                id: state.next_id(),
+                guild_id: state.guild_id,
+                name: body["name"].as_str().unwrap_or("channel").to_string(),
+                kind: body["type"].as_u64().unwrap_or(0) as u8,
+                parent_id: body["parent_id"].as_str().and_then(id),
+                thread_members: HashSet::new(),
+            };
+            state.channels.insert(channel.id, channel.clone());
+            ok(state.channel_json(&channel))
+        }
+        ("GET", ["guilds", _, "members", user_id]) => {
+            match state.members.get(&id(user_id).unwrap_or_default()) {
+                Some(member) => ok(state.member_json(member)),
+                None => not_found(10007, "Unknown Member"),
+            }
+        }
+        ("PATCH", ["guilds", _, "members", user_id]) => {
+            match state.members.get_mut(&id(user_id).unwrap_or_default()) {
+                Some(member) => {
+                    if let Some(nick) = body.get("nick") {
+                        member.nick = nick.as_str().map(String::from);
+                    }
+                    if let Some(until) = body.get("communication_disabled_until") {
+                        member.communication_disabled_until = until.as_str().map(String::from);
+                    }
+                    if let Some(roles) = body.get("roles") {
+                        member.roles = array(roles)
+                            .iter()
+                            .filter_map(|role| role.as_str().and_then(id))
+                            .collect();
+                    }
+                    let member = member.clone();
+                    ok(state.member_json(&member))
+                }
+                None => not_found(10007, "Unknown Member"),
+            }
+        }
+        ("DELETE", ["guilds", _, "members", user_id]) => {
+            let user_id = id(user_id).unwrap_or_default();
+            if state.members.remove(&user_id).is_some() {
+                state.kicked.push(user_id);
+                no_content()
+            } else {
+                not_found(10007, "Unknown Member")
+            }
+        }
+        ("PUT" | "DELETE", ["guilds", _, "members", user_id, "roles", role_id]) => {
+            let role_id = id(role_id).unwrap_or_default();
+            match state.members.get_mut(&id(user_id).unwrap_or_default()) {
+                Some(member) => {
+                    member.roles.retain(|id| *id != role_id);
+                    if method == Method::PUT {
+                        member.roles.push(role_id);
+                    }
+                    no_content()
+                }
+                None => not_found(10007, "Unknown Member"),
+            }
+        }
+        ("PUT", ["guilds", _, "bans", user_id]) => {
+            let user_id = id(user_id).unwrap_or_default();
+            state.members.remove(&user_id);
+            state.bans.insert(user_id);
+            no_content()
+        }
+        ("DELETE", ["guilds", _, "bans", user_id]) => {
+            if state.bans.remove(&id(user_id).unwrap_or_default()) {
+                no_content()
+            } else {
+                not_found(10026, "Unknown Ban")
+            }
+        }
+
+        // interactions, the responses are stored as messages of the bot
+        ("POST", ["interactions", interaction_id, token, "callback"]) => {
+            let kind = body["type"].as_u64().unwrap_or_default();
+            let token = token.to_string();
+            state.interaction_callbacks.push(InteractionCallback {
+                interaction_id: id(interaction_id).unwrap_or_default(),
+                token: token.clone(),
+                kind,
+                data: body["data"].clone(),
+            });
+            // 4 answers with a message, 7 updates the message of the component
+            match kind {
+                4 => {
+                    let channel_id = state.interaction_channel(&token);
+                    state.create_message(channel_id, bot_id, &body["data"], Some(token));
+                }
+                7 => {
+                    if let Some(message_id) = state.interaction_message(&token, None) {
+                        state.update_message(message_id, &body["data"]);
+                    }
+                }
+                _ => {}
+            }
+            no_content()
+        }
+        ("PATCH", ["webhooks", _, token, "messages", "@original"]) => {
+            let token = token.to_string();
+            let message_id = match state.interaction_message(&token, None) {
+                Some(message_id) => message_id,
+                None => {
+                    // the deferred response becomes a message when it's first edited
+                    let channel_id = state.interaction_channel(&token);
+                    state.create_message(channel_id, bot_id, &json!({}), Some(token)).id
+                }
+            };
+            let message = state.update_message(message_id, &body).unwrap();
+            ok(state.message_json(&message))
+        }
+        ("GET", ["webhooks", _, token, "messages", "@original"]) => {
+            match state.interaction_message(token, None) {
+                Some(message_id) => ok(state.message_json(&state.messages[&message_id])),
+                None => not_found(10008, "Unknown Message"),
+            }
+        }
+        ("PATCH", ["webhooks", _, token, "messages", message_id]) => {
+            let message_id = state.interaction_message(token, id(message_id));
+            match message_id.and_then(|message_id| state.update_message(message_id, &body)) {
+                Some(message) => ok(state.message_json(&message)),
+                None => not_found(10008, "Unknown Message"),
+            }
+        }
+        ("POST", ["webhooks", _, token]) => {
+            let token = token.to_string();
+            let channel_id = state.interaction_channel(&token);
+            let message = state.create_message(channel_id, bot_id, &body, Some(token));
+            ok(state.message_json(&message))
+        }
+
+        _ => not_found(0, "Not implemented by the stand-in"),
+    }
+}
+
+fn ok(value: Value) -> Response {
+    (StatusCode::OK, Json(value)).into_response()
+}
+
+fn no_content() -> Response {
+    StatusCode::NO_CONTENT.into_response()
+}
+
+fn not_found(code: u64, message: &str) -> Response {
+    (StatusCode::NOT_FOUND, Json(json!({ "code": code, "message": message }))).into_response()
+}
+
+fn id(value: &str) -> Option<u64> {
+    value.parse().ok()
+}
+
+fn array(value: &Value) -> Vec<Value> {
+    value.as_array().cloned().unwrap_or_default()
+}
+
+fn query_value(uri: &Uri, key: &str) -> Option<u64> {
+    uri.query()?
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(name, _)| *name == key)
+        .and_then(|(_, value)| value.parse().ok())
+}
+
+/// ISO 8601 timestamp of a snowflake.
+pub fn timestamp(snowflake: u64) -> String {
+    let millis = (snowflake >> 22) + DISCORD_EPOCH_MILLIS;
+    Utc.timestamp_millis_opt(millis as i64).unwrap().to_rfc3339()
+}
+
+fn now_timestamp() -> String {
+    Utc::now().to_rfc3339()
+}
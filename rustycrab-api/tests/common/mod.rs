@@ -0,0 +1,7 @@
+//! Helpers shared by the integration tests: a stand-in of the Discord REST API, a bot running
+//! against it and the replay of recorded gateway events.
+#![allow(dead_code)]
+
+pub mod bot;
+pub mod discord;
+pub mod replay;
@@ -0,0 +1,123 @@
+//! Replays recorded gateway events through the bot's event handlers.
+
+use std::{ fs, sync::Arc };
+
+use rustycrab_api::twilightrs::{
+    event_recorder::read_recording,
+    events::{ handle_event, prepare_event },
+};
+use twilight_gateway::Event;
+
+use super::{
+    bot::TestBot,
+    discord::{ StandInMember, StandInMessage, StandInUser },
+};
+
+/// The events of `tests/recordings/<name>.jsonl`, in the order they were received.
+pub fn recording(name: &str) -> Vec<Event> {
+    let path = format!("{}/tests/recordings/{}.jsonl", env!("CARGO_MANIFEST_DIR"), name);
+    let content = fs::read_to_string(&path).expect("reading the recording failed");
+
+    read_recording(&content)
+        .expect("the recording is not valid JSON")
+        .into_iter()
+        .map(|event| {
+            event
+                .into_event()
+                .expect("the recording has an invalid payload")
+                .expect("the recording has an event kind that is not recorded")
+        })
+        .collect()
+}
+
+impl TestBot {
+    /// Replays events like the gateway loop handles them, one after the other, returns the
+    /// messages the bot sent or edited meanwhile.
+    pub async fn replay(&self, events: Vec<Event>) -> Vec<StandInMessage> {
+        let revision = self.discord.state().revision;
+        for event in events {
+            self.replay_event(event).await;
+        }
+
+        self.bot_messages_since(revision)
+    }
+
+    /// Handles one event, after telling the stand-in what the event says happened on Discord.
+    pub async fn replay_event(&self, event: Event) {
+        self.observe(&event);
+
+        let before = prepare_event(&self.client, &event).await;
+        handle_event(
+            Arc::clone(&self.client),
+            event,
+            Arc::clone(&self.event_bus),
+            before
+        ).await.expect("handling the event failed");
+    }
+
+    /// Keeps the stand-in in line with the event, so the bot can fetch what it's about.
+    fn observe(&self, event: &Event) {
+        let mut state = self.discord.state();
+        match event {
+            Event::MessageCreate(message) => {
+                let author = &message.author;
+                state.users.entry(author.id.get()).or_insert_with(|| StandInUser {
+                    id: author.id.get(),
+                    name: author.name.clone(),
+                    bot: author.bot,
+                });
+                let revision = state.revision;
+                state.messages.insert(message.id.get(), StandInMessage {
+                    id: message.id.get(),
+                    channel_id: message.channel_id.get(),
+                    author_id: author.id.get(),
+                    content: message.content.clone(),
+                    embeds: Vec::new(),
+                    components: Vec::new(),
+                    reply_to: None,
+                    interaction_token: None,
+                    flags: 0,
+                    edited: false,
+                    revision,
+                });
+            }
+            Event::MessageUpdate(update) => {
+                if let Some(message) = state.messages.get_mut(&update.id.get()) {
+                    if let Some(content) = &update.content {
+                        message.content = content.clone();
+                        message.edited = true;
+                    }
+                }
+            }
+            Event::MessageDelete(deleted) => {
+                state.messages.remove(&deleted.id.get());
+            }
+            Event::MemberAdd(member) => {
+                let user = StandInUser {
+                    id: member.user.id.get(),
+                    name: member.user.name.clone(),
+                    bot: member.user.bot,
+                };
+                state.members.insert(user.id, StandInMember {
+                    user_id: user.id,
+                    nick: member.nick.clone(),
+                    roles: member.roles
+                        .iter()
+                        .map(|role_id| role_id.get())
+                        .collect(),
+                    communication_disabled_until: None,
+                });
+                state.users.insert(user.id, user);
+            }
+            Event::MemberRemove(member) => {
+                state.members.remove(&member.user.id.get());
+            }
+            Event::InteractionCreate(interaction) => {
+                if let Some(channel) = &interaction.channel {
+                    state.interaction_channels.insert(interaction.token.clone(), channel.id.get());
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,233 @@
+mod common;
+
+use common::{
+    bot::{ from_json, TestBot, OWNER_ID },
+    discord::{ AFK_CHANNEL_ID, CHANNEL_ID, GUILD_ID, VOICE_CHANNEL_ID },
+    replay::recording,
+};
+use rustycrab_api::{
+    database::{
+        buttons,
+        messages,
+        ticket_panels,
+        ticket_settings,
+        ticket_support_teams,
+        prelude::Tickets,
+    },
+    twilightrs::{
+        bot::{ welcome::send_welcome_message, goodbye::send_goodbye_message },
+        events::voice_updates::update_type::{ get_update_type, VoiceUpdateType },
+    },
+};
+use sea_orm::{ ActiveModelTrait, EntityTrait, Set };
+use twilight_gateway::Event;
+use twilight_model::{
+    gateway::payload::incoming::{ MemberAdd, MemberRemove },
+    id::{ Id, marker::{ GuildMarker, UserMarker } },
+    voice::VoiceState,
+};
+
+/// The member the recordings were made with, besides the owner.
+const MEMBER_ID: u64 = 1_002;
+
+#[tokio::test]
+async fn afk_members_are_announced_until_they_talk_again() {
+    let bot = TestBot::new().await;
+
+    let replies = bot.replay(recording("afk")).await;
+
+    assert!(replies.iter().any(|reply| reply.contains("your AFK status has been updated")));
+    let announcement = replies
+        .iter()
+        .find(|reply| reply.contains("`@owner` is AFK"))
+        .expect("the mention of the AFK owner was not answered");
+    assert_eq!(announcement.custom_ids(), vec![format!("2:{}", OWNER_ID)]);
+    let notified = "You will be notified when this user is back";
+    assert!(replies.iter().any(|reply| reply.contains(notified)));
+    let back = format!("<@{}> is no longer afk", OWNER_ID);
+    assert!(replies.iter().any(|reply| reply.contains(&back)));
+
+    let state = bot.discord.state();
+    assert_eq!(state.members[&OWNER_ID].nick.as_deref(), Some("[AFK] owner"));
+    let notifications = state.dm_messages(MEMBER_ID);
+    assert_eq!(notifications.len(), 1);
+    assert!(notifications[0].contains(&format!("AFK Notification: <@{}> is back", OWNER_ID)));
+
+    let afk_users = bot.client.afk_users.read().unwrap();
+    assert!(afk_users.get(&Id::new(GUILD_ID)).map_or(true, |guild| guild.is_empty()));
+}
+
+#[tokio::test]
+async fn deleted_messages_can_be_sniped() {
+    let bot = TestBot::new().await;
+
+    let replies = bot.replay(recording("snipe")).await;
+
+    {
+        let deleted_messages = bot.client.deleted_messages.read().unwrap();
+        let sniped = &deleted_messages[&Id::new(CHANNEL_ID)];
+        assert_eq!(sniped.len(), 1);
+        assert_eq!(sniped[0].content(), "nobody saw that");
+    }
+    assert_eq!(replies.len(), 1);
+    assert_eq!(replies[0].embed_descriptions(), vec!["nobody saw that"]);
+}
+
+#[tokio::test]
+async fn greetings_are_skipped_without_a_configuration() {
+    let bot = TestBot::new().await;
+    let user = bot.add_member("member", &[]);
+    let mut member_json = {
+        let state = bot.discord.state();
+        state.member_json(&state.members[&user.id])
+    };
+    member_json["guild_id"] = GUILD_ID.to_string().into();
+    let member_add: MemberAdd = from_json(member_json.clone());
+    let member_remove: MemberRemove = from_json(
+        serde_json::json!({ "guild_id": GUILD_ID.to_string(), "user": member_json["user"] })
+    );
+
+    assert!(send_welcome_message(bot.client.clone(), &member_add).await.is_ok());
+    assert!(send_goodbye_message(bot.client.clone(), &member_remove).await.is_ok());
+    assert!(bot.discord.bot_messages().is_empty());
+}
+
+#[tokio::test]
+async fn voice_state_updates_are_told_apart() {
+    let bot = TestBot::new().await;
+    let guild_id = Id::new(GUILD_ID);
+    let user_id = Id::new(MEMBER_ID);
+
+    let mut transitions = Vec::new();
+    for event in recording("voice") {
+        let new_state = match &event {
+            Event::VoiceStateUpdate(update) => update.0.clone(),
+            _ => panic!("the voice recording has other events"),
+        };
+        let old_state = cached_voice_state(&bot, guild_id, user_id);
+
+        bot.replay_event(event).await;
+
+        assert_eq!(cached_voice_state(&bot, guild_id, user_id).as_ref(), Some(&new_state));
+        transitions.push(get_update_type(&old_state, &new_state));
+    }
+
+    let (voice_channel_id, afk_channel_id) = (Id::new(VOICE_CHANNEL_ID), Id::new(AFK_CHANNEL_ID));
+    assert!(
+        matches!(
+            transitions.as_slice(),
+            [
+                VoiceUpdateType::Join(joined),
+                VoiceUpdateType::ChannelSwitch(from, to),
+                VoiceUpdateType::UpdateWithinChannel(within),
+                VoiceUpdateType::Leave(Some(left)),
+            ]
+                if *joined == voice_channel_id &&
+                *from == voice_channel_id &&
+                *to == afk_channel_id &&
+                *within == afk_channel_id &&
+                *left == afk_channel_id
+        ),
+        "unexpected transitions {:?}",
+        transitions
+    );
+    assert!(bot.client.cache.voice_state(user_id, guild_id).is_none());
+}
+
+#[tokio::test]
+async fn ticket_buttons_open_a_ticket_channel() {
+    let bot = TestBot::new().await;
+    // the recorded button opens the first panel
+    assert_eq!(seed_ticket_panel(&bot).await, 1);
+
+    let replies = bot.replay(recording("ticket")).await;
+
+    let channel_id = bot.discord
+        .state()
+        .channels
+        .values()
+        .find(|channel| channel.name == "ticket-member")
+        .map(|channel| channel.id)
+        .expect("the ticket channel was not created");
+    let created = format!("Ticket created at <#{}>", channel_id);
+    assert!(replies.iter().any(|reply| reply.contains(&created)));
+    assert_eq!(bot.discord.state().interaction_callbacks[0].kind, 5);
+
+    let ticket = Tickets::find().one(&bot.client.db).await.unwrap().expect("no ticket was saved");
+    assert_eq!(ticket.user_id, MEMBER_ID.to_string());
+    assert_eq!(ticket.status.as_deref(), Some("Opened"));
+    assert_eq!(ticket.channel_id, Some(channel_id.to_string()));
+}
+
+fn cached_voice_state(
+    bot: &TestBot,
+    guild_id: Id<GuildMarker>,
+    user_id: Id<UserMarker>
+) -> Option<VoiceState> {
+    bot.client.voice_states_cached
+        .read()
+        .unwrap()
+        .get(&guild_id)
+        .and_then(|guild| guild.get(&user_id))
+        .cloned()
+}
+
+/// Adds the guild's ticket settings and a panel without questions opening channel tickets,
+/// returns the panel's ID.
+async fn seed_ticket_panel(bot: &TestBot) -> i32 {
+    let db = &bot.client.db;
+    let (bot_id, guild_id) = (bot.config.bot_id, bot.config.guild_id);
+
+    (ticket_settings::ActiveModel {
+        per_user_ticket_limit: Set(3),
+        allow_user_to_close_tickets: Set(1),
+        ticket_close_confirmation: Set(1),
+        thread_ticket: Set(0),
+        bot_id: Set(bot_id),
+        guild_id: Set(guild_id),
+        ..Default::default()
+    }).insert(db).await.expect("adding the ticket settings failed");
+    let team = (ticket_support_teams::ActiveModel {
+        name: Set("Support".to_string()),
+        roles: Set(String::new()),
+        users: Set(String::new()),
+        bot_id: Set(bot_id),
+        guild_id: Set(guild_id),
+        ..Default::default()
+    }).insert(db).await.expect("adding the support team failed");
+
+    let mut message_ids = Vec::new();
+    for content in ["Open a ticket", "Welcome"] {
+        let message = (messages::ActiveModel {
+            content: Set(Some(content.to_string())),
+            r#type: Set("Message".to_string()),
+            embed_id: Set(None),
+            ..Default::default()
+        }).insert(db).await.expect("adding a panel message failed");
+        message_ids.push(message.id);
+    }
+    let button = (buttons::ActiveModel {
+        color: Set("Green".to_string()),
+        text: Set("Open".to_string()),
+        emoji: Set(String::new()),
+        ..Default::default()
+    }).insert(db).await.expect("adding the panel button failed");
+
+    let panel = (ticket_panels::ActiveModel {
+        mention_on_open: Set(String::new()),
+        naming_scheme: Set("ticket-{username}".to_string()),
+        channel_id: Set(CHANNEL_ID.to_string()),
+        ticket_category: Set(String::new()),
+        sent_message_id: Set(String::new()),
+        bot_id: Set(bot_id),
+        guild_id: Set(guild_id),
+        message_id: Set(message_ids[0]),
+        button_id: Set(button.id),
+        welcome_message_id: Set(message_ids[1]),
+        support_team_id: Set(Some(team.id)),
+        questions: Set(None),
+        ..Default::default()
+    }).insert(db).await.expect("adding the panel failed");
+
+    panel.id
+}
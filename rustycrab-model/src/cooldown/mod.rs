@@ -0,0 +1,28 @@
+use serde::{ Deserialize, Serialize };
+
+/// Who shares a command's cooldown.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum CooldownScope {
+    /// Each user has their own cooldown
+    User,
+    /// Everyone in a channel shares the cooldown
+    Channel,
+    /// Everyone in the guild shares the cooldown
+    Guild,
+}
+
+/// Rate limit of a command: at most `burst` uses every `window` seconds.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommandCooldown {
+    pub scope: CooldownScope,
+    pub burst: u32,
+    /// Length of the window, in seconds
+    pub window: u64,
+}
+
+impl CommandCooldown {
+    pub fn new(scope: CooldownScope, burst: u32, window: u64) -> Self {
+        Self { scope, burst, window }
+    }
+}
@@ -6,3 +6,5 @@ pub mod afk;
 pub mod music;
 pub mod color;
 pub mod auth;
+pub mod cooldown;
+pub mod modules;
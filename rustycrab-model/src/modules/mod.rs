@@ -0,0 +1,67 @@
+use serde::{ Deserialize, Serialize };
+
+/// Bot features that can be turned off per guild.
+///
+/// Each module is mapped to a bit of the guild configuration's `module_flags`. A set bit disables
+/// the module, so guilds with the default flags of `0` have every module enabled.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum GuildModule {
+    Music,
+    Tickets,
+    AutoResponses,
+    Afk,
+    Snipe,
+    Moderation,
+    Logging,
+}
+
+impl GuildModule {
+    pub const ALL: [GuildModule; 7] = [
+        GuildModule::Music,
+        GuildModule::Tickets,
+        GuildModule::AutoResponses,
+        GuildModule::Afk,
+        GuildModule::Snipe,
+        GuildModule::Moderation,
+        GuildModule::Logging,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            GuildModule::Music => "music",
+            GuildModule::Tickets => "tickets",
+            GuildModule::AutoResponses => "auto_responses",
+            GuildModule::Afk => "afk",
+            GuildModule::Snipe => "snipe",
+            GuildModule::Moderation => "moderation",
+            GuildModule::Logging => "logging",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        GuildModule::ALL.into_iter().find(|module| module.name() == name.to_lowercase())
+    }
+
+    /// The bit of `module_flags` this module is mapped to. Bits must never be reused.
+    pub fn flag(&self) -> i32 {
+        match self {
+            GuildModule::Music => 1 << 0,
+            GuildModule::Tickets => 1 << 1,
+            GuildModule::AutoResponses => 1 << 2,
+            GuildModule::Afk => 1 << 3,
+            GuildModule::Snipe => 1 << 4,
+            GuildModule::Moderation => 1 << 5,
+            GuildModule::Logging => 1 << 6,
+        }
+    }
+
+    pub fn is_enabled(&self, module_flags: i32) -> bool {
+        module_flags & self.flag() == 0
+    }
+
+    /// Returns `module_flags` with this module enabled or disabled.
+    pub fn set_enabled(&self, module_flags: i32, enabled: bool) -> i32 {
+        if enabled { module_flags & !self.flag() } else { module_flags | self.flag() }
+    }
+}
@@ -0,0 +1,27 @@
+use serde::{ Serialize, Deserialize };
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ResponseAutoRole {
+    pub id: i32,
+    pub r#type: String,
+    pub role_id: String,
+    pub delay: i32,
+    pub bot_id: i32,
+    pub guild_id: i32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RequestCreateAutoRole {
+    pub bot_discord_id: String,
+    pub guild_discord_id: String,
+    pub r#type: String,
+    pub role_id: String,
+    pub delay: Option<i32>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct RequestUpdateAutoRole {
+    pub r#type: Option<String>,
+    pub role_id: Option<String>,
+    pub delay: Option<i32>,
+}
@@ -1,5 +1,9 @@
+use std::collections::HashMap;
+
 use serde::{ Deserialize, Serialize };
 
+use crate::{ cooldown::CommandCooldown, modules::GuildModule };
+
 use super::{ bots::ResponseBot, guilds::ResponseGuild };
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -16,6 +20,15 @@ pub struct RequestUpdateConfig {
     pub locale: Option<String>,
     pub module_flags: Option<i32>,
     pub premium_flags: Option<i32>,
+    /// Cooldown overrides keyed by the full command name, e.g. `autores add`.
+    /// Replaces every existing override when set.
+    pub command_cooldowns: Option<HashMap<String, CommandCooldown>>,
+    /// Whether unknown commands are answered with "did you mean" suggestions, 0 or 1.
+    pub command_suggestions: Option<i8>,
+    /// Prefixes accepted besides `prefix`, e.g. `rc `. Replaces every existing one when set.
+    pub extra_prefixes: Option<Vec<String>>,
+    /// Whether mentioning the bot works as a prefix, 0 or 1.
+    pub mention_prefix: Option<i8>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -27,6 +40,10 @@ pub struct ResponseGuildConfig {
     pub guild_id: i32,
     pub module_flags: i32,
     pub premium_flags: i32,
+    pub command_cooldowns: HashMap<String, CommandCooldown>,
+    pub command_suggestions: i8,
+    pub extra_prefixes: Vec<String>,
+    pub mention_prefix: i8,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -39,3 +56,9 @@ pub struct ResponseGuildConfigDetails {
     pub module_flags: i32,
     pub premium_flags: i32,
 }
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ResponseGuildModule {
+    pub module: GuildModule,
+    pub enabled: bool,
+}
@@ -0,0 +1,26 @@
+use serde::{ Deserialize, Serialize };
+
+use super::discord_message::RequestCreateUpdateMessage;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RequestCreateGoodbye {
+    pub bot_discord_id: String,
+    pub guild_discord_id: String,
+    pub message_data: Option<RequestCreateUpdateMessage>,
+    pub channel_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RequestUpdateGoodbye {
+    pub channel_id: Option<String>,
+    pub message_data: Option<RequestCreateUpdateMessage>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ResponseGuildGoodbye {
+    pub id: i32,
+    pub channel_id: Option<String>,
+    pub bot_id: i32,
+    pub guild_id: i32,
+    pub message_id: Option<i32>,
+}
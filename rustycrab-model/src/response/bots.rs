@@ -29,3 +29,29 @@ pub struct ResponseBot {
     pub discord_callback_url: String,
     pub premium_flags: i32,
 }
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ResponseBotStatus {
+    pub bot_id: String,
+    pub status: String,
+    pub restarts: u32,
+    pub last_error: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct RequestSyncCommands {
+    /// Syncs the commands of a single guild instead of the global commands
+    pub guild_discord_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ResponseCommandSync {
+    /// `global`, or the Discord ID of the guild
+    pub scope: String,
+    pub created: Vec<String>,
+    pub updated: Vec<String>,
+    pub deleted: Vec<String>,
+    pub unchanged: usize,
+    /// Commands Discord rejected, with the error it returned
+    pub failed: Vec<(String, String)>,
+}
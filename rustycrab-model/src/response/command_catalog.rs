@@ -0,0 +1,40 @@
+use serde::{ Serialize, Deserialize };
+
+use crate::modules::GuildModule;
+
+/// An argument or flag of a command.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ResponseCommandArg {
+    pub name: String,
+    /// `text`, `number`, `user`, `channel`, `role`, `duration`, `bool`, `choice`...
+    pub kind: String,
+    pub optional: bool,
+    /// The accepted values of `choice` arguments
+    pub choices: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ResponseCommand {
+    pub name: String,
+    /// Full name of the command, e.g. `autores add`
+    pub full_name: String,
+    pub aliases: Vec<String>,
+    /// Localized description, `None` for undocumented commands
+    pub description: Option<String>,
+    /// Usage without the prefix, e.g. `autores add <trigger> <response>`
+    pub usage: String,
+    pub args: Vec<ResponseCommandArg>,
+    pub flags: Vec<ResponseCommandArg>,
+    /// Permissions the user needs, any one of the alternatives is enough, e.g.
+    /// `[["administrator"], ["kick_members", "ban_members"]]`
+    pub permissions: Vec<Vec<String>>,
+    /// The module the command belongs to, `None` for commands that are always available
+    pub module: Option<GuildModule>,
+    pub subcommands: Vec<ResponseCommand>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ResponseCommandCategory {
+    pub name: String,
+    pub commands: Vec<ResponseCommand>,
+}
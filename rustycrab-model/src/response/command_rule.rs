@@ -0,0 +1,36 @@
+use serde::{ Serialize, Deserialize };
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ResponseCommandRule {
+    pub id: i32,
+    /// `command`, `category` or `module`
+    pub target: String,
+    /// Full name of the command, e.g. `autores add`, or name of the category or module
+    pub name: String,
+    /// `channel`, `role` or `user`
+    pub subject: String,
+    pub subject_id: String,
+    pub allow: i8,
+    pub bot_id: i32,
+    pub guild_id: i32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RequestCreateCommandRule {
+    pub bot_discord_id: String,
+    pub guild_discord_id: String,
+    pub target: String,
+    pub name: String,
+    pub subject: String,
+    pub subject_id: String,
+    pub allow: Option<i8>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct RequestUpdateCommandRule {
+    pub target: Option<String>,
+    pub name: Option<String>,
+    pub subject: Option<String>,
+    pub subject_id: Option<String>,
+    pub allow: Option<i8>,
+}
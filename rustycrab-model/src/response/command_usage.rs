@@ -0,0 +1,64 @@
+use serde::{ Serialize, Deserialize };
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ResponseCommandUsage {
+    pub id: i32,
+    /// Full name of the command, e.g. `autores add`
+    pub command: String,
+    /// `context`, `slash` or `menu`
+    pub kind: String,
+    pub channel_id: String,
+    pub user_id: String,
+    pub duration_ms: i64,
+    pub success: i8,
+    /// Unix timestamp in seconds
+    pub created_at: i64,
+    pub bot_id: i32,
+    pub guild_id: i32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RequestCreateCommandUsage {
+    pub bot_discord_id: String,
+    pub guild_discord_id: String,
+    pub command: String,
+    pub kind: String,
+    pub channel_id: String,
+    pub user_id: String,
+    pub duration_ms: i64,
+    pub success: i8,
+}
+
+/// Usages are never updated, this only exists for the default routes.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct RequestUpdateCommandUsage {}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ResponseCommandStats {
+    pub command: String,
+    pub uses: i64,
+    pub errors: i64,
+    pub error_rate: f64,
+    pub average_duration_ms: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ResponseDailyUsage {
+    /// Unix timestamp in seconds of the start of the day, in UTC
+    pub day: i64,
+    pub uses: i64,
+    pub errors: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ResponseCommandUsageStats {
+    /// Unix timestamp in seconds of the oldest usage counted
+    pub since: i64,
+    pub uses: i64,
+    pub errors: i64,
+    pub error_rate: f64,
+    /// Most used commands, most used first
+    pub top_commands: Vec<ResponseCommandStats>,
+    /// Usage per day, oldest first
+    pub daily: Vec<ResponseDailyUsage>,
+}
@@ -10,6 +10,9 @@ pub struct RequestCreateLogSetting {
 pub struct RequestUpdateLogSetting {
     pub specify_channels: Option<i8>,
     pub new_account_age: Option<i32>,
+    pub new_account_action: Option<String>,
+    pub new_account_timeout: Option<i32>,
+    pub quarantine_role_id: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -17,6 +20,9 @@ pub struct ResponseLogSetting {
     pub id: i32,
     pub specify_channels: i8,
     pub new_account_age: i32,
+    pub new_account_action: Option<String>,
+    pub new_account_timeout: Option<i32>,
+    pub quarantine_role_id: Option<String>,
     pub bot_id: i32,
     pub guild_id: i32,
 }
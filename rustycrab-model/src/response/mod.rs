@@ -4,12 +4,17 @@ pub mod guilds;
 pub mod bot_guild_config;
 pub mod bot_users;
 pub mod bot_guild_welcome;
+pub mod bot_guild_goodbye;
 pub mod logs;
 pub mod ticket;
 pub mod auto_response;
+pub mod auto_role;
+pub mod command_rule;
+pub mod command_usage;
 pub mod discord_message;
 pub mod marriages;
 pub mod items;
+pub mod command_catalog;
 
 use serde::{ Serialize, Deserialize };
 
@@ -26,6 +26,8 @@ pub struct RequestCreateTicketPanel {
     pub welcome_message_data: RequestCreateUpdateMessage,
     pub support_team_id: i32,
     pub ticket_category: Option<String>,
+    /// Asked in a modal before the ticket is opened, at most 5
+    pub questions: Option<Vec<String>>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -39,6 +41,7 @@ pub struct RequestUpdateTicketPanel {
     pub welcome_message_data: Option<RequestCreateUpdateMessage>,
     pub support_team_id: Option<i32>,
     pub ticket_category: Option<String>,
+    pub questions: Option<Vec<String>>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -55,6 +58,7 @@ pub struct ResponseTicketPanel {
     pub button_id: i32,
     pub ticket_category: String,
     pub support_team_id: Option<i32>,
+    pub questions: Vec<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -71,4 +75,5 @@ pub struct ResponseTicketPanelDetails {
     pub naming_scheme: String,
     pub channel_id: String,
     pub ticket_category: String,
+    pub questions: Vec<String>,
 }